@@ -0,0 +1,151 @@
+// src/attributes.rs : parsing of `#[base_traits(...)]` attributes
+
+use syn::{
+    Attribute,
+    Expr,
+    Field,
+    Ident,
+};
+
+
+/// The name of the helper attribute shared by all derives.
+const ATTRIBUTE_NAME : &str = "base_traits";
+
+
+/// Options specified via `#[base_traits(...)]` on a struct or enum.
+#[derive(Default)]
+pub(crate) struct ContainerAttributes {
+    /// Specified as `#[base_traits(as(<target>, ...))]`.
+    as_ : Option<Vec<Ident>>,
+    /// Specified as `#[base_traits(to(<target>, ...))]`.
+    to :  Option<Vec<Ident>>,
+}
+
+impl ContainerAttributes {
+    /// Parses all `#[base_traits(...)]` attributes in the given list.
+    pub(crate) fn parse(attrs : &[Attribute]) -> syn::Result<Self> {
+        let mut attributes = Self::default();
+
+        for attr in base_traits_attributes_(attrs) {
+            attr.parse_nested_meta(|meta| {
+                let (option, targets) = if meta.path.is_ident("as") {
+                    ("as", &mut attributes.as_)
+                } else if meta.path.is_ident("to") {
+                    ("to", &mut attributes.to)
+                } else {
+                    return Err(meta.error("unrecognised `base_traits` option"));
+                };
+
+                if targets.is_some() {
+                    return Err(meta.error(format!("duplicate `{option}` option")));
+                }
+
+                let mut list : Vec<Ident> = Vec::new();
+
+                meta.parse_nested_meta(|inner| {
+                    let target = inner.path.require_ident()?;
+
+                    if list.contains(target) {
+                        return Err(inner.error(format!("duplicate `{option}` conversion `{target}`")));
+                    }
+
+                    list.push(target.clone());
+
+                    Ok(())
+                })?;
+
+                *targets = Some(list);
+
+                Ok(())
+            })?;
+        }
+
+        Ok(attributes)
+    }
+
+    /// The targets listed for the given option - `"as"` or `"to"` - if
+    /// specified.
+    pub(crate) fn conversions(
+        &self,
+        option : &str,
+    ) -> Option<&[Ident]> {
+        match option {
+            "as" => self.as_.as_deref(),
+            "to" => self.to.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+
+/// Options specified via `#[base_traits(...)]` on a field.
+///
+/// Since all the derives share the helper attribute, each derive ignores
+/// the options that apply only to other derives - `delegate` applies to the
+/// delegating derives, and `skip` and `zero` to the aggregating derives -
+/// and only an option that no derive recognises is an error.
+#[derive(Default)]
+pub(crate) struct FieldAttributes {
+    /// Marked with `#[base_traits(delegate)]`.
+    pub(crate) delegate : bool,
+    /// Marked with `#[base_traits(skip)]`.
+    pub(crate) skip :     bool,
+    /// Specified as `#[base_traits(zero = <expr>)]`.
+    pub(crate) zero :     Option<Expr>,
+}
+
+impl FieldAttributes {
+    /// Parses all `#[base_traits(...)]` attributes on the given field.
+    pub(crate) fn parse(field : &Field) -> syn::Result<Self> {
+        let mut attributes = Self::default();
+
+        for attr in base_traits_attributes_(&field.attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("delegate") {
+                    if attributes.delegate {
+                        return Err(meta.error("duplicate `delegate` option"));
+                    }
+
+                    attributes.delegate = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    if attributes.skip {
+                        return Err(meta.error("duplicate `skip` option"));
+                    }
+                    if attributes.zero.is_some() {
+                        return Err(meta.error("`skip` may not be combined with `zero`"));
+                    }
+
+                    attributes.skip = true;
+
+                    Ok(())
+                } else if meta.path.is_ident("zero") {
+                    if attributes.zero.is_some() {
+                        return Err(meta.error("duplicate `zero` option"));
+                    }
+                    if attributes.skip {
+                        return Err(meta.error("`zero` may not be combined with `skip`"));
+                    }
+
+                    attributes.zero = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unrecognised `base_traits` field option"))
+                }
+            })?;
+        }
+
+        Ok(attributes)
+    }
+}
+
+
+fn base_traits_attributes_(attrs : &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME))
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
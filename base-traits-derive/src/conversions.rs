@@ -0,0 +1,410 @@
+// src/conversions.rs : `#[derive(As)]` and `#[derive(To)]`
+
+use crate::{
+    attributes::ContainerAttributes,
+    delegate::Delegation,
+};
+
+use proc_macro2::{
+    Span,
+    TokenStream,
+};
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+use syn::{
+    DeriveInput,
+    Ident,
+    Type,
+    WherePredicate,
+};
+
+
+/// The minimum pointer width for which a conversion is implemented.
+#[derive(Clone, Copy)]
+enum MinWidth {
+    Any,
+    Bits16,
+    Bits32,
+    Bits64,
+    Bits128,
+}
+
+/// A conversion trait, such as `ToI32`, along with the built-in types
+/// that implement it (as of the `implement-*-for-built_ins` features).
+struct Conversion {
+    /// The name of the target type, as given in the attribute, e.g. `i32`.
+    target :  &'static str,
+    /// The name of the trait, e.g. `ToI32`.
+    trait_ :  &'static str,
+    /// The built-in types that implement the trait.
+    sources : &'static [(&'static str, MinWidth)],
+}
+
+/// A family of conversion traits, such as `To*`.
+pub(crate) struct Family {
+    /// The name of the derive, e.g. `To`.
+    derive :      &'static str,
+    /// The name of the option in `#[base_traits(...)]`, e.g. `to`.
+    option :      &'static str,
+    /// The prefix of the method names, e.g. `to_`.
+    prefix :      &'static str,
+    conversions : &'static [Conversion],
+}
+
+
+use MinWidth::{
+    Any,
+    Bits128,
+    Bits16,
+    Bits32,
+    Bits64,
+};
+
+/// The `As*` family, as defined in `src/traits/as_*.rs`.
+///
+/// NOTE: keep the source types of each conversion in sync with the
+/// implementations in `src/traits/as_*.rs` of the main crate (as verified by
+/// its `tests/derive_conversions.rs`).
+pub(crate) const AS : Family = Family {
+    derive :      "As",
+    option :      "as",
+    prefix :      "as_",
+    conversions : &[
+        Conversion { target : "f32", trait_ : "AsF32", sources : &[("f32", Any)] },
+        Conversion { target : "f64", trait_ : "AsF64", sources : &[("f64", Any)] },
+        Conversion { target : "i8", trait_ : "AsI8", sources : &[("i8", Any)] },
+        Conversion { target : "i16", trait_ : "AsI16", sources : &[("i16", Any)] },
+        Conversion { target : "i32", trait_ : "AsI32", sources : &[("i32", Any)] },
+        Conversion { target : "i64", trait_ : "AsI64", sources : &[("i64", Any)] },
+        Conversion { target : "i128", trait_ : "AsI128", sources : &[("i128", Any)] },
+        Conversion { target : "isize", trait_ : "AsISize", sources : &[("isize", Any)] },
+        Conversion { target : "u8", trait_ : "AsU8", sources : &[("u8", Any)] },
+        Conversion { target : "u16", trait_ : "AsU16", sources : &[("u16", Any)] },
+        Conversion { target : "u32", trait_ : "AsU32", sources : &[("u32", Any)] },
+        Conversion { target : "u64", trait_ : "AsU64", sources : &[("u64", Any)] },
+        Conversion { target : "u128", trait_ : "AsU128", sources : &[("u128", Any)] },
+        Conversion { target : "usize", trait_ : "AsUSize", sources : &[("usize", Any)] },
+    ],
+};
+
+/// The `To*` family, as defined in `src/traits/to_*.rs`.
+///
+/// NOTE: keep the source types of each conversion in sync with the
+/// implementations in `src/traits/to_*.rs` of the main crate (as verified by
+/// its `tests/derive_conversions.rs`).
+pub(crate) const TO : Family = Family {
+    derive :      "To",
+    option :      "to",
+    prefix :      "to_",
+    conversions : &[
+        Conversion {
+            target :  "f32",
+            trait_ :  "ToF32",
+            sources : &[("i8", Any), ("i16", Any), ("u8", Any), ("u16", Any), ("f32", Any)],
+        },
+        Conversion {
+            target :  "f64",
+            trait_ :  "ToF64",
+            sources : &[
+                ("i8", Any),
+                ("i16", Any),
+                ("i32", Any),
+                ("i64", Any),
+                ("i128", Any),
+                ("isize", Any),
+                ("u8", Any),
+                ("u16", Any),
+                ("u32", Any),
+                ("u64", Any),
+                ("u128", Any),
+                ("usize", Any),
+                ("f32", Any),
+                ("f64", Any),
+            ],
+        },
+        Conversion {
+            target :  "i8",
+            trait_ :  "ToI8",
+            sources : &[("i8", Any)],
+        },
+        Conversion {
+            target :  "i16",
+            trait_ :  "ToI16",
+            sources : &[("i8", Any), ("i16", Any), ("u8", Any)],
+        },
+        Conversion {
+            target :  "i32",
+            trait_ :  "ToI32",
+            sources : &[("i8", Any), ("i16", Any), ("i32", Any), ("u8", Any), ("u16", Any)],
+        },
+        Conversion {
+            target :  "i64",
+            trait_ :  "ToI64",
+            sources : &[
+                ("i8", Any),
+                ("i16", Any),
+                ("i32", Any),
+                ("i64", Any),
+                ("u8", Any),
+                ("u16", Any),
+                ("u32", Any),
+            ],
+        },
+        Conversion {
+            target :  "i128",
+            trait_ :  "ToI128",
+            sources : &[
+                ("i8", Any),
+                ("i16", Any),
+                ("i32", Any),
+                ("i64", Any),
+                ("i128", Any),
+                ("u8", Any),
+                ("u16", Any),
+                ("u32", Any),
+                ("u64", Any),
+            ],
+        },
+        Conversion {
+            target :  "isize",
+            trait_ :  "ToISize",
+            sources : &[
+                ("i8", Any),
+                ("i16", Bits16),
+                ("i32", Bits32),
+                ("i64", Bits64),
+                ("i128", Bits128),
+                ("isize", Any),
+                ("u8", Bits16),
+                ("u16", Bits32),
+                ("u32", Bits64),
+                ("u64", Bits128),
+            ],
+        },
+        Conversion {
+            target :  "u8",
+            trait_ :  "ToU8",
+            sources : &[("u8", Any)],
+        },
+        Conversion {
+            target :  "u16",
+            trait_ :  "ToU16",
+            sources : &[("u8", Any), ("u16", Any)],
+        },
+        Conversion {
+            target :  "u32",
+            trait_ :  "ToU32",
+            sources : &[("u8", Any), ("u16", Any), ("u32", Any)],
+        },
+        Conversion {
+            target :  "u64",
+            trait_ :  "ToU64",
+            sources : &[("u8", Any), ("u16", Any), ("u32", Any), ("u64", Any)],
+        },
+        Conversion {
+            target :  "u128",
+            trait_ :  "ToU128",
+            sources : &[("u8", Any), ("u16", Any), ("u32", Any), ("u64", Any), ("u128", Any)],
+        },
+        Conversion {
+            target :  "usize",
+            trait_ :  "ToUSize",
+            sources : &[
+                ("u8", Any),
+                ("u16", Bits16),
+                ("u32", Bits32),
+                ("u64", Bits64),
+                ("u128", Bits128),
+                ("usize", Any),
+            ],
+        },
+    ],
+};
+
+
+pub(crate) fn expand(
+    input : &DeriveInput,
+    family : &Family,
+) -> syn::Result<TokenStream> {
+    let delegation = Delegation::from_input(input, family.derive)?;
+    let attributes = ContainerAttributes::parse(&input.attrs)?;
+
+    let delegate_types = delegation.types();
+
+    // The conversions to be implemented, each with the span to which any
+    // error is to be attributed and an optional `#[cfg(...)]`.
+    let mut selected : Vec<(&Conversion, Span, Option<TokenStream>)> = Vec::new();
+
+    match attributes.conversions(family.option) {
+        Some(targets) => {
+            for target in targets {
+                let conversion = find_conversion_(family, target)?;
+
+                for &ty in &delegate_types {
+                    if let Some(source) = primitive_name_(ty) {
+                        if !conversion.sources.iter().any(|&(s, _)| s == source) {
+                            return Err(syn::Error::new_spanned(
+                                target,
+                                format!(
+                                    "cannot derive `{}` for `{}`: the field type `{source}` does not implement `{}`",
+                                    family.derive, input.ident, conversion.trait_,
+                                ),
+                            ));
+                        }
+                    }
+                }
+
+                selected.push((conversion, target.span(), None));
+            }
+        },
+        None => {
+            let source = match common_primitive_name_(&delegate_types) {
+                Some(source) => source,
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        &input.ident,
+                        format!(
+                            "cannot derive `{}` for `{}`: the field type is not a built-in numeric type, so the conversions must be listed, as in `#[base_traits({}(...))]`",
+                            family.derive, input.ident, family.option,
+                        ),
+                    ));
+                },
+            };
+
+            for conversion in family.conversions {
+                if let Some(&(_, min_width)) = conversion.sources.iter().find(|&&(s, _)| s == source) {
+                    selected.push((conversion, input.ident.span(), cfg_for_(min_width)));
+                }
+            }
+
+            if selected.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    format!(
+                        "cannot derive `{}` for `{}`: the field type `{source}` implements none of the `{}*` traits",
+                        family.derive, input.ident, family.derive,
+                    ),
+                ));
+            }
+        },
+    }
+
+    let ident = &input.ident;
+
+    let impls = selected.into_iter().map(|(conversion, span, cfg)| {
+        let trait_ident = Ident::new(conversion.trait_, span);
+        let trait_path = quote_spanned!(span=> ::base_traits::#trait_ident);
+        let method = format_ident!("{}{}", family.prefix, conversion.target);
+        let target = Ident::new(conversion.target, Span::call_site());
+
+        let body = delegation.body(|delegate| quote!(#trait_path::#method(#delegate)));
+
+        let mut generics = input.generics.clone();
+        let where_clause = generics.make_where_clause();
+
+        for &ty in &delegate_types {
+            let predicate : WherePredicate = syn::parse_quote_spanned!(span=> #ty : #trait_path);
+
+            where_clause.predicates.push(predicate);
+        }
+
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            #cfg
+            #[automatically_derived]
+            impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+                #[inline]
+                fn #method(&self) -> #target {
+                    #body
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #(#impls)*
+    })
+}
+
+
+fn find_conversion_<'a>(
+    family : &'a Family,
+    target : &Ident,
+) -> syn::Result<&'a Conversion> {
+    match family.conversions.iter().find(|conversion| target == conversion.target) {
+        Some(conversion) => Ok(conversion),
+        None => {
+            let expected : Vec<&str> = family.conversions.iter().map(|conversion| conversion.target).collect();
+
+            Err(syn::Error::new_spanned(
+                target,
+                format!(
+                    "unrecognised `{}` conversion `{target}`: expected one of {}",
+                    family.option,
+                    expected.join(", "),
+                ),
+            ))
+        },
+    }
+}
+
+/// Obtains the name of `ty` if it is a built-in numeric type.
+fn primitive_name_(ty : &Type) -> Option<String> {
+    const PRIMITIVES : &[&str] = &[
+        "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    ];
+
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let ident = type_path.path.get_ident()?.to_string();
+
+            if PRIMITIVES.contains(&ident.as_str()) {
+                Some(ident)
+            } else {
+                None
+            }
+        },
+        Type::Group(group) => primitive_name_(&group.elem),
+        Type::Paren(paren) => primitive_name_(&paren.elem),
+        _ => None,
+    }
+}
+
+/// Obtains the name of the built-in numeric type shared by all of `types`.
+fn common_primitive_name_(types : &[&Type]) -> Option<String> {
+    let mut names = types.iter().map(|ty| primitive_name_(ty));
+
+    let first = names.next()??;
+
+    if names.all(|name| name.as_deref() == Some(first.as_str())) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+fn cfg_for_(min_width : MinWidth) -> Option<TokenStream> {
+    // NOTE: the value "128" is not among those expected by rustc for
+    // `target_pointer_width`, and so would provoke the `unexpected_cfgs`
+    // lint in the deriving crate; the (empty) `any()` is used instead, as
+    // there are at this time no 128-bit targets
+    let widths : &[&str] = match min_width {
+        Any => return None,
+        Bits16 => &["16", "32", "64"],
+        Bits32 => &["32", "64"],
+        Bits64 => &["64"],
+        Bits128 => &[],
+    };
+
+    Some(quote! {
+        #[cfg(any(#(target_pointer_width = #widths),*))]
+    })
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,211 @@
+// src/delegate.rs : selection of the field to which a derive delegates
+
+use crate::{
+    attributes::FieldAttributes,
+    generics,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Data,
+    DeriveInput,
+    Fields,
+    Generics,
+    Ident,
+    Member,
+    Type,
+};
+
+
+/// The field to which a struct, or a variant of an enum, delegates.
+pub(crate) struct Target<'a> {
+    variant : Option<&'a Ident>,
+    member :  Member,
+    ty :      &'a Type,
+}
+
+/// The delegation plan for a whole type: one target for a struct, one per
+/// variant for an enum.
+pub(crate) struct Delegation<'a> {
+    input :   &'a DeriveInput,
+    is_enum : bool,
+    targets : Vec<Target<'a>>,
+}
+
+impl<'a> Delegation<'a> {
+    /// Determines the delegate field(s) of `input`, failing with an error
+    /// that names `trait_name` if none can be chosen.
+    pub(crate) fn from_input(
+        input : &'a DeriveInput,
+        trait_name : &str,
+    ) -> syn::Result<Self> {
+        match &input.data {
+            Data::Struct(data) => {
+                let (member, ty) = select_(&data.fields, trait_name, &input.ident, "struct")?;
+
+                Ok(Self {
+                    input,
+                    is_enum : false,
+                    targets : vec![Target {
+                        variant : None,
+                        member,
+                        ty,
+                    }],
+                })
+            },
+            Data::Enum(data) => {
+                let mut targets = Vec::with_capacity(data.variants.len());
+                let mut errors : Option<syn::Error> = None;
+
+                for variant in &data.variants {
+                    match select_(&variant.fields, trait_name, &variant.ident, "variant") {
+                        Ok((member, ty)) => {
+                            targets.push(Target {
+                                variant : Some(&variant.ident),
+                                member,
+                                ty,
+                            });
+                        },
+                        Err(e) => {
+                            match &mut errors {
+                                Some(errors) => errors.combine(e),
+                                None => errors = Some(e),
+                            }
+                        },
+                    }
+                }
+
+                match errors {
+                    Some(errors) => Err(errors),
+                    None => {
+                        Ok(Self {
+                            input,
+                            is_enum : true,
+                            targets,
+                        })
+                    },
+                }
+            },
+            Data::Union(data) => {
+                Err(syn::Error::new_spanned(
+                    data.union_token,
+                    format!("cannot derive `{trait_name}` for a union"),
+                ))
+            },
+        }
+    }
+
+    /// Produces the body of a trait method, where `f` is given an
+    /// expression of type `&Delegate` and yields the method's result.
+    pub(crate) fn body<F>(
+        &self,
+        f : F,
+    ) -> TokenStream
+    where
+        F : Fn(TokenStream) -> TokenStream,
+    {
+        if self.is_enum && self.targets.is_empty() {
+            quote! {
+                match *self {}
+            }
+        } else if self.is_enum {
+            let arms = self.targets.iter().map(|target| {
+                let variant = target.variant;
+                let member = &target.member;
+                let result = f(quote!(__base_traits_delegate));
+
+                quote! {
+                    Self::#variant { #member : __base_traits_delegate, .. } => #result,
+                }
+            });
+
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        } else {
+            let member = &self.targets[0].member;
+
+            f(quote!(&self.#member))
+        }
+    }
+
+    /// The types of the delegate fields.
+    pub(crate) fn types(&self) -> Vec<&'a Type> {
+        self.targets.iter().map(|target| target.ty).collect()
+    }
+
+    /// Produces the generics of the type with, for each delegate whose type
+    /// involves a type parameter, the additional predicate
+    /// `Delegate : bound`.
+    pub(crate) fn generics(
+        &self,
+        bound : &TokenStream,
+    ) -> Generics {
+        generics::with_bounds(
+            &self.input.generics,
+            self.targets.iter().map(|target| (target.ty, bound.clone())),
+        )
+    }
+}
+
+
+fn select_<'a>(
+    fields : &'a Fields,
+    trait_name : &str,
+    owner : &Ident,
+    owner_kind : &str,
+) -> syn::Result<(Member, &'a Type)> {
+    let mut marked = None;
+
+    for (index, field) in fields.iter().enumerate() {
+        if FieldAttributes::parse(field)?.delegate {
+            if marked.is_some() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    format!(
+                        "cannot derive `{trait_name}` for {owner_kind} `{owner}`: only one field may be marked `#[base_traits(delegate)]`"
+                    ),
+                ));
+            }
+
+            marked = Some((index, field));
+        }
+    }
+
+    let (index, field) = match marked {
+        Some(marked) => marked,
+        None => {
+            match fields.len() {
+                1 => (0, fields.iter().next().unwrap()),
+                0 => {
+                    return Err(syn::Error::new_spanned(
+                        owner,
+                        format!("cannot derive `{trait_name}` for {owner_kind} `{owner}`: it has no field to delegate to"),
+                    ));
+                },
+                n => {
+                    return Err(syn::Error::new_spanned(
+                        owner,
+                        format!(
+                            "cannot derive `{trait_name}` for {owner_kind} `{owner}`: it has {n} fields, so the one to delegate to must be marked `#[base_traits(delegate)]`"
+                        ),
+                    ));
+                },
+            }
+        },
+    };
+
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    };
+
+    Ok((member, &field.ty))
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,65 @@
+// src/fields.rs : the fields of a deriving struct, with their attributes
+
+use crate::attributes::FieldAttributes;
+
+use syn::{
+    Data,
+    DeriveInput,
+    Fields,
+    Member,
+    Type,
+};
+
+
+/// A field of a struct, along with its `#[base_traits(...)]` options.
+pub(crate) struct StructField<'a> {
+    pub(crate) member :     Member,
+    pub(crate) ty :         &'a Type,
+    pub(crate) attributes : FieldAttributes,
+}
+
+/// Obtains the fields of `input`, failing with an error that names
+/// `trait_name` if `input` is not a struct.
+pub(crate) fn struct_fields<'a>(
+    input : &'a DeriveInput,
+    trait_name : &str,
+) -> syn::Result<(&'a Fields, Vec<StructField<'a>>)> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(syn::Error::new_spanned(
+                data.enum_token,
+                format!("cannot derive `{trait_name}` for enum `{}`: only structs are supported", input.ident),
+            ));
+        },
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                format!("cannot derive `{trait_name}` for a union"),
+            ));
+        },
+    };
+
+    let struct_fields = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(index.into()),
+            };
+
+            Ok(StructField {
+                member,
+                ty : &field.ty,
+                attributes : FieldAttributes::parse(field)?,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok((fields, struct_fields))
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,64 @@
+// src/generics.rs : bounds on the generics of a deriving type
+
+use proc_macro2::{
+    TokenStream,
+    TokenTree,
+};
+use quote::ToTokens;
+use syn::{
+    Generics,
+    Ident,
+    Type,
+    WherePredicate,
+};
+
+
+/// Produces a copy of `generics` with the additional predicate
+/// `Type : Bound` for each given pair whose type involves a type parameter
+/// of `generics`.
+///
+/// Pairs whose type does not involve a type parameter are omitted, since
+/// such (trivial) bounds are checked in any case at the point of use.
+pub(crate) fn with_bounds<'a, I>(
+    generics : &Generics,
+    bounds : I,
+) -> Generics
+where
+    I : IntoIterator<Item = (&'a Type, TokenStream)>,
+{
+    let mut result = generics.clone();
+
+    let type_params : Vec<&Ident> = generics.type_params().map(|tp| &tp.ident).collect();
+
+    if !type_params.is_empty() {
+        let where_clause = result.make_where_clause();
+
+        for (ty, bound) in bounds {
+            if mentions_any_(ty.to_token_stream(), &type_params) {
+                let predicate : WherePredicate = syn::parse_quote!(#ty : #bound);
+
+                where_clause.predicates.push(predicate);
+            }
+        }
+    }
+
+    result
+}
+
+
+fn mentions_any_(
+    tokens : TokenStream,
+    idents : &[&Ident],
+) -> bool {
+    tokens.into_iter().any(|tt| {
+        match tt {
+            TokenTree::Ident(ident) => idents.iter().any(|&i| *i == ident),
+            TokenTree::Group(group) => mentions_any_(group.stream(), idents),
+            _ => false,
+        }
+    })
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
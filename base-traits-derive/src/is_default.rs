@@ -0,0 +1,40 @@
+// src/is_default.rs : `#[derive(IsDefault)]`
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    DeriveInput,
+    WherePredicate,
+};
+
+
+pub(crate) fn expand(input : &DeriveInput) -> syn::Result<TokenStream> {
+    let trait_path = quote!(::base_traits::IsDefault);
+    let ident = &input.ident;
+
+    let mut generics = input.generics.clone();
+
+    {
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        let predicate : WherePredicate = syn::parse_quote!(
+            #ident #ty_generics : ::core::default::Default + ::core::cmp::PartialEq
+        );
+
+        generics.make_where_clause().predicates.push(predicate);
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+            #[inline]
+            fn is_default(&self) -> bool {
+                *self == <Self as ::core::default::Default>::default()
+            }
+        }
+    })
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
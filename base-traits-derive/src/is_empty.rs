@@ -0,0 +1,32 @@
+// src/is_empty.rs : `#[derive(IsEmpty)]`
+
+use crate::delegate::Delegation;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+
+pub(crate) fn expand(input : &DeriveInput) -> syn::Result<TokenStream> {
+    let delegation = Delegation::from_input(input, "IsEmpty")?;
+
+    let trait_path = quote!(::base_traits::IsEmpty);
+    let body = delegation.body(|delegate| quote!(#trait_path::is_empty(#delegate)));
+    let generics = delegation.generics(&trait_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+            #[inline]
+            fn is_empty(&self) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
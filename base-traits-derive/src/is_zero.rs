@@ -0,0 +1,63 @@
+// src/is_zero.rs : `#[derive(IsZero)]`
+
+use crate::{
+    fields,
+    generics,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+
+pub(crate) fn expand(input : &DeriveInput) -> syn::Result<TokenStream> {
+    let (_, struct_fields) = fields::struct_fields(input, "IsZero")?;
+
+    let trait_path = quote!(::base_traits::IsZero);
+
+    let conditions : Vec<TokenStream> = struct_fields
+        .iter()
+        .filter(|field| !field.attributes.skip)
+        .map(|field| {
+            let member = &field.member;
+
+            match &field.attributes.zero {
+                Some(zero) => quote!(self.#member == #zero),
+                None => quote!(#trait_path::is_zero(&self.#member)),
+            }
+        })
+        .collect();
+
+    let body = if conditions.is_empty() {
+        quote!(true)
+    } else {
+        quote!(#(#conditions)&&*)
+    };
+
+    let generics = generics::with_bounds(
+        &input.generics,
+        struct_fields.iter().filter(|field| !field.attributes.skip).map(|field| {
+            if field.attributes.zero.is_some() {
+                (field.ty, quote!(::core::cmp::PartialEq))
+            } else {
+                (field.ty, trait_path.clone())
+            }
+        }),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+            #[inline]
+            fn is_zero(&self) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,32 @@
+// src/len.rs : `#[derive(Len)]`
+
+use crate::delegate::Delegation;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+
+pub(crate) fn expand(input : &DeriveInput) -> syn::Result<TokenStream> {
+    let delegation = Delegation::from_input(input, "Len")?;
+
+    let trait_path = quote!(::base_traits::Len);
+    let body = delegation.body(|delegate| quote!(#trait_path::len(#delegate)));
+    let generics = delegation.generics(&trait_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+            #[inline]
+            fn len(&self) -> usize {
+                #body
+            }
+        }
+    })
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,383 @@
+// src/lib.rs : base-traits-derive
+
+//! Derive macros for the traits defined in the **base-traits** crate.
+//!
+//! These are not intended to be used directly, but rather via the
+//! `"derive"` feature of **base-traits**, which re-exports them alongside
+//! the traits of the same name.
+//!
+//! # Delegation
+//!
+//! The derives [`IsEmpty`](macro@IsEmpty) and [`Len`](macro@Len) implement
+//! their trait by delegating to a single field:
+//!
+//! - for a struct, the delegate is the field marked with
+//!   `#[base_traits(delegate)]` or, if none is marked, the sole field of the
+//!   struct;
+//! - for an enum, each variant is treated as a struct, so that each variant
+//!   must have a single field or a field marked with
+//!   `#[base_traits(delegate)]`;
+//!
+//! ```
+//! use base_traits::{
+//!     IsEmpty,
+//!     Len,
+//! };
+//!
+//! #[derive(IsEmpty, Len)]
+//! struct Names {
+//!     #[base_traits(delegate)]
+//!     names : Vec<String>,
+//!     sorted : bool,
+//! }
+//!
+//! #[derive(IsEmpty, Len)]
+//! enum Text {
+//!     Borrowed(&'static str),
+//!     Owned(String),
+//! }
+//!
+//! let names = Names { names : vec![ "abc".into() ], sorted : true };
+//!
+//! assert!(!names.is_empty());
+//! assert_eq!(1, names.len());
+//!
+//! assert!(Text::Borrowed("").is_empty());
+//! assert_eq!(3, Text::Owned("abc".into()).len());
+//! ```
+//!
+//! A compile error is issued when no delegate field can be chosen:
+//!
+//! ```compile_fail
+//! use base_traits::IsEmpty;
+//!
+//! #[derive(IsEmpty)]
+//! struct Names {
+//!     names : Vec<String>,
+//!     sorted : bool,
+//! }
+//! ```
+//!
+//! or when more than one field is marked:
+//!
+//! ```compile_fail
+//! use base_traits::Len;
+//!
+//! #[derive(Len)]
+//! struct Names {
+//!     #[base_traits(delegate)]
+//!     names : Vec<String>,
+//!     #[base_traits(delegate)]
+//!     aliases : Vec<String>,
+//! }
+//! ```
+//!
+//! or when an enum variant has no field:
+//!
+//! ```compile_fail
+//! use base_traits::IsEmpty;
+//!
+//! #[derive(IsEmpty)]
+//! enum Text {
+//!     Nothing,
+//!     Owned(String),
+//! }
+//! ```
+//!
+//! The options `skip` and `zero` (see below) apply only to the aggregating
+//! derives, and so are ignored, allowing the same struct to derive both:
+//!
+//! ```
+//! use base_traits::{
+//!     IsEmpty,
+//!     IsZero,
+//!     Len,
+//! };
+//!
+//! #[derive(IsEmpty, IsZero, Len)]
+//! struct Batch(#[base_traits(zero = Vec::new())] Vec<u32>);
+//!
+//! let batch = Batch(vec![]);
+//!
+//! assert_eq!(0, batch.len());
+//! assert!(batch.is_zero());
+//! ```
+//!
+//! # Aggregation
+//!
+//! The derives [`Zero`](macro@Zero) and [`IsZero`](macro@IsZero) implement
+//! their trait in terms of all the fields of a struct (which, for a newtype,
+//! amounts to forwarding to the wrapped value):
+//!
+//! - `zero()` creates an instance in which each field is obtained from its
+//!   own `Zero::zero()`;
+//! - `is_zero()` yields `true` if every field is zero, according to its
+//!   own `IsZero::is_zero()`;
+//!
+//! The handling of an individual field may be altered by:
+//!
+//! - `#[base_traits(skip)]` - the field is excluded from `is_zero()`, and is
+//!   obtained from `Default::default()` in `zero()`;
+//! - `#[base_traits(zero = <expr>)]` - the field is obtained from `<expr>`
+//!   in `zero()`, and is compared (via `PartialEq`) with `<expr>` in
+//!   `is_zero()`;
+//!
+//! ```
+//! use base_traits::{
+//!     IsZero,
+//!     Zero,
+//! };
+//!
+//! #[derive(Debug, PartialEq, IsZero, Zero)]
+//! struct Price(u64);
+//!
+//! #[derive(Debug, IsZero, Zero)]
+//! struct Position {
+//!     quantity : i32,
+//!     price : Price,
+//!     #[base_traits(zero = 'X')]
+//!     exchange : char,
+//!     #[base_traits(skip)]
+//!     note : String,
+//! }
+//!
+//! assert_eq!(Price(0), Price::zero());
+//! assert!(!Price(1).is_zero());
+//!
+//! let position = Position::zero();
+//!
+//! assert_eq!(0, position.quantity);
+//! assert_eq!('X', position.exchange);
+//! assert!(position.is_zero());
+//!
+//! let position = Position { note : "abc".into(), ..Position::zero() };
+//!
+//! assert!(position.is_zero());
+//!
+//! let position = Position { quantity : 1, ..Position::zero() };
+//!
+//! assert!(!position.is_zero());
+//! ```
+//!
+//! Enums and unions are not supported:
+//!
+//! ```compile_fail
+//! use base_traits::Zero;
+//!
+//! #[derive(Zero)]
+//! enum Sign {
+//!     Negative,
+//!     Positive,
+//! }
+//! ```
+//!
+//! Since every field takes part, `#[base_traits(delegate)]` - which applies
+//! only to the delegating derives - is ignored, allowing the same struct to
+//! derive both:
+//!
+//! ```
+//! use base_traits::{
+//!     IsZero,
+//!     To,
+//!     ToF64,
+//!     Zero,
+//! };
+//!
+//! #[derive(IsZero, To, Zero)]
+//! struct Price {
+//!     #[base_traits(delegate)]
+//!     cents : i64,
+//!     currency : u8,
+//! }
+//!
+//! let price = Price { cents : 0, currency : 1 };
+//!
+//! assert_eq!(0.0, price.to_f64());
+//! assert!(!price.is_zero());
+//! assert!(Price::zero().is_zero());
+//! ```
+//!
+//! # Comparison with the default
+//!
+//! The derive [`IsDefault`](macro@IsDefault) implements its trait, for a
+//! type that implements `Default` and `PartialEq`, as
+//! `*self == Self::default()`, and so is applicable to structs, enums, and
+//! unions alike:
+//!
+//! ```
+//! use base_traits::IsDefault;
+//!
+//! #[derive(Default, PartialEq, IsDefault)]
+//! struct Settings {
+//!     verbose : bool,
+//!     retries : u32,
+//! }
+//!
+//! #[derive(Default, PartialEq, IsDefault)]
+//! enum Mode {
+//!     #[default]
+//!     Normal,
+//!     Fast,
+//! }
+//!
+//! assert!(Settings::default().is_default());
+//! assert!(!Settings { retries : 3, ..Default::default() }.is_default());
+//!
+//! assert!(Mode::Normal.is_default());
+//! assert!(!Mode::Fast.is_default());
+//! ```
+//!
+//! A compile error is issued when the type does not implement `Default` or
+//! `PartialEq`:
+//!
+//! ```compile_fail
+//! use base_traits::IsDefault;
+//!
+//! #[derive(Default, IsDefault)]
+//! struct Settings {
+//!     verbose : bool,
+//! }
+//! ```
+//!
+//! # Conversion
+//!
+//! The derives [`To`](macro@To) and [`As`](macro@As) implement members of
+//! the `To*` (e.g. `ToF64`, `ToI64`) and `As*` (e.g. `AsU32`) families of
+//! traits, respectively, by delegating to a single field, as described
+//! above.
+//!
+//! The traits to be implemented may be listed, as in
+//! `#[base_traits(to(f64, i64, i128))]` and `#[base_traits(as(u32))]`,
+//! respectively. If they are not listed, and the delegate field is of a
+//! built-in numeric type, then all the traits of the family that are
+//! implemented by that type are implemented. (This assumes that the
+//! corresponding `implement-*-for-built_ins` features of **base-traits**
+//! are defined, as they are by `"default"`; if one is not, the
+//! corresponding trait must be excluded by listing the traits to be
+//! implemented.)
+//!
+//! ```
+//! use base_traits::{
+//!     As,
+//!     AsU32,
+//!     To,
+//!     ToF64,
+//!     ToI64,
+//!     ToU64,
+//! };
+//!
+//! #[derive(As, To)]
+//! struct Id(u32);
+//!
+//! #[derive(To)]
+//! #[base_traits(to(f64, i64))]
+//! struct Price {
+//!     #[base_traits(delegate)]
+//!     cents : i32,
+//!     currency : &'static str,
+//! }
+//!
+//! assert_eq!(123, Id(123).as_u32());
+//! assert_eq!(123, Id(123).to_u64());
+//!
+//! let price = Price { cents : 1234, currency : "AUD" };
+//!
+//! assert_eq!(1234.0, price.to_f64());
+//! assert_eq!(1234, price.to_i64());
+//! ```
+//!
+//! A compile error is issued when a listed trait is not implemented by the
+//! delegate field's type:
+//!
+//! ```compile_fail
+//! use base_traits::To;
+//!
+//! #[derive(To)]
+//! #[base_traits(to(f64, i32))]
+//! struct Nanoseconds(i64); // `i64` does not implement `ToI32`
+//! ```
+
+
+// /////////////////////////////////////////////////////////
+// imports
+
+mod attributes;
+mod conversions;
+mod delegate;
+mod fields;
+mod generics;
+mod is_default;
+mod is_empty;
+mod is_zero;
+mod len;
+mod zero;
+
+use proc_macro::TokenStream;
+use syn::{
+    parse_macro_input,
+    DeriveInput,
+};
+
+
+// /////////////////////////////////////////////////////////
+// derive macros
+
+/// Derives the `base_traits::As*` traits by delegating to a single field.
+#[proc_macro_derive(As, attributes(base_traits))]
+pub fn derive_as(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    conversions::expand(&input, &conversions::AS).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `base_traits::IsDefault` as `*self == Self::default()`.
+#[proc_macro_derive(IsDefault)]
+pub fn derive_is_default(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    is_default::expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `base_traits::IsEmpty` by delegating to a single field.
+#[proc_macro_derive(IsEmpty, attributes(base_traits))]
+pub fn derive_is_empty(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    is_empty::expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `base_traits::IsZero` as "all (non-skipped) fields are zero".
+#[proc_macro_derive(IsZero, attributes(base_traits))]
+pub fn derive_is_zero(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    is_zero::expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `base_traits::Len` by delegating to a single field.
+#[proc_macro_derive(Len, attributes(base_traits))]
+pub fn derive_len(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    len::expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives the `base_traits::To*` traits by delegating to a single field.
+#[proc_macro_derive(To, attributes(base_traits))]
+pub fn derive_to(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    conversions::expand(&input, &conversions::TO).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `base_traits::Zero` by making every field zero.
+#[proc_macro_derive(Zero, attributes(base_traits))]
+pub fn derive_zero(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    zero::expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,66 @@
+// src/zero.rs : `#[derive(Zero)]`
+
+use crate::{
+    fields,
+    generics,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    DeriveInput,
+    Fields,
+};
+
+
+pub(crate) fn expand(input : &DeriveInput) -> syn::Result<TokenStream> {
+    let (fields, struct_fields) = fields::struct_fields(input, "Zero")?;
+
+    let trait_path = quote!(::base_traits::Zero);
+
+    let initialisers = struct_fields.iter().map(|field| {
+        let member = &field.member;
+
+        let value = if field.attributes.skip {
+            quote!(::core::default::Default::default())
+        } else if let Some(zero) = &field.attributes.zero {
+            quote!(#zero)
+        } else {
+            quote!(#trait_path::zero())
+        };
+
+        quote!(#member : #value)
+    });
+
+    let body = match fields {
+        Fields::Unit => quote!(Self),
+        _ => quote!(Self { #(#initialisers),* }),
+    };
+
+    let generics = generics::with_bounds(
+        &input.generics,
+        struct_fields.iter().filter(|field| field.attributes.zero.is_none()).map(|field| {
+            if field.attributes.skip {
+                (field.ty, quote!(::core::default::Default))
+            } else {
+                (field.ty, trait_path.clone())
+            }
+        }),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+            #[inline]
+            fn zero() -> Self {
+                #body
+            }
+        }
+    })
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,175 @@
+// tests/conversions.rs : `#[derive(As)]` and `#[derive(To)]`
+
+#![allow(non_snake_case)]
+
+use base_traits::{
+    As,
+    AsF32,
+    AsI64,
+    AsU32,
+    AsU8,
+    To,
+    ToF32,
+    ToF64,
+    ToI128,
+    ToI16,
+    ToI32,
+    ToI64,
+    ToISize,
+    ToU128,
+    ToU16,
+    ToU32,
+    ToU64,
+    ToU8,
+    ToUSize,
+};
+
+
+mod TEST_To {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    /// All conversions supported by `u16`.
+    #[derive(To)]
+    struct Quantity(u16);
+
+    /// Only the listed conversions.
+    #[derive(To)]
+    #[base_traits(to(f64, i64, i128))]
+    struct Price {
+        #[base_traits(delegate)]
+        cents :    i32,
+        #[allow(unused)]
+        currency : &'static str,
+    }
+
+    #[derive(To)]
+    #[base_traits(to(f64))]
+    struct Wrapper<T>(T);
+
+    #[derive(To)]
+    #[base_traits(to(f64, i64))]
+    struct Nested(Price);
+
+    #[derive(To)]
+    enum Reading {
+        Raw(u8),
+        Scaled {
+            #[allow(unused)]
+            id :    u32,
+            #[base_traits(delegate)]
+            value : u8,
+        },
+    }
+
+
+    #[test]
+    fn TEST_Quantity() {
+        let q = Quantity(1234);
+
+        assert_eq!(1234.0, q.to_f32());
+        assert_eq!(1234.0, q.to_f64());
+        assert_eq!(1234, q.to_i32());
+        assert_eq!(1234, q.to_i64());
+        assert_eq!(1234, q.to_i128());
+        assert_eq!(1234, q.to_isize());
+        assert_eq!(1234, q.to_u16());
+        assert_eq!(1234, q.to_u32());
+        assert_eq!(1234, q.to_u64());
+        assert_eq!(1234, q.to_u128());
+        assert_eq!(1234, q.to_usize());
+    }
+
+    #[test]
+    fn TEST_Price() {
+        let p = Price { cents : -1234, currency : "AUD" };
+
+        assert_eq!(-1234.0, p.to_f64());
+        assert_eq!(-1234, p.to_i64());
+        assert_eq!(-1234, p.to_i128());
+    }
+
+    #[test]
+    fn TEST_Wrapper() {
+        assert_eq!(1.5, Wrapper(1.5f32).to_f64());
+        assert_eq!(-3.0, Wrapper(-3i64).to_f64());
+    }
+
+    #[test]
+    fn TEST_Nested() {
+        let n = Nested(Price { cents : 5, currency : "AUD" });
+
+        assert_eq!(5.0, n.to_f64());
+        assert_eq!(5, n.to_i64());
+    }
+
+    #[test]
+    fn TEST_Reading() {
+        assert_eq!(12, Reading::Raw(12).to_i16());
+        assert_eq!(12.0, Reading::Raw(12).to_f32());
+        assert_eq!(34, Reading::Scaled { id : 0, value : 34 }.to_u8());
+        assert_eq!(34, Reading::Scaled { id : 0, value : 34 }.to_u16());
+    }
+}
+
+
+mod TEST_As {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(As, To)]
+    struct Id(u32);
+
+    #[derive(As, To)]
+    struct Level(u8);
+
+    #[derive(As)]
+    struct Gain(f32);
+
+    #[derive(As)]
+    #[base_traits(as(i64))]
+    struct Timestamp {
+        #[base_traits(delegate)]
+        nanos : i64,
+        #[allow(unused)]
+        zone :  &'static str,
+    }
+
+
+    #[test]
+    fn TEST_Id() {
+        let id = Id(0xDEADBEEF);
+
+        assert_eq!(0xDEADBEEF, id.as_u32());
+        assert_eq!(0xDEADBEEF, id.to_u64());
+    }
+
+    #[test]
+    fn TEST_Level() {
+        let level = Level(200);
+
+        assert_eq!(200, level.as_u8());
+        assert_eq!(200, level.to_u8());
+        assert_eq!(200.0, level.to_f32());
+    }
+
+    #[test]
+    fn TEST_Gain() {
+        assert_eq!(0.5, Gain(0.5).as_f32());
+    }
+
+    #[test]
+    fn TEST_Timestamp() {
+        let ts = Timestamp { nanos : -1, zone : "UTC" };
+
+        assert_eq!(-1, ts.as_i64());
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,98 @@
+// tests/is_default.rs : `#[derive(IsDefault)]`
+
+#![allow(non_snake_case)]
+
+use base_traits::IsDefault;
+
+
+#[allow(unused)]
+fn as_IsDefault<T : IsDefault>(t : &T) -> &impl IsDefault {
+    t
+}
+
+
+mod TEST_STRUCT {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(Default, PartialEq, IsDefault)]
+    struct Unit;
+
+    #[derive(Default, PartialEq, IsDefault)]
+    struct Newtype(String);
+
+    #[derive(PartialEq, IsDefault)]
+    struct Bespoke {
+        limit : u32,
+    }
+
+    impl Default for Bespoke {
+        fn default() -> Self {
+            Self { limit : 100 }
+        }
+    }
+
+    #[derive(Default, PartialEq, IsDefault)]
+    struct Generic<T> {
+        items : Vec<T>,
+        label : &'static str,
+    }
+
+
+    #[test]
+    fn TEST_Unit() {
+        assert!(Unit.is_default());
+    }
+
+    #[test]
+    fn TEST_Newtype() {
+        assert!(Newtype(String::new()).is_default());
+        assert!(!Newtype("abc".into()).is_default());
+    }
+
+    #[test]
+    fn TEST_Bespoke() {
+        assert!(Bespoke { limit : 100 }.is_default());
+        assert!(!Bespoke { limit : 0 }.is_default());
+    }
+
+    #[test]
+    fn TEST_Generic() {
+        assert!(Generic::<i32> { items : vec![], label : "" }.is_default());
+        assert!(!Generic { items : vec![ 1 ], label : "" }.is_default());
+        assert!(!Generic::<i32> { items : vec![], label : "abc" }.is_default());
+
+        let g = Box::new(Generic::<f64>::default());
+
+        assert!(as_IsDefault(&g).is_default());
+    }
+}
+
+
+mod TEST_ENUM {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(Default, PartialEq, IsDefault)]
+    enum Level {
+        Low,
+        #[default]
+        Medium,
+        High,
+    }
+
+
+    #[test]
+    fn TEST_Level() {
+        assert!(!Level::Low.is_default());
+        assert!(Level::Medium.is_default());
+        assert!(!Level::High.is_default());
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,141 @@
+// tests/is_empty.rs : `#[derive(IsEmpty)]`
+
+#![allow(non_snake_case)]
+
+use base_traits::IsEmpty;
+
+
+#[allow(unused)]
+fn as_IsEmpty<T : IsEmpty>(t : &T) -> &impl IsEmpty {
+    t
+}
+
+
+mod TEST_STRUCT {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(IsEmpty)]
+    struct Newtype(String);
+
+    #[derive(IsEmpty)]
+    struct SoleField {
+        names : Vec<String>,
+    }
+
+    #[derive(IsEmpty)]
+    struct MarkedField {
+        #[allow(unused)]
+        id :    i32,
+        #[base_traits(delegate)]
+        names : Vec<String>,
+    }
+
+    #[derive(IsEmpty)]
+    struct MarkedTupleField(#[allow(unused)] i32, #[base_traits(delegate)] &'static str);
+
+    #[derive(IsEmpty)]
+    struct Generic<T> {
+        #[base_traits(delegate)]
+        items : Vec<T>,
+        #[allow(unused)]
+        label : &'static str,
+    }
+
+    #[derive(IsEmpty)]
+    struct GenericDelegate<C : Default>(C);
+
+
+    #[test]
+    fn TEST_Newtype() {
+        assert!(Newtype("".into()).is_empty());
+        assert!(!Newtype("abc".into()).is_empty());
+
+        assert!(as_IsEmpty(&Newtype("".into())).is_empty());
+    }
+
+    #[test]
+    fn TEST_SoleField() {
+        assert!(SoleField { names : vec![] }.is_empty());
+        assert!(!SoleField { names : vec!["abc".into()] }.is_empty());
+    }
+
+    #[test]
+    fn TEST_MarkedField() {
+        assert!(MarkedField { id : 1, names : vec![] }.is_empty());
+        assert!(!MarkedField { id : 0, names : vec!["abc".into()] }.is_empty());
+    }
+
+    #[test]
+    fn TEST_MarkedTupleField() {
+        assert!(MarkedTupleField(1, "").is_empty());
+        assert!(!MarkedTupleField(0, "abc").is_empty());
+    }
+
+    #[test]
+    fn TEST_Generic() {
+        assert!(Generic::<i32> { items : vec![], label : "abc" }.is_empty());
+        assert!(!Generic { items : vec![0], label : "" }.is_empty());
+    }
+
+    #[test]
+    fn TEST_GenericDelegate() {
+        assert!(GenericDelegate(String::new()).is_empty());
+        assert!(!GenericDelegate(vec![0]).is_empty());
+    }
+}
+
+
+mod TEST_ENUM {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(IsEmpty)]
+    enum Text {
+        Borrowed(&'static str),
+        Owned(String),
+        Named {
+            #[allow(unused)]
+            id :   i32,
+            #[base_traits(delegate)]
+            text : String,
+        },
+    }
+
+    #[derive(IsEmpty)]
+    enum Never {}
+
+
+    #[test]
+    fn TEST_EMPTY() {
+        assert!(Text::Borrowed("").is_empty());
+        assert!(Text::Owned("".into()).is_empty());
+        assert!(Text::Named { id : 1, text : "".into() }.is_empty());
+
+        assert!(as_IsEmpty(&Text::Borrowed("")).is_empty());
+    }
+
+    #[test]
+    fn TEST_NONEMPTY() {
+        assert!(!Text::Borrowed("abc").is_empty());
+        assert!(!Text::Owned("abc".into()).is_empty());
+        assert!(!Text::Named { id : 0, text : "abc".into() }.is_empty());
+    }
+
+    #[test]
+    fn TEST_Never() {
+        fn is_empty_never(n : &Never) -> bool {
+            n.is_empty()
+        }
+
+        let _ = is_empty_never;
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
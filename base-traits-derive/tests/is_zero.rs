@@ -0,0 +1,103 @@
+// tests/is_zero.rs : `#[derive(IsZero)]`
+
+#![allow(non_snake_case)]
+
+use base_traits::IsZero;
+
+
+#[allow(unused)]
+fn as_IsZero<T : IsZero>(t : &T) -> &impl IsZero {
+    t
+}
+
+
+mod TEST_NEWTYPE {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(IsZero)]
+    struct Price(u64);
+
+    #[derive(IsZero)]
+    struct Celsius {
+        degrees : f64,
+    }
+
+    #[derive(IsZero)]
+    struct Generic<T>(T);
+
+
+    #[test]
+    fn TEST_Price() {
+        assert!(Price(0).is_zero());
+        assert!(!Price(1).is_zero());
+
+        assert!(as_IsZero(&Price(0)).is_zero());
+    }
+
+    #[test]
+    fn TEST_Celsius() {
+        assert!(Celsius { degrees : 0.0 }.is_zero());
+        assert!(!Celsius { degrees : -40.0 }.is_zero());
+    }
+
+    #[test]
+    fn TEST_Generic() {
+        assert!(Generic(0i8).is_zero());
+        assert!(!Generic(Price(1)).is_zero());
+    }
+}
+
+
+mod TEST_AGGREGATE {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(IsZero)]
+    struct Unit;
+
+    #[derive(IsZero)]
+    struct Point {
+        x : i32,
+        y : i32,
+    }
+
+    #[derive(IsZero)]
+    struct Tagged<T> {
+        value : T,
+        #[base_traits(skip)]
+        #[allow(unused)]
+        tag :   String,
+        #[base_traits(zero = 'X')]
+        code :  char,
+    }
+
+
+    #[test]
+    fn TEST_Unit() {
+        assert!(Unit.is_zero());
+    }
+
+    #[test]
+    fn TEST_Point() {
+        assert!(Point { x : 0, y : 0 }.is_zero());
+        assert!(!Point { x : 0, y : 1 }.is_zero());
+        assert!(!Point { x : 1, y : 0 }.is_zero());
+    }
+
+    #[test]
+    fn TEST_Tagged() {
+        assert!(Tagged { value : 0, tag : "abc".into(), code : 'X' }.is_zero());
+        assert!(Tagged { value : 0.0, tag : "".into(), code : 'X' }.is_zero());
+        assert!(!Tagged { value : 1, tag : "".into(), code : 'X' }.is_zero());
+        assert!(!Tagged { value : 0, tag : "".into(), code : '\0' }.is_zero());
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
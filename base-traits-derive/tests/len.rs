@@ -0,0 +1,128 @@
+// tests/len.rs : `#[derive(Len)]`
+
+#![allow(non_snake_case)]
+
+use base_traits::Len;
+
+
+#[allow(unused)]
+fn as_Len<T : Len>(t : &T) -> &impl Len {
+    t
+}
+
+
+mod TEST_STRUCT {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(Len)]
+    struct Newtype(String);
+
+    #[derive(Len)]
+    struct MarkedField {
+        #[allow(unused)]
+        id :    i32,
+        #[base_traits(delegate)]
+        names : Vec<String>,
+    }
+
+    #[derive(Len)]
+    struct Generic<T> {
+        #[base_traits(delegate)]
+        items : Vec<T>,
+        #[allow(unused)]
+        label : &'static str,
+    }
+
+
+    #[test]
+    fn TEST_Newtype() {
+        assert_eq!(0, Newtype("".into()).len());
+        assert_eq!(3, Newtype("abc".into()).len());
+
+        assert_eq!(3, as_Len(&Newtype("abc".into())).len());
+    }
+
+    #[test]
+    fn TEST_MarkedField() {
+        assert_eq!(0, MarkedField { id : 1, names : vec![] }.len());
+        assert_eq!(2, MarkedField { id : 0, names : vec!["abc".into(), "def".into()] }.len());
+    }
+
+    #[test]
+    fn TEST_Generic() {
+        assert_eq!(0, Generic::<i32> { items : vec![], label : "abc" }.len());
+        assert_eq!(3, Generic { items : vec![0, 1, 2], label : "" }.len());
+    }
+}
+
+
+mod TEST_ENUM {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(Len)]
+    enum Items<T> {
+        Array([T; 2]),
+        Vector(Vec<T>),
+        Named {
+            #[allow(unused)]
+            id :    i32,
+            #[base_traits(delegate)]
+            items : Vec<T>,
+        },
+    }
+
+
+    #[test]
+    fn TEST_VARIANTS() {
+        assert_eq!(2, Items::Array([0, 1]).len());
+        assert_eq!(0, Items::<i32>::Vector(vec![]).len());
+        assert_eq!(3, Items::Vector(vec![0, 1, 2]).len());
+        assert_eq!(1, Items::Named { id : 0, items : vec![0] }.len());
+
+        assert_eq!(2, as_Len(&Items::Array([0, 1])).len());
+    }
+}
+
+
+mod TEST_SHARED_ATTRIBUTES {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use base_traits::{
+        IsEmpty,
+        IsZero,
+    };
+
+
+    /// The options of the aggregating derives are ignored by `IsEmpty` and
+    /// `Len`.
+    #[derive(IsEmpty, IsZero, Len)]
+    struct Batch(#[base_traits(zero = Vec::new())] Vec<u32>);
+
+
+    #[test]
+    fn TEST_Batch() {
+        let batch = Batch(vec![ 1, 2 ]);
+
+        assert_eq!(2, batch.len());
+        assert!(!batch.is_empty());
+        assert!(!batch.is_zero());
+
+        let batch = Batch(vec![]);
+
+        assert_eq!(0, batch.len());
+        assert!(batch.is_empty());
+        assert!(batch.is_zero());
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,138 @@
+// tests/zero.rs : `#[derive(Zero)]`
+
+#![allow(non_snake_case)]
+
+use base_traits::Zero;
+
+
+mod TEST_NEWTYPE {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(Debug, PartialEq, Zero)]
+    struct Price(u64);
+
+    #[derive(Debug, PartialEq, Zero)]
+    struct Celsius {
+        degrees : f64,
+    }
+
+    #[derive(Debug, PartialEq, Zero)]
+    struct Generic<T>(T);
+
+
+    #[test]
+    fn TEST_Price() {
+        assert_eq!(Price(0), Price::zero());
+    }
+
+    #[test]
+    fn TEST_Celsius() {
+        assert_eq!(Celsius { degrees : 0.0 }, Celsius::zero());
+    }
+
+    #[test]
+    fn TEST_Generic() {
+        assert_eq!(Generic(0i8), Generic::zero());
+        assert_eq!(Generic(Price(0)), Generic::zero());
+    }
+}
+
+
+mod TEST_AGGREGATE {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[derive(Debug, PartialEq, Zero)]
+    struct Unit;
+
+    #[derive(Debug, PartialEq, Zero)]
+    struct Point {
+        x : i32,
+        y : i32,
+    }
+
+    #[derive(Debug, PartialEq, Zero)]
+    struct Tagged<T> {
+        value : T,
+        #[base_traits(skip)]
+        tag :   String,
+        #[base_traits(zero = 'X')]
+        code :  char,
+        #[base_traits(zero = vec![ 0 ])]
+        limbs : Vec<u32>,
+    }
+
+
+    #[test]
+    fn TEST_Unit() {
+        assert_eq!(Unit, Unit::zero());
+    }
+
+    #[test]
+    fn TEST_Point() {
+        assert_eq!(Point { x : 0, y : 0 }, Point::zero());
+    }
+
+    #[test]
+    fn TEST_Tagged() {
+        let expected = Tagged {
+            value : Point { x : 0, y : 0 },
+            tag :   String::new(),
+            code :  'X',
+            limbs : vec![0],
+        };
+
+        assert_eq!(expected, Tagged::zero());
+    }
+}
+
+
+mod TEST_SHARED_ATTRIBUTES {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use base_traits::{
+        IsZero,
+        To,
+        ToF64,
+        ToI64,
+    };
+
+
+    /// The `delegate` option of the delegating derives is ignored by `Zero`
+    /// and `IsZero`.
+    #[derive(Debug, PartialEq, IsZero, To, Zero)]
+    struct Price {
+        #[base_traits(delegate)]
+        cents :    i64,
+        currency : u8,
+    }
+
+
+    #[test]
+    fn TEST_Price() {
+        assert_eq!(Price { cents : 0, currency : 0 }, Price::zero());
+        assert!(Price::zero().is_zero());
+
+        let price = Price { cents : 1234, currency : 0 };
+
+        assert_eq!(1234, price.to_i64());
+        assert_eq!(1234.0, price.to_f64());
+        assert!(!price.is_zero());
+
+        let price = Price { cents : 0, currency : 1 };
+
+        assert_eq!(0, price.to_i64());
+        assert!(!price.is_zero());
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
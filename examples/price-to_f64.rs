@@ -33,7 +33,7 @@ where
         let n = values.len() as f64;
         let sum : f64 = values.iter().sum();
         let mean = sum / n;
-        let ss : f64 = values.iter().map(|v| (v - mean)).map(|v| v * v).sum();
+        let ss : f64 = values.iter().map(|v| v - mean).map(|v| v * v).sum();
         let var = ss / n;
         let stddev = var.sqrt();
 
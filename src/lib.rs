@@ -8,6 +8,10 @@
 #![cfg_attr(all(not(test), feature = "nostd"), no_std)]
 
 
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+extern crate alloc;
+
+
 // /////////////////////////////////////////////////////////
 // crate-level feature discrimination
 
@@ -16,47 +20,137 @@
 // imports
 
 mod traits;
+mod types;
 
 pub use traits::{
+    AsF32,
     AsF64,
     AsI128,
+    AsI16,
     AsI32,
     AsI64,
+    AsI8,
     AsISize,
     AsStr,
     AsU128,
+    AsU16,
     AsU32,
     AsU64,
+    AsU8,
     AsUSize,
+    Classify,
+    ComponentCount,
+    Elapsed,
     Infinity,
     Integer,
     IsDefault,
     IsEmpty,
+    IsEpoch,
+    IsFinite,
     IsInfinity,
+    IsMaxValue,
+    IsMinValue,
     IsNAN,
+    IsNegative,
+    IsNegativeInfinity,
+    IsNormal,
+    IsOne,
+    IsPositive,
+    IsPositiveInfinity,
+    IsSignNegative,
+    IsSubnormal,
     IsZero,
+    IsZeroWithOrdering,
     Len,
+    MaxValue,
+    MinValue,
+    NegativeInfinity,
+    NotANumber,
     Numeric,
+    One,
     Real,
+    SaturatingToI128,
+    SaturatingToI16,
+    SaturatingToI32,
+    SaturatingToI64,
+    SaturatingToI8,
+    SaturatingToISize,
+    SaturatingToU128,
+    SaturatingToU16,
+    SaturatingToU32,
+    SaturatingToU64,
+    SaturatingToU8,
+    SaturatingToUSize,
     Scalar,
     Signed,
+    Signum,
+    ToF32,
     ToF64,
     ToI128,
     ToI16,
     ToI32,
     ToI64,
+    ToI8,
     ToISize,
     ToU128,
     ToU16,
     ToU32,
     ToU64,
+    ToU8,
     ToUSize,
+    TryToI128,
+    TryToI16,
+    TryToI32,
+    TryToI64,
+    TryToI8,
+    TryToISize,
+    TryToU128,
+    TryToU16,
+    TryToU32,
+    TryToU64,
+    TryToU8,
+    TryToUSize,
     Unsigned,
+    WrappingToI128,
+    WrappingToI16,
+    WrappingToI32,
+    WrappingToI64,
+    WrappingToI8,
+    WrappingToISize,
+    WrappingToU128,
+    WrappingToU16,
+    WrappingToU32,
+    WrappingToU64,
+    WrappingToU8,
+    WrappingToUSize,
+    Zero,
+};
+
+#[cfg(not(feature = "nostd"))]
+pub use traits::AsOsStr;
+
+pub use traits::primitive;
+
+pub use types::{
+    ConversionError,
+    ConversionErrorKind,
+    ExactSize,
+    NumberCategory,
+    Presence,
+};
+
+#[cfg(feature = "derive")]
+pub use base_traits_derive::{
+    As,
+    IsDefault,
+    IsEmpty,
+    IsZero,
+    Len,
+    To,
     Zero,
 };
 
 mod private {
-    #[allow(unused_imports)]
     pub(crate) use super::traits::Sealed;
 }
 
@@ -0,0 +1,152 @@
+// src/traits/as_f32.rs : `AsF32`
+
+/// Trait defining instance method `as_f32() : f32` that provides a
+/// cost-free conversion into `f32`.
+///
+/// It is expected that the implementing type "is-a" `f32` in a direct
+/// manner as well as in a logical manner.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-AsF32-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`f32`];
+pub trait AsF32 {
+    fn as_f32(&self) -> f32;
+}
+
+
+implement_forwarding_!(AsF32 {
+    fn as_f32(&self) -> f32;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsF32` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
+#[cfg(feature = "implement-AsF32-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    impl super::AsF32 for f32 {
+        #[inline]
+        fn as_f32(&self) -> f32 {
+            *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::AsF32;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : f32,
+        }
+
+        impl AsF32 for CustomType {
+            fn as_f32(&self) -> f32 {
+                self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_WHEN_ZERO() {
+            let ct = CustomType { value : 0.0 };
+
+            assert_eq!(0.0, ct.as_f32());
+
+            let ct = &ct;
+
+            assert_eq!(0.0, ct.as_f32());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO() {
+            let ct = CustomType { value : 1.5 };
+
+            assert_eq!(1.5, ct.as_f32());
+
+            let ct = &ct;
+
+            assert_eq!(1.5, ct.as_f32());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Box() {
+            let ct = Box::new(CustomType { value : 1.5 });
+
+            assert_eq!(1.5, ct.as_f32());
+
+            let ct : Box<dyn AsF32> = Box::new(CustomType { value : 1.5 });
+
+            assert_eq!(1.5, ct.as_f32());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Rc() {
+            let ct = Rc::new(CustomType { value : 1.5 });
+
+            assert_eq!(1.5, ct.as_f32());
+
+            let ct = &ct;
+
+            assert_eq!(1.5, ct.as_f32());
+        }
+    }
+
+
+    #[cfg(feature = "implement-AsF32-for-built_ins")]
+    #[test]
+    fn TEST_f32_AsF32() {
+
+        {
+            let v : f32 = 1.5;
+            let actual = v.as_f32();
+
+            assert_eq!(1.5, actual);
+        }
+
+        {
+            let v : &f32 = &1.5;
+            let actual = v.as_f32();
+
+            assert_eq!(1.5, actual);
+        }
+
+        {
+            let v = Box::new(1.5f32);
+            let actual = v.as_f32();
+
+            assert_eq!(1.5, actual);
+        }
+
+        {
+            let v = Rc::new(1.5f32);
+            let actual = v.as_f32();
+
+            assert_eq!(1.5, actual);
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -19,21 +19,14 @@ pub trait AsF64 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : AsF64 + ?Sized> AsF64 for Box<T> {
-    fn as_f64(&self) -> f64 {
-        (**self).as_f64()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : AsF64 + ?Sized> AsF64 for std::rc::Rc<T> {
-    fn as_f64(&self) -> f64 {
-        (**self).as_f64()
-    }
-}
+implement_forwarding_!(AsF64 {
+    fn as_f64(&self) -> f64;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsF64` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-AsF64-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
@@ -19,6 +19,14 @@ pub trait AsI128 {
 }
 
 
+implement_forwarding_!(AsI128 {
+    fn as_i128(&self) -> i128;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsI128` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-AsI128-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
@@ -0,0 +1,152 @@
+// src/traits/as_i16.rs : `AsI16`
+
+/// Trait defining instance method `as_i16() : i16` that provides a
+/// cost-free conversion into `i16`.
+///
+/// It is expected that the implementing type "is-a" `i16` in a direct
+/// manner as well as in a logical manner.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-AsI16-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`i16`];
+pub trait AsI16 {
+    fn as_i16(&self) -> i16;
+}
+
+
+implement_forwarding_!(AsI16 {
+    fn as_i16(&self) -> i16;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsI16` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
+#[cfg(feature = "implement-AsI16-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    impl super::AsI16 for i16 {
+        #[inline]
+        fn as_i16(&self) -> i16 {
+            *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::AsI16;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : i16,
+        }
+
+        impl AsI16 for CustomType {
+            fn as_i16(&self) -> i16 {
+                self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_WHEN_ZERO() {
+            let ct = CustomType { value : 0 };
+
+            assert_eq!(0, ct.as_i16());
+
+            let ct = &ct;
+
+            assert_eq!(0, ct.as_i16());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO() {
+            let ct = CustomType { value : -12345 };
+
+            assert_eq!(-12345, ct.as_i16());
+
+            let ct = &ct;
+
+            assert_eq!(-12345, ct.as_i16());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Box() {
+            let ct = Box::new(CustomType { value : -12345 });
+
+            assert_eq!(-12345, ct.as_i16());
+
+            let ct : Box<dyn AsI16> = Box::new(CustomType { value : -12345 });
+
+            assert_eq!(-12345, ct.as_i16());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Rc() {
+            let ct = Rc::new(CustomType { value : -12345 });
+
+            assert_eq!(-12345, ct.as_i16());
+
+            let ct = &ct;
+
+            assert_eq!(-12345, ct.as_i16());
+        }
+    }
+
+
+    #[cfg(feature = "implement-AsI16-for-built_ins")]
+    #[test]
+    fn TEST_i16_AsI16() {
+
+        {
+            let v : i16 = -12345;
+            let actual = v.as_i16();
+
+            assert_eq!(-12345, actual);
+        }
+
+        {
+            let v : &i16 = &-12345;
+            let actual = v.as_i16();
+
+            assert_eq!(-12345, actual);
+        }
+
+        {
+            let v = Box::new(-12345i16);
+            let actual = v.as_i16();
+
+            assert_eq!(-12345, actual);
+        }
+
+        {
+            let v = Rc::new(-12345i16);
+            let actual = v.as_i16();
+
+            assert_eq!(-12345, actual);
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
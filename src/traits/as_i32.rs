@@ -19,6 +19,14 @@ pub trait AsI32 {
 }
 
 
+implement_forwarding_!(AsI32 {
+    fn as_i32(&self) -> i32;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsI32` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-AsI32-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
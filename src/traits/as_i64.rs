@@ -19,6 +19,14 @@ pub trait AsI64 {
 }
 
 
+implement_forwarding_!(AsI64 {
+    fn as_i64(&self) -> i64;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsI64` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-AsI64-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
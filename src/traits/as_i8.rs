@@ -0,0 +1,152 @@
+// src/traits/as_i8.rs : `AsI8`
+
+/// Trait defining instance method `as_i8() : i8` that provides a
+/// cost-free conversion into `i8`.
+///
+/// It is expected that the implementing type "is-a" `i8` in a direct
+/// manner as well as in a logical manner.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-AsI8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`i8`];
+pub trait AsI8 {
+    fn as_i8(&self) -> i8;
+}
+
+
+implement_forwarding_!(AsI8 {
+    fn as_i8(&self) -> i8;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsI8` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
+#[cfg(feature = "implement-AsI8-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    impl super::AsI8 for i8 {
+        #[inline]
+        fn as_i8(&self) -> i8 {
+            *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::AsI8;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : i8,
+        }
+
+        impl AsI8 for CustomType {
+            fn as_i8(&self) -> i8 {
+                self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_WHEN_ZERO() {
+            let ct = CustomType { value : 0 };
+
+            assert_eq!(0, ct.as_i8());
+
+            let ct = &ct;
+
+            assert_eq!(0, ct.as_i8());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO() {
+            let ct = CustomType { value : -123 };
+
+            assert_eq!(-123, ct.as_i8());
+
+            let ct = &ct;
+
+            assert_eq!(-123, ct.as_i8());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Box() {
+            let ct = Box::new(CustomType { value : -123 });
+
+            assert_eq!(-123, ct.as_i8());
+
+            let ct : Box<dyn AsI8> = Box::new(CustomType { value : -123 });
+
+            assert_eq!(-123, ct.as_i8());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Rc() {
+            let ct = Rc::new(CustomType { value : -123 });
+
+            assert_eq!(-123, ct.as_i8());
+
+            let ct = &ct;
+
+            assert_eq!(-123, ct.as_i8());
+        }
+    }
+
+
+    #[cfg(feature = "implement-AsI8-for-built_ins")]
+    #[test]
+    fn TEST_i8_AsI8() {
+
+        {
+            let v : i8 = -123;
+            let actual = v.as_i8();
+
+            assert_eq!(-123, actual);
+        }
+
+        {
+            let v : &i8 = &-123;
+            let actual = v.as_i8();
+
+            assert_eq!(-123, actual);
+        }
+
+        {
+            let v = Box::new(-123i8);
+            let actual = v.as_i8();
+
+            assert_eq!(-123, actual);
+        }
+
+        {
+            let v = Rc::new(-123i8);
+            let actual = v.as_i8();
+
+            assert_eq!(-123, actual);
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
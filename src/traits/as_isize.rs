@@ -19,21 +19,14 @@ pub trait AsISize {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : AsISize + ?Sized> AsISize for Box<T> {
-    fn as_isize(&self) -> isize {
-        (**self).as_isize()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : AsISize + ?Sized> AsISize for std::rc::Rc<T> {
-    fn as_isize(&self) -> isize {
-        (**self).as_isize()
-    }
-}
+implement_forwarding_!(AsISize {
+    fn as_isize(&self) -> isize;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsISize` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-AsISize-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
@@ -185,7 +178,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).as_isize();
+                let instance = &value;
+                let actual = instance.as_isize();
 
                 assert_eq!(expected, actual);
             }
@@ -239,7 +233,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).as_isize();
+                let instance = &instance;
+                let actual = instance.as_isize();
 
                 assert_eq!(expected, actual);
             }
@@ -293,7 +288,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).as_isize();
+                let instance = &instance;
+                let actual = instance.as_isize();
 
                 assert_eq!(expected, actual);
             }
@@ -0,0 +1,216 @@
+// src/traits/as_os_str.rs : `AsOsStr`
+
+use std::ffi as std_ffi;
+
+
+/// Trait defining instance method `as_os_str() : &OsStr` that allows a type
+/// to expose its contiguous platform-string representation to client code.
+///
+/// This is the counterpart of [`crate::AsStr`] for types whose contents are
+/// not necessarily valid UTF-8, and so is available only when the feature
+/// `"nostd"` is NOT defined.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Standard FFI Types
+///
+/// If the feature `"implement-AsOsStr-for-standard_ffi_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`std::ffi::OsStr`];
+/// - [`std::ffi::OsString`];
+///
+/// ## Standard Path Types
+///
+/// If the feature `"implement-AsOsStr-for-standard_path_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`std::path::Path`];
+/// - [`std::path::PathBuf`];
+pub trait AsOsStr {
+    fn as_os_str(&self) -> &std_ffi::OsStr;
+}
+
+
+implement_forwarding_!(AsOsStr {
+    fn as_os_str(&self) -> &std_ffi::OsStr;
+});
+
+
+#[cfg(feature = "implement-AsOsStr-for-standard_ffi_types")]
+mod impl_for_std_ffi_types {
+    use std::ffi as std_ffi;
+
+
+    // OsStr
+
+    impl super::AsOsStr for std_ffi::OsStr {
+        #[inline]
+        fn as_os_str(&self) -> &std_ffi::OsStr {
+            self
+        }
+    }
+
+    impl super::AsOsStr for &std_ffi::OsStr {
+        #[inline]
+        fn as_os_str(&self) -> &std_ffi::OsStr {
+            self
+        }
+    }
+
+    // OsString
+
+    impl super::AsOsStr for std_ffi::OsString {
+        #[inline]
+        fn as_os_str(&self) -> &std_ffi::OsStr {
+            std_ffi::OsString::as_os_str(self)
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-AsOsStr-for-standard_path_types")]
+mod impl_for_std_path_types {
+    use std::{
+        ffi as std_ffi,
+        path as std_path,
+    };
+
+
+    // Path
+
+    impl super::AsOsStr for std_path::Path {
+        #[inline]
+        fn as_os_str(&self) -> &std_ffi::OsStr {
+            std_path::Path::as_os_str(self)
+        }
+    }
+
+    impl super::AsOsStr for &std_path::Path {
+        #[inline]
+        fn as_os_str(&self) -> &std_ffi::OsStr {
+            std_path::Path::as_os_str(self)
+        }
+    }
+
+    // PathBuf
+
+    impl super::AsOsStr for std_path::PathBuf {
+        #[inline]
+        fn as_os_str(&self) -> &std_ffi::OsStr {
+            std_path::Path::as_os_str(self)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::AsOsStr;
+
+    use std::ffi::OsStr;
+
+
+    #[allow(unused)]
+    fn as_AsOsStr<T : AsOsStr>(t : &T) -> &impl AsOsStr {
+        t
+    }
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::ffi::OsString;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            s : OsString,
+        }
+
+        impl AsOsStr for CustomType {
+            fn as_os_str(&self) -> &OsStr {
+                &self.s
+            }
+        }
+
+
+        #[test]
+        fn TEST_AS_VALUE() {
+            let ct = CustomType { s : "abc".into() };
+
+            assert_eq!("abc", ct.as_os_str());
+
+            let ct = &ct;
+
+            assert_eq!("abc", ct.as_os_str());
+        }
+
+        #[test]
+        fn TEST_IN_Box() {
+            let ct = Box::new(CustomType { s : "abc".into() });
+
+            assert_eq!("abc", as_AsOsStr(&ct).as_os_str());
+        }
+    }
+
+
+    #[cfg(feature = "implement-AsOsStr-for-standard_ffi_types")]
+    mod TEST_FFI_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::ffi::OsString;
+
+
+        #[test]
+        fn TEST_OsStr() {
+            let s = OsStr::new("abc");
+
+            assert_eq!("abc", as_AsOsStr(&s).as_os_str());
+        }
+
+        #[test]
+        fn TEST_OsString() {
+            let s = OsString::from("abc");
+
+            assert_eq!("abc", as_AsOsStr(&s).as_os_str());
+        }
+    }
+
+
+    #[cfg(feature = "implement-AsOsStr-for-standard_path_types")]
+    mod TEST_PATH_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::path::{
+            Path,
+            PathBuf,
+        };
+
+
+        #[test]
+        fn TEST_Path() {
+            let p = Path::new("foo/bar.txt");
+
+            assert_eq!("foo/bar.txt", as_AsOsStr(&p).as_os_str());
+        }
+
+        #[test]
+        fn TEST_PathBuf() {
+            let p = PathBuf::from("foo/bar.txt");
+
+            assert_eq!("foo/bar.txt", as_AsOsStr(&p).as_os_str());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
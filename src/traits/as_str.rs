@@ -23,19 +23,9 @@ pub trait AsStr {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : AsStr + ?Sized> AsStr for Box<T> {
-    fn as_str(&self) -> &str {
-        (**self).as_str()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : AsStr + ?Sized> AsStr for std::rc::Rc<T> {
-    fn as_str(&self) -> &str {
-        (**self).as_str()
-    }
-}
+implement_forwarding_!(AsStr {
+    fn as_str(&self) -> &str;
+});
 
 
 #[cfg(feature = "implement-AsStr-for-built_ins")]
@@ -56,8 +46,10 @@ mod impl_for_built_ins {
     }
 }
 
-#[cfg(all(not(feature = "nostd"), feature = "implement-AsStr-for-standard_collection_types"))]
+#[cfg(all(any(feature = "alloc", not(feature = "nostd")), feature = "implement-AsStr-for-standard_collection_types"))]
 mod impl_for_std_coll_types {
+    use alloc::string::String;
+
 
     impl super::AsStr for String {
         #[inline]
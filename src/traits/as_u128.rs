@@ -19,6 +19,14 @@ pub trait AsU128 {
 }
 
 
+implement_forwarding_!(AsU128 {
+    fn as_u128(&self) -> u128;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsU128` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-AsU128-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
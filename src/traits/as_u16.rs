@@ -0,0 +1,152 @@
+// src/traits/as_u16.rs : `AsU16`
+
+/// Trait defining instance method `as_u16() : u16` that provides a
+/// cost-free conversion into `u16`.
+///
+/// It is expected that the implementing type "is-a" `u16` in a direct
+/// manner as well as in a logical manner.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-AsU16-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`u16`];
+pub trait AsU16 {
+    fn as_u16(&self) -> u16;
+}
+
+
+implement_forwarding_!(AsU16 {
+    fn as_u16(&self) -> u16;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsU16` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
+#[cfg(feature = "implement-AsU16-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    impl super::AsU16 for u16 {
+        #[inline]
+        fn as_u16(&self) -> u16 {
+            *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::AsU16;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : u16,
+        }
+
+        impl AsU16 for CustomType {
+            fn as_u16(&self) -> u16 {
+                self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_WHEN_ZERO() {
+            let ct = CustomType { value : 0 };
+
+            assert_eq!(0, ct.as_u16());
+
+            let ct = &ct;
+
+            assert_eq!(0, ct.as_u16());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO() {
+            let ct = CustomType { value : 12345 };
+
+            assert_eq!(12345, ct.as_u16());
+
+            let ct = &ct;
+
+            assert_eq!(12345, ct.as_u16());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Box() {
+            let ct = Box::new(CustomType { value : 12345 });
+
+            assert_eq!(12345, ct.as_u16());
+
+            let ct : Box<dyn AsU16> = Box::new(CustomType { value : 12345 });
+
+            assert_eq!(12345, ct.as_u16());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Rc() {
+            let ct = Rc::new(CustomType { value : 12345 });
+
+            assert_eq!(12345, ct.as_u16());
+
+            let ct = &ct;
+
+            assert_eq!(12345, ct.as_u16());
+        }
+    }
+
+
+    #[cfg(feature = "implement-AsU16-for-built_ins")]
+    #[test]
+    fn TEST_u16_AsU16() {
+
+        {
+            let v : u16 = 12345;
+            let actual = v.as_u16();
+
+            assert_eq!(12345, actual);
+        }
+
+        {
+            let v : &u16 = &12345;
+            let actual = v.as_u16();
+
+            assert_eq!(12345, actual);
+        }
+
+        {
+            let v = Box::new(12345u16);
+            let actual = v.as_u16();
+
+            assert_eq!(12345, actual);
+        }
+
+        {
+            let v = Rc::new(12345u16);
+            let actual = v.as_u16();
+
+            assert_eq!(12345, actual);
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -19,6 +19,14 @@ pub trait AsU32 {
 }
 
 
+implement_forwarding_!(AsU32 {
+    fn as_u32(&self) -> u32;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsU32` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-AsU32-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
@@ -19,6 +19,14 @@ pub trait AsU64 {
 }
 
 
+implement_forwarding_!(AsU64 {
+    fn as_u64(&self) -> u64;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsU64` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-AsU64-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
@@ -0,0 +1,152 @@
+// src/traits/as_u8.rs : `AsU8`
+
+/// Trait defining instance method `as_u8() : u8` that provides a
+/// cost-free conversion into `u8`.
+///
+/// It is expected that the implementing type "is-a" `u8` in a direct
+/// manner as well as in a logical manner.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-AsU8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`u8`];
+pub trait AsU8 {
+    fn as_u8(&self) -> u8;
+}
+
+
+implement_forwarding_!(AsU8 {
+    fn as_u8(&self) -> u8;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsU8` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
+#[cfg(feature = "implement-AsU8-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    impl super::AsU8 for u8 {
+        #[inline]
+        fn as_u8(&self) -> u8 {
+            *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::AsU8;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : u8,
+        }
+
+        impl AsU8 for CustomType {
+            fn as_u8(&self) -> u8 {
+                self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_WHEN_ZERO() {
+            let ct = CustomType { value : 0 };
+
+            assert_eq!(0, ct.as_u8());
+
+            let ct = &ct;
+
+            assert_eq!(0, ct.as_u8());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO() {
+            let ct = CustomType { value : 123 };
+
+            assert_eq!(123, ct.as_u8());
+
+            let ct = &ct;
+
+            assert_eq!(123, ct.as_u8());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Box() {
+            let ct = Box::new(CustomType { value : 123 });
+
+            assert_eq!(123, ct.as_u8());
+
+            let ct : Box<dyn AsU8> = Box::new(CustomType { value : 123 });
+
+            assert_eq!(123, ct.as_u8());
+        }
+
+        #[test]
+        fn TEST_WHEN_NONZERO_IN_Rc() {
+            let ct = Rc::new(CustomType { value : 123 });
+
+            assert_eq!(123, ct.as_u8());
+
+            let ct = &ct;
+
+            assert_eq!(123, ct.as_u8());
+        }
+    }
+
+
+    #[cfg(feature = "implement-AsU8-for-built_ins")]
+    #[test]
+    fn TEST_u8_AsU8() {
+
+        {
+            let v : u8 = 123;
+            let actual = v.as_u8();
+
+            assert_eq!(123, actual);
+        }
+
+        {
+            let v : &u8 = &123;
+            let actual = v.as_u8();
+
+            assert_eq!(123, actual);
+        }
+
+        {
+            let v = Box::new(123u8);
+            let actual = v.as_u8();
+
+            assert_eq!(123, actual);
+        }
+
+        {
+            let v = Rc::new(123u8);
+            let actual = v.as_u8();
+
+            assert_eq!(123, actual);
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -19,21 +19,14 @@ pub trait AsUSize {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : AsUSize + ?Sized> AsUSize for Box<T> {
-    fn as_usize(&self) -> usize {
-        (**self).as_usize()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : AsUSize + ?Sized> AsUSize for std::rc::Rc<T> {
-    fn as_usize(&self) -> usize {
-        (**self).as_usize()
-    }
-}
+implement_forwarding_!(AsUSize {
+    fn as_usize(&self) -> usize;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `AsUSize` entry of the `AS` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-AsUSize-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
@@ -183,7 +176,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).as_usize();
+                let instance = &value;
+                let actual = instance.as_usize();
 
                 assert_eq!(expected, actual);
             }
@@ -237,7 +231,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).as_usize();
+                let instance = &instance;
+                let actual = instance.as_usize();
 
                 assert_eq!(expected, actual);
             }
@@ -291,7 +286,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).as_usize();
+                let instance = &instance;
+                let actual = instance.as_usize();
 
                 assert_eq!(expected, actual);
             }
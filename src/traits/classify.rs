@@ -0,0 +1,246 @@
+// src/traits/classify.rs : `Classify`
+
+use crate::NumberCategory;
+
+
+/// Trait defining instance method `classify() : NumberCategory` that
+/// obtains the category - zero, subnormal, normal, infinite, or "not a
+/// number" - of the implementing type instance.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-Classify-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+///
+/// NOTE: an integer is classified as [`NumberCategory::Zero`] if it is
+/// zero (as determined by [`IsZero`](crate::IsZero)), and as
+/// [`NumberCategory::Normal`] otherwise.
+pub trait Classify {
+    fn classify(&self) -> NumberCategory;
+}
+
+
+implement_forwarding_!(Classify {
+    fn classify(&self) -> NumberCategory;
+});
+
+
+#[cfg(feature = "implement-Classify-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    use crate::{
+        IsZero,
+        NumberCategory,
+    };
+
+
+    macro_rules! implement_Classify_for_integer_ {
+        ($type:tt) => {
+            impl super::Classify for $type {
+                #[inline]
+                fn classify(&self) -> NumberCategory {
+                    if IsZero::is_zero(self) {
+                        NumberCategory::Zero
+                    } else {
+                        NumberCategory::Normal
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_Classify_for_float_ {
+        ($type:tt) => {
+            impl super::Classify for $type {
+                #[inline]
+                fn classify(&self) -> NumberCategory {
+                    $type::classify(*self).into()
+                }
+            }
+        };
+    }
+
+    implement_Classify_for_integer_!(i8);
+    implement_Classify_for_integer_!(i16);
+    implement_Classify_for_integer_!(i32);
+    implement_Classify_for_integer_!(i64);
+    implement_Classify_for_integer_!(i128);
+    implement_Classify_for_integer_!(isize);
+
+    implement_Classify_for_integer_!(u8);
+    implement_Classify_for_integer_!(u16);
+    implement_Classify_for_integer_!(u32);
+    implement_Classify_for_integer_!(u64);
+    implement_Classify_for_integer_!(u128);
+    implement_Classify_for_integer_!(usize);
+
+    implement_Classify_for_float_!(f32);
+    implement_Classify_for_float_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::Classify;
+
+    use crate::NumberCategory;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// A fixed-point type, in which the value is held in hundredths.
+        #[derive(Debug)]
+        struct Price {
+            cents : i64,
+        }
+
+        impl Classify for Price {
+            fn classify(&self) -> NumberCategory {
+                if 0 == self.cents {
+                    NumberCategory::Zero
+                } else {
+                    NumberCategory::Normal
+                }
+            }
+        }
+
+
+        #[test]
+        fn TEST_Classify() {
+            assert_eq!(NumberCategory::Zero, Price { cents : 0 }.classify());
+            assert_eq!(NumberCategory::Normal, Price { cents : 1 }.classify());
+            assert_eq!(NumberCategory::Normal, Price { cents : -1234 }.classify());
+        }
+
+        #[test]
+        fn TEST_Classify_IN_Box() {
+            let price : Box<dyn Classify> = Box::new(Price { cents : 1234 });
+
+            assert_eq!(NumberCategory::Normal, price.classify());
+        }
+
+        #[test]
+        fn TEST_Classify_IN_Rc() {
+            let price = Rc::new(Price { cents : 0 });
+
+            assert_eq!(NumberCategory::Zero, price.classify());
+        }
+    }
+
+
+    #[cfg(feature = "implement-Classify-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// Classifies via `Classify`, for use in generic code.
+        fn classify_all<T : Classify>(values : &[T]) -> Vec<NumberCategory> {
+            values.iter().map(Classify::classify).collect()
+        }
+
+
+        #[test]
+        fn TEST_integers() {
+            assert_eq!(NumberCategory::Zero, 0i8.classify());
+            assert_eq!(NumberCategory::Normal, i8::MIN.classify());
+            assert_eq!(NumberCategory::Normal, i8::MAX.classify());
+
+            assert_eq!(NumberCategory::Zero, 0i32.classify());
+            assert_eq!(NumberCategory::Normal, (-1i32).classify());
+
+            assert_eq!(NumberCategory::Zero, 0i128.classify());
+            assert_eq!(NumberCategory::Normal, i128::MAX.classify());
+
+            assert_eq!(NumberCategory::Zero, 0isize.classify());
+            assert_eq!(NumberCategory::Normal, 1isize.classify());
+
+            assert_eq!(NumberCategory::Zero, 0u8.classify());
+            assert_eq!(NumberCategory::Normal, u8::MAX.classify());
+
+            assert_eq!(NumberCategory::Zero, 0u64.classify());
+            assert_eq!(NumberCategory::Normal, 1u64.classify());
+
+            assert_eq!(NumberCategory::Zero, 0usize.classify());
+            assert_eq!(NumberCategory::Normal, usize::MAX.classify());
+        }
+
+        #[test]
+        fn TEST_f32() {
+            assert_eq!(
+                vec![
+                    NumberCategory::Zero,
+                    NumberCategory::Zero,
+                    NumberCategory::Subnormal,
+                    NumberCategory::Normal,
+                    NumberCategory::Normal,
+                    NumberCategory::Infinite,
+                    NumberCategory::Infinite,
+                    NumberCategory::NaN,
+                ],
+                classify_all(&[
+                    0.0f32,
+                    -0.0,
+                    f32::MIN_POSITIVE / 2.0,
+                    f32::MIN_POSITIVE,
+                    f32::MAX,
+                    f32::INFINITY,
+                    f32::NEG_INFINITY,
+                    f32::NAN,
+                ])
+            );
+        }
+
+        #[test]
+        fn TEST_f64() {
+            assert_eq!(
+                vec![
+                    NumberCategory::Zero,
+                    NumberCategory::Zero,
+                    NumberCategory::Subnormal,
+                    NumberCategory::Normal,
+                    NumberCategory::Normal,
+                    NumberCategory::Infinite,
+                    NumberCategory::Infinite,
+                    NumberCategory::NaN,
+                ],
+                classify_all(&[
+                    0.0f64,
+                    -0.0,
+                    f64::MIN_POSITIVE / 2.0,
+                    f64::MIN_POSITIVE,
+                    f64::MAX,
+                    f64::INFINITY,
+                    f64::NEG_INFINITY,
+                    f64::NAN,
+                ])
+            );
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert_eq!(NumberCategory::Zero, Box::new(0u32).classify());
+            assert_eq!(NumberCategory::Normal, Rc::new(-7i16).classify());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,162 @@
+// src/traits/component_count.rs : `ComponentCount`
+
+/// Trait defining instance method `component_count() : usize` that
+/// indicates the number of components - e.g. the directories and file name
+/// of a path - of which the implementing type instance is composed.
+///
+/// This differs from [`crate::Len`], which, for the path types, indicates
+/// the length (in bytes) of the underlying string.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Standard Path Types
+///
+/// If the feature `"implement-ComponentCount-for-standard_path_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`std::path::Path`];
+/// - [`std::path::PathBuf`];
+///
+/// The count is that of the items obtained from `Path#components()`, so
+/// that, for example, `"/usr/lib/"` has three components (`RootDir`,
+/// `"usr"`, and `"lib"`) and `"./a/b"` has three components (`CurDir`,
+/// `"a"`, and `"b"`).
+pub trait ComponentCount {
+    fn component_count(&self) -> usize;
+}
+
+
+implement_forwarding_!(ComponentCount {
+    fn component_count(&self) -> usize;
+});
+
+
+#[cfg(all(not(feature = "nostd"), feature = "implement-ComponentCount-for-standard_path_types"))]
+mod impl_for_std_path_types {
+    #![allow(non_snake_case)]
+
+    use std::path as std_path;
+
+
+    mod isolate_ {
+        #![allow(non_snake_case)]
+
+        use std::path as std_path;
+
+
+        #[inline]
+        pub(super) fn get_component_count_Path_(path : &std_path::Path) -> usize {
+            path.components().count()
+        }
+    }
+
+
+    // Path
+
+    impl super::ComponentCount for std_path::Path {
+        #[inline]
+        fn component_count(&self) -> usize {
+            isolate_::get_component_count_Path_(self)
+        }
+    }
+
+    impl super::ComponentCount for &std_path::Path {
+        #[inline]
+        fn component_count(&self) -> usize {
+            isolate_::get_component_count_Path_(self)
+        }
+    }
+
+    // PathBuf
+
+    impl super::ComponentCount for std_path::PathBuf {
+        #[inline]
+        fn component_count(&self) -> usize {
+            isolate_::get_component_count_Path_(self)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::ComponentCount;
+
+
+    #[allow(unused)]
+    fn as_ComponentCount<T : ComponentCount>(t : &T) -> &impl ComponentCount {
+        t
+    }
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            parts : Vec<String>,
+        }
+
+        impl ComponentCount for CustomType {
+            fn component_count(&self) -> usize {
+                self.parts.len()
+            }
+        }
+
+
+        #[test]
+        fn TEST_component_count() {
+            let ct = CustomType { parts : vec![ "a".into(), "b".into() ] };
+
+            assert_eq!(2, ct.component_count());
+
+            let ct = Box::new(ct);
+
+            assert_eq!(2, as_ComponentCount(&ct).component_count());
+        }
+    }
+
+
+    #[cfg(all(not(feature = "nostd"), feature = "implement-ComponentCount-for-standard_path_types"))]
+    mod TEST_PATH_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::path::{
+            Path,
+            PathBuf,
+        };
+
+
+        #[test]
+        fn TEST_Path() {
+            assert_eq!(0, as_ComponentCount(&Path::new("")).component_count());
+            assert_eq!(1, as_ComponentCount(&Path::new("a")).component_count());
+            assert_eq!(3, as_ComponentCount(&Path::new("a/b/c.txt")).component_count());
+            assert_eq!(3, as_ComponentCount(&Path::new("/usr/lib/")).component_count());
+            assert_eq!(3, as_ComponentCount(&Path::new("./a/b")).component_count());
+        }
+
+        #[test]
+        fn TEST_PathBuf() {
+            let mut p = PathBuf::new();
+
+            assert_eq!(0, p.component_count());
+
+            p.push("a");
+            p.push("b");
+
+            assert_eq!(2, p.component_count());
+            assert_eq!(2, as_ComponentCount(&p).component_count());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,265 @@
+// src/traits/elapsed.rs : `Elapsed`
+
+use core::time as core_time;
+
+
+/// Trait defining instance method
+/// `elapsed_since(earlier : &Self) : Option<Duration>` that obtains the
+/// amount of time that has elapsed from `earlier` to the implementing type
+/// instance.
+///
+/// The result is `None` if `earlier` is later than the instance (or if the
+/// elapsed time cannot be represented).
+///
+/// Since the result depends only on the two given instances - and not on
+/// the current time - it is deterministic; the elapsed time up to "now" is
+/// obtained by passing the current time, as in
+/// `SystemTime::now().elapsed_since(&then)`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-Elapsed-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::time::Duration`];
+/// - [`std::time::Instant`];
+/// - [`std::time::SystemTime`];
+///
+/// NOTE: since `earlier` is of type `&Self`, this is implemented for the
+/// standard pointer and wrapper types - `Box<T>`, `Rc<T>`, `Arc<T>`,
+/// `Cow<'_, T>`, `&mut T`, and `Pin<P>` - by forwarding to the pointees of
+/// both instances.
+pub trait Elapsed {
+    fn elapsed_since(
+        &self,
+        earlier : &Self,
+    ) -> Option<core_time::Duration>;
+}
+
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : Elapsed + ?Sized> Elapsed for alloc::boxed::Box<T> {
+    fn elapsed_since(
+        &self,
+        earlier : &Self,
+    ) -> Option<core_time::Duration> {
+        (**self).elapsed_since(&**earlier)
+    }
+}
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : Elapsed + ?Sized> Elapsed for alloc::rc::Rc<T> {
+    fn elapsed_since(
+        &self,
+        earlier : &Self,
+    ) -> Option<core_time::Duration> {
+        (**self).elapsed_since(&**earlier)
+    }
+}
+
+#[cfg(all(any(feature = "alloc", not(feature = "nostd")), target_has_atomic = "ptr"))]
+impl<T : Elapsed + ?Sized> Elapsed for alloc::sync::Arc<T> {
+    fn elapsed_since(
+        &self,
+        earlier : &Self,
+    ) -> Option<core_time::Duration> {
+        (**self).elapsed_since(&**earlier)
+    }
+}
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : Elapsed + alloc::borrow::ToOwned + ?Sized> Elapsed for alloc::borrow::Cow<'_, T> {
+    fn elapsed_since(
+        &self,
+        earlier : &Self,
+    ) -> Option<core_time::Duration> {
+        (**self).elapsed_since(&**earlier)
+    }
+}
+
+impl<T : Elapsed + ?Sized> Elapsed for &mut T {
+    fn elapsed_since(
+        &self,
+        earlier : &Self,
+    ) -> Option<core_time::Duration> {
+        (**self).elapsed_since(&**earlier)
+    }
+}
+
+impl<P> Elapsed for core::pin::Pin<P>
+where
+    P : core::ops::Deref,
+    P::Target : Elapsed,
+{
+    fn elapsed_since(
+        &self,
+        earlier : &Self,
+    ) -> Option<core_time::Duration> {
+        (**self).elapsed_since(&**earlier)
+    }
+}
+
+
+#[cfg(feature = "implement-Elapsed-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use super::Elapsed;
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl Elapsed for core_time::Duration {
+        #[inline]
+        fn elapsed_since(
+            &self,
+            earlier : &Self,
+        ) -> Option<core_time::Duration> {
+            self.checked_sub(*earlier)
+        }
+    }
+
+    // Instant
+
+    #[cfg(not(feature = "nostd"))]
+    impl Elapsed for std::time::Instant {
+        #[inline]
+        fn elapsed_since(
+            &self,
+            earlier : &Self,
+        ) -> Option<core_time::Duration> {
+            self.checked_duration_since(*earlier)
+        }
+    }
+
+    // SystemTime
+
+    #[cfg(not(feature = "nostd"))]
+    impl Elapsed for std::time::SystemTime {
+        #[inline]
+        fn elapsed_since(
+            &self,
+            earlier : &Self,
+        ) -> Option<core_time::Duration> {
+            self.duration_since(*earlier).ok()
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::Elapsed;
+
+    use std::time::Duration;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::{
+            rc::Rc,
+            sync::Arc,
+        };
+
+
+        #[derive(Debug)]
+        struct Tick(u64);
+
+        impl Elapsed for Tick {
+            fn elapsed_since(
+                &self,
+                earlier : &Self,
+            ) -> Option<Duration> {
+                self.0.checked_sub(earlier.0).map(Duration::from_millis)
+            }
+        }
+
+
+        #[test]
+        fn TEST_elapsed_since() {
+            assert_eq!(Some(Duration::from_millis(5)), Tick(15).elapsed_since(&Tick(10)));
+            assert_eq!(None, Tick(10).elapsed_since(&Tick(15)));
+        }
+
+        #[test]
+        fn TEST_elapsed_since_IN_POINTERS() {
+            fn elapsed_since_<T : Elapsed>(
+                later : &T,
+                earlier : &T,
+            ) -> Option<Duration> {
+                later.elapsed_since(earlier)
+            }
+
+            assert_eq!(Some(Duration::from_millis(5)), elapsed_since_(&Box::new(Tick(15)), &Box::new(Tick(10))));
+            assert_eq!(None, elapsed_since_(&Rc::new(Tick(10)), &Rc::new(Tick(15))));
+            assert_eq!(Some(Duration::from_millis(1)), elapsed_since_(&Arc::new(Tick(11)), &Arc::new(Tick(10))));
+            assert_eq!(Some(Duration::ZERO), elapsed_since_(&Box::pin(Tick(3)), &Box::pin(Tick(3))));
+        }
+    }
+
+
+    #[cfg(feature = "implement-Elapsed-for-standard_time_types")]
+    mod TEST_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        #[cfg(not(feature = "nostd"))]
+        use std::time::{
+            Instant,
+            SystemTime,
+            UNIX_EPOCH,
+        };
+
+
+        #[test]
+        fn TEST_Duration() {
+            let d1 = Duration::from_secs(10);
+            let d2 = Duration::from_secs(12);
+
+            assert_eq!(Some(Duration::from_secs(2)), d2.elapsed_since(&d1));
+            assert_eq!(Some(Duration::ZERO), d2.elapsed_since(&d2));
+            assert_eq!(None, d1.elapsed_since(&d2));
+        }
+
+        #[cfg(not(feature = "nostd"))]
+        #[test]
+        fn TEST_Instant() {
+            // NOTE: only the difference between the instants matters, so
+            // the arbitrary base obtained from `now()` is immaterial
+            let base = Instant::now();
+            let later = base + Duration::from_millis(250);
+
+            assert_eq!(Some(Duration::from_millis(250)), later.elapsed_since(&base));
+            assert_eq!(None, base.elapsed_since(&later));
+        }
+
+        #[cfg(not(feature = "nostd"))]
+        #[test]
+        fn TEST_SystemTime() {
+            let t1 = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+            let t2 = t1 + Duration::from_secs(60);
+
+            assert_eq!(Some(Duration::from_secs(60)), t2.elapsed_since(&t1));
+            assert_eq!(Some(Duration::from_secs(1_000_000_060)), t2.elapsed_since(&UNIX_EPOCH));
+            assert_eq!(None, t1.elapsed_since(&t2));
+
+            let before : SystemTime = UNIX_EPOCH - Duration::from_secs(1);
+
+            assert_eq!(None, before.elapsed_since(&UNIX_EPOCH));
+            assert_eq!(Some(Duration::from_secs(1)), UNIX_EPOCH.elapsed_since(&before));
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -1,9 +1,25 @@
 // src/traits/integer.rs : `Integer`
 
-pub trait Integer {
+use crate::Numeric;
+
+
+/// Marker trait for integral numeric types, which, in addition to the
+/// facilities implied by [`Numeric`], are totally ordered.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-Integer-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+pub trait Integer : Numeric + Ord + Eq {
 }
 
 
+#[cfg(feature = "implement-Integer-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
 
@@ -26,5 +42,68 @@ mod impl_for_built_ins {
 }
 
 
-// ///////////////////////////// end of file //////////////////////////// //
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    /// Uses only the facilities implied by `Integer`.
+    fn gcd<T : Integer>(
+        a : T,
+        b : T,
+    ) -> T {
+        if b.is_zero() {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    fn largest<T : Integer>(values : &[T]) -> Option<T> {
+        values.iter().copied().max()
+    }
+
+    fn requires_Numeric<T : Numeric>(t : T) -> T {
+        t
+    }
+
+    fn Integer_is_Numeric<T : Integer>(t : T) -> T {
+        requires_Numeric(t)
+    }
 
+
+    #[cfg(feature = "implement-Integer-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_gcd() {
+            assert_eq!(6, gcd(12i8, 18));
+            assert_eq!(6, gcd(12u8, 18));
+            assert_eq!(1, gcd(17i64, 5));
+            assert_eq!(5, gcd(0u128, 5));
+            assert_eq!(4, gcd(8usize, 12));
+        }
+
+        #[test]
+        fn TEST_largest() {
+            assert_eq!(None, largest::<i32>(&[]));
+            assert_eq!(Some(3), largest(&[1i32, -2, 3]));
+            assert_eq!(Some(u16::MAX), largest(&[u16::MAX, 0]));
+        }
+
+        #[test]
+        fn TEST_Integer_is_Numeric() {
+            assert_eq!(-1, Integer_is_Numeric(-1isize));
+            assert_eq!(1, Integer_is_Numeric(1u32));
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
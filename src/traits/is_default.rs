@@ -2,25 +2,471 @@
 
 /// Trait defining instance method `is_default() : bool` that allows a type
 /// instance to indicate whether it holds the "default" value.
+///
+/// For a type that implements [`Default`] and [`PartialEq`], this may be
+/// obtained - as `*self == Self::default()` - by `#[derive(IsDefault)]`, if
+/// the feature `"derive"` is defined.
+///
+/// NOTE: it is not implemented for the network address types (such as
+/// [`core::net::Ipv4Addr`]), since they do not implement [`Default`]; for
+/// these, the "unset" value may be detected by [`crate::IsZero`].
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsDefault-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], ..., [`i128`], [`isize`];
+/// - [`u8`], ..., [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+/// - [`bool`];
+/// - [`char`];
+/// - [`str`];
+/// - `()`;
+/// - `(T1,)`, ..., `(T1, ..., T12)`, where each `Ti : IsDefault`;
+/// - `[T; N]`, where `T : IsDefault`;
+///
+/// A tuple or an array is default if each of its elements is default.
+///
+/// ## Standard Collection Types
+///
+/// If the feature `"implement-IsDefault-for-standard_collection_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`alloc::collections::BTreeMap`];
+/// - [`alloc::collections::BTreeSet`];
+/// - [`alloc::collections::BinaryHeap`];
+/// - [`std::collections::HashMap`];
+/// - [`std::collections::HashSet`];
+/// - [`alloc::collections::LinkedList`];
+/// - [`String`];
+/// - [`Vec`];
+/// - [`alloc::collections::VecDeque`];
+///
+/// A collection is default if it is empty.
+///
+/// NOTE: when the feature `"nostd"` is defined, these implementations are
+/// retained only if the feature `"alloc"` is also defined, in which case
+/// those for [`std::collections::HashMap`] and [`std::collections::HashSet`]
+/// are omitted.
+///
+/// ## Standard Option Types
+///
+/// If the feature `"implement-IsDefault-for-standard_option_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`Option<T>`], which is default if it is `None`;
 pub trait IsDefault {
 	fn is_default(&self) -> bool;
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : IsDefault + ?Sized> IsDefault for Box<T> {
-    fn is_default(&self) -> bool {
-        (**self).is_default()
+implement_forwarding_!(IsDefault {
+    fn is_default(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsDefault-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    use super::IsDefault;
+
+
+    macro_rules! implement_IsDefault_ {
+        ($type:tt, $default_value:expr) => {
+            impl IsDefault for $type {
+                #[inline]
+                fn is_default(&self) -> bool {
+                    $default_value == *self
+                }
+            }
+        };
+    }
+
+    implement_IsDefault_!(i8, 0);
+    implement_IsDefault_!(i16, 0);
+    implement_IsDefault_!(i32, 0);
+    implement_IsDefault_!(i64, 0);
+    implement_IsDefault_!(i128, 0);
+
+    implement_IsDefault_!(u8, 0);
+    implement_IsDefault_!(u16, 0);
+    implement_IsDefault_!(u32, 0);
+    implement_IsDefault_!(u64, 0);
+    implement_IsDefault_!(u128, 0);
+
+    implement_IsDefault_!(isize, 0);
+    implement_IsDefault_!(usize, 0);
+
+    implement_IsDefault_!(f32, 0.0);
+    implement_IsDefault_!(f64, 0.0);
+
+    implement_IsDefault_!(bool, false);
+
+    implement_IsDefault_!(char, '\0');
+
+
+    // str
+
+    impl IsDefault for str {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    impl IsDefault for &str {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+
+    // ()
+
+    impl IsDefault for () {
+        #[inline]
+        fn is_default(&self) -> bool {
+            true
+        }
+    }
+
+
+    // (T1, ..., Tn)
+
+    macro_rules! implement_IsDefault_for_tuple_ {
+        ($($type_param:ident : $index:tt),+) => {
+            impl<$($type_param : IsDefault),+> IsDefault for ($($type_param,)+) {
+                #[inline]
+                fn is_default(&self) -> bool {
+                    $(self.$index.is_default())&&+
+                }
+            }
+        };
+    }
+
+    implement_IsDefault_for_tuple_!(T1 : 0);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2, T4 : 3);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2, T4 : 3, T5 : 4);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2, T4 : 3, T5 : 4, T6 : 5);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2, T4 : 3, T5 : 4, T6 : 5, T7 : 6);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2, T4 : 3, T5 : 4, T6 : 5, T7 : 6, T8 : 7);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2, T4 : 3, T5 : 4, T6 : 5, T7 : 6, T8 : 7, T9 : 8);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2, T4 : 3, T5 : 4, T6 : 5, T7 : 6, T8 : 7, T9 : 8, T10 : 9);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2, T4 : 3, T5 : 4, T6 : 5, T7 : 6, T8 : 7, T9 : 8, T10 : 9, T11 : 10);
+    implement_IsDefault_for_tuple_!(T1 : 0, T2 : 1, T3 : 2, T4 : 3, T5 : 4, T6 : 5, T7 : 6, T8 : 7, T9 : 8, T10 : 9, T11 : 10, T12 : 11);
+
+
+    // [T; N]
+
+    impl<T : IsDefault, const N : usize> IsDefault for [T; N] {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.iter().all(IsDefault::is_default)
+        }
+    }
+}
+
+
+#[cfg(all(any(feature = "alloc", not(feature = "nostd")), feature = "implement-IsDefault-for-standard_collection_types"))]
+mod impl_for_std_coll_types {
+    #![allow(non_snake_case)]
+
+    use super::IsDefault;
+
+    use alloc::{
+        collections as alloc_collections,
+        string::String,
+        vec::Vec,
+    };
+    #[cfg(not(feature = "nostd"))]
+    use std::collections as std_collections;
+
+
+    // BTreeMap<>
+
+    impl<K, V> IsDefault for alloc_collections::BTreeMap<K, V> {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    // BTreeSet<>
+
+    impl<T> IsDefault for alloc_collections::BTreeSet<T> {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    // BinaryHeap<>
+
+    impl<T> IsDefault for alloc_collections::BinaryHeap<T> {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    // HashMap<>
+
+    #[cfg(not(feature = "nostd"))]
+    impl<K, V, S> IsDefault for std_collections::HashMap<K, V, S> {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    // HashSet<>
+
+    #[cfg(not(feature = "nostd"))]
+    impl<T, S> IsDefault for std_collections::HashSet<T, S> {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    // LinkedList<>
+
+    impl<T> IsDefault for alloc_collections::LinkedList<T> {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    // String
+
+    impl IsDefault for String {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    // Vec<>
+
+    impl<T> IsDefault for Vec<T> {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
+    }
+
+    // VecDeque<>
+
+    impl<T> IsDefault for alloc_collections::VecDeque<T> {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_empty()
+        }
     }
 }
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : IsDefault + ?Sized> IsDefault for std::rc::Rc<T> {
-    fn is_default(&self) -> bool {
-        (**self).is_default()
+
+#[cfg(feature = "implement-IsDefault-for-standard_option_types")]
+mod impl_for_std_option_types {
+
+    // Option<>
+
+    impl<T> super::IsDefault for Option<T> {
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_none()
+        }
     }
 }
 
 
-// ///////////////////////////// end of file //////////////////////////// //
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsDefault;
+
+
+    #[allow(unused)]
+    fn as_IsDefault<T : IsDefault>(t : &T) -> &impl IsDefault {
+        t
+    }
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : i32,
+        }
+
+        impl IsDefault for CustomType {
+            fn is_default(&self) -> bool {
+                0 == self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_is_default() {
+            assert!(CustomType { value : 0 }.is_default());
+            assert!(!CustomType { value : 1 }.is_default());
+
+            let ct = Box::new(CustomType { value : 0 });
+
+            assert!(as_IsDefault(&ct).is_default());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsDefault-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
 
+        use super::*;
+
+
+        #[test]
+        fn TEST_MATCHES_Default() {
+            fn matches_<T : Default + IsDefault>() -> bool {
+                T::default().is_default()
+            }
+
+            assert!(matches_::<i8>());
+            assert!(matches_::<i16>());
+            assert!(matches_::<i32>());
+            assert!(matches_::<i64>());
+            assert!(matches_::<i128>());
+            assert!(matches_::<isize>());
+            assert!(matches_::<u8>());
+            assert!(matches_::<u16>());
+            assert!(matches_::<u32>());
+            assert!(matches_::<u64>());
+            assert!(matches_::<u128>());
+            assert!(matches_::<usize>());
+            assert!(matches_::<f32>());
+            assert!(matches_::<f64>());
+            assert!(matches_::<bool>());
+            assert!(matches_::<char>());
+            assert!(matches_::<&str>());
+            assert!(matches_::<()>());
+            assert!(matches_::<(i32, bool, char)>());
+            assert!(matches_::<[u8; 4]>());
+        }
+
+        #[test]
+        fn TEST_NOT_DEFAULT() {
+            assert!(!(-1i32).is_default());
+            assert!(!1u64.is_default());
+            assert!(!f64::NAN.is_default());
+            assert!(!0.5f32.is_default());
+            assert!(!true.is_default());
+            assert!(!'a'.is_default());
+            assert!(!"abc".is_default());
+        }
+
+        #[test]
+        fn TEST_TUPLES() {
+            assert!((0,).is_default());
+            assert!(!(1,).is_default());
+            assert!((0, false, '\0', "").is_default());
+            assert!(!(0, false, '\0', "a").is_default());
+            assert!((0u8, 0u16, 0u32, 0u64, 0u128, 0usize, 0i8, 0i16, 0i32, 0i64, 0i128, 0isize).is_default());
+            assert!(!(0u8, 0u16, 0u32, 0u64, 0u128, 0usize, 0i8, 0i16, 0i32, 0i64, 0i128, 1isize).is_default());
+        }
+
+        #[test]
+        fn TEST_ARRAYS() {
+            assert!([0i32; 0].is_default());
+            assert!([0i32; 3].is_default());
+            assert!(![0, 0, 1].is_default());
+            assert!([[false; 2]; 40].is_default());
+            assert!(as_IsDefault(&[(0, ""); 5]).is_default());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsDefault-for-standard_collection_types")]
+    mod TEST_STANDARD_COLLECTION_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::collections::{
+            BTreeMap,
+            BTreeSet,
+            BinaryHeap,
+            LinkedList,
+            VecDeque,
+        };
+
+
+        #[test]
+        fn TEST_EMPTY() {
+            assert!(BTreeMap::<i32, i32>::new().is_default());
+            assert!(BTreeSet::<i32>::new().is_default());
+            assert!(BinaryHeap::<i32>::new().is_default());
+            assert!(LinkedList::<i32>::new().is_default());
+            assert!(String::new().is_default());
+            assert!(Vec::<i32>::new().is_default());
+            assert!(VecDeque::<i32>::new().is_default());
+        }
+
+        #[test]
+        fn TEST_NONEMPTY() {
+            assert!(!BTreeMap::from([ (1, 2) ]).is_default());
+            assert!(!BTreeSet::from([ 1 ]).is_default());
+            assert!(!BinaryHeap::from([ 1 ]).is_default());
+            assert!(!LinkedList::from([ 1 ]).is_default());
+            assert!(!String::from("abc").is_default());
+            assert!(!vec![ 0 ].is_default());
+            assert!(!VecDeque::from([ 1 ]).is_default());
+        }
+
+        #[cfg(not(feature = "nostd"))]
+        #[test]
+        fn TEST_HASHED() {
+            use std::collections::{
+                HashMap,
+                HashSet,
+            };
+
+            assert!(HashMap::<i32, i32>::new().is_default());
+            assert!(HashSet::<i32>::new().is_default());
+
+            assert!(!HashMap::from([ (1, 2) ]).is_default());
+            assert!(!HashSet::from([ 1 ]).is_default());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsDefault-for-standard_option_types")]
+    mod TEST_STANDARD_OPTION_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_Option() {
+            assert!(None::<i32>.is_default());
+            assert!(!Some(0).is_default());
+            assert!(as_IsDefault(&Option::<String>::None).is_default());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -19,23 +19,49 @@
 /// If the feature `"implement-IsEmpty-for-standard_collection_types"`
 /// is defined (as it is by `"default"`), then this is also implemented
 /// for the following types:
-/// - [`std::collections::BTreeMap`];
-/// - [`std::collections::BTreeSet`];
-/// - [`std::collections::BinaryHeap`];
+/// - [`alloc::collections::BTreeMap`];
+/// - [`alloc::collections::BTreeSet`];
+/// - [`alloc::collections::BinaryHeap`];
 /// - [`std::collections::HashMap`];
 /// - [`std::collections::HashSet`];
-/// - [`std::collections::LinkedList`];
+/// - [`alloc::collections::LinkedList`];
 /// - [`String`];
 /// - [`Vec`];
-/// - [`std::collections::VecDeque`];
+/// - [`alloc::collections::VecDeque`];
+///
+/// NOTE: when the feature `"nostd"` is defined, these implementations are
+/// retained only if the feature `"alloc"` is also defined, in which case
+/// those for [`std::collections::HashMap`] and [`std::collections::HashSet`]
+/// are omitted.
 ///
 /// ## Standard FFI Types
 ///
 /// If the feature `"implement-IsEmpty-for-standard_ffi_types"`
 /// is defined (as it is by `"default"`), then this is also implemented
 /// for the following types:
-/// - [`std::ffi::CStr`];
-/// - [`std::ffi::CString`];
+/// - [`core::ffi::CStr`];
+/// - [`alloc::ffi::CString`];
+/// - [`std::ffi::OsStr`];
+/// - [`std::ffi::OsString`];
+///
+/// ## Standard Iterator Types
+///
+/// If the feature `"implement-IsEmpty-for-standard_iter_types"`
+/// is defined (which is NOT by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::iter::Empty`];
+/// - [`core::iter::Once`];
+///
+/// ## Standard Option Types
+///
+/// If the feature `"implement-IsEmpty-for-standard_option_types"`
+/// is defined (which is NOT by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`Option`];
+///
+/// An instance of `Option<T>` is empty if it is `None`, and otherwise has
+/// the emptiness of its value. To treat instead any `Some` as non-empty,
+/// use [`crate::Presence`].
 ///
 /// ## Standard Path Types
 ///
@@ -48,11 +74,16 @@
 /// ## Standard Process Types
 ///
 /// If the feature `"implement-IsEmpty-for-standard_process_types"`
-/// is defined (as it is by `"default"`), then this is also implemented
+/// is defined (which is NOT by `"default"`), then this is also implemented
 /// for the following types:
 /// - [`std::process::CommandArgs`];
 /// - [`std::process::CommandEnvs`];
 ///
+/// These are available on the stable toolchain. If the feature
+/// `"experimental-exact_size_is_empty"` is also defined, then they are
+/// obtained from the (nightly-only) `ExactSizeIterator#is_empty()`;
+/// otherwise they are obtained from `ExactSizeIterator#len()`.
+///
 /// ## Standard Range Types
 ///
 /// If the feature `"implement-IsEmpty-for-standard_range_types"`
@@ -64,6 +95,13 @@
 /// - [`std::ops::RangeInclusive`];
 /// - [`std::ops::RangeTo`];
 ///
+/// ## Standard Result Types
+///
+/// If the feature `"implement-IsEmpty-for-standard_result_types"`
+/// is defined (which is NOT by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`Result`];
+///
 /// ## Standard Time Types
 ///
 /// If the feature `"implement-IsEmpty-for-standard_time_types"`
@@ -75,19 +113,9 @@ pub trait IsEmpty {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : IsEmpty + ?Sized> IsEmpty for Box<T> {
-    fn is_empty(&self) -> bool {
-        (**self).is_empty()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : IsEmpty + ?Sized> IsEmpty for std::rc::Rc<T> {
-    fn is_empty(&self) -> bool {
-        (**self).is_empty()
-    }
-}
+implement_forwarding_!(IsEmpty {
+    fn is_empty(&self) -> bool;
+});
 
 
 #[cfg(feature = "implement-IsEmpty-for-built_ins")]
@@ -160,44 +188,54 @@ mod impl_for_built_ins {
 }
 
 
-#[cfg(all(not(feature = "nostd"), feature = "implement-IsEmpty-for-standard_collection_types"))]
+#[cfg(all(any(feature = "alloc", not(feature = "nostd")), feature = "implement-IsEmpty-for-standard_collection_types"))]
 mod impl_for_std_coll_types {
+    use alloc::{
+        collections as alloc_collections,
+        string::String,
+        vec::Vec,
+    };
+    #[cfg(not(feature = "nostd"))]
     use std::collections as std_collections;
 
 
     mod isolate_ {
         #![allow(non_snake_case)]
 
+        use alloc::collections as alloc_collections;
+        #[cfg(not(feature = "nostd"))]
         use std::collections as std_collections;
 
 
         #[inline]
-        pub(super) fn get_is_empty_BTreeMap_<K, V>(coll : &std_collections::BTreeMap<K, V>) -> bool {
+        pub(super) fn get_is_empty_BTreeMap_<K, V>(coll : &alloc_collections::BTreeMap<K, V>) -> bool {
             coll.is_empty()
         }
 
         #[inline]
-        pub(super) fn get_is_empty_BTreeSet_<T>(coll : &std_collections::BTreeSet<T>) -> bool {
+        pub(super) fn get_is_empty_BTreeSet_<T>(coll : &alloc_collections::BTreeSet<T>) -> bool {
             coll.is_empty()
         }
 
         #[inline]
-        pub(super) fn get_is_empty_BinaryHeap_<T>(coll : &std_collections::BinaryHeap<T>) -> bool {
+        pub(super) fn get_is_empty_BinaryHeap_<T>(coll : &alloc_collections::BinaryHeap<T>) -> bool {
             coll.is_empty()
         }
 
+        #[cfg(not(feature = "nostd"))]
         #[inline]
         pub(super) fn get_is_empty_HashMap_<K, V>(coll : &std_collections::HashMap<K, V>) -> bool {
             coll.is_empty()
         }
 
+        #[cfg(not(feature = "nostd"))]
         #[inline]
         pub(super) fn get_is_empty_HashSet_<T>(coll : &std_collections::HashSet<T>) -> bool {
             coll.is_empty()
         }
 
         #[inline]
-        pub(super) fn get_is_empty_LinkedList_<T>(coll : &std_collections::LinkedList<T>) -> bool {
+        pub(super) fn get_is_empty_LinkedList_<T>(coll : &alloc_collections::LinkedList<T>) -> bool {
             coll.is_empty()
         }
 
@@ -214,7 +252,7 @@ mod impl_for_std_coll_types {
         }
 
         #[inline]
-        pub(super) fn get_is_empty_VecDeque_<T>(coll : &std_collections::VecDeque<T>) -> bool {
+        pub(super) fn get_is_empty_VecDeque_<T>(coll : &alloc_collections::VecDeque<T>) -> bool {
             coll.is_empty()
         }
     }
@@ -222,7 +260,7 @@ mod impl_for_std_coll_types {
 
     // BTreeMap<>
 
-    impl<K, V> super::IsEmpty for std_collections::BTreeMap<K, V> {
+    impl<K, V> super::IsEmpty for alloc_collections::BTreeMap<K, V> {
         #[inline]
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_BTreeMap_(self)
@@ -231,7 +269,7 @@ mod impl_for_std_coll_types {
 
     // BTreeSet<>
 
-    impl<T> super::IsEmpty for std_collections::BTreeSet<T> {
+    impl<T> super::IsEmpty for alloc_collections::BTreeSet<T> {
         #[inline]
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_BTreeSet_(self)
@@ -240,7 +278,7 @@ mod impl_for_std_coll_types {
 
     // BinaryHeap<>
 
-    impl<T> super::IsEmpty for std_collections::BinaryHeap<T> {
+    impl<T> super::IsEmpty for alloc_collections::BinaryHeap<T> {
         #[inline]
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_BinaryHeap_(self)
@@ -249,6 +287,7 @@ mod impl_for_std_coll_types {
 
     // HashMap<>
 
+    #[cfg(not(feature = "nostd"))]
     impl<K, V> super::IsEmpty for std_collections::HashMap<K, V> {
         #[inline]
         fn is_empty(&self) -> bool {
@@ -258,6 +297,7 @@ mod impl_for_std_coll_types {
 
     // HashSet<>
 
+    #[cfg(not(feature = "nostd"))]
     impl<T> super::IsEmpty for std_collections::HashSet<T> {
         #[inline]
         fn is_empty(&self) -> bool {
@@ -267,7 +307,7 @@ mod impl_for_std_coll_types {
 
     // LinkedList<>
 
-    impl<T> super::IsEmpty for std_collections::LinkedList<T> {
+    impl<T> super::IsEmpty for alloc_collections::LinkedList<T> {
         #[inline]
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_LinkedList_(self)
@@ -294,7 +334,7 @@ mod impl_for_std_coll_types {
 
     // VecDeque<>
 
-    impl<T> super::IsEmpty for std_collections::VecDeque<T> {
+    impl<T> super::IsEmpty for alloc_collections::VecDeque<T> {
         #[inline]
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_VecDeque_(self)
@@ -303,41 +343,56 @@ mod impl_for_std_coll_types {
 }
 
 
-#[cfg(all(not(feature = "nostd"), feature = "implement-IsEmpty-for-standard_ffi_types"))]
+#[cfg(feature = "implement-IsEmpty-for-standard_ffi_types")]
 mod impl_for_std_ffi_types {
     #![allow(non_snake_case)]
 
+    use core::ffi as core_ffi;
+    #[cfg(any(feature = "alloc", not(feature = "nostd")))]
+    use alloc::ffi as alloc_ffi;
+    #[cfg(not(feature = "nostd"))]
     use std::ffi as std_ffi;
 
 
     mod isolate_ {
         #![allow(non_snake_case)]
 
+        use core::ffi as core_ffi;
+        #[cfg(any(feature = "alloc", not(feature = "nostd")))]
+        use alloc::ffi as alloc_ffi;
+        #[cfg(not(feature = "nostd"))]
         use std::ffi as std_ffi;
 
 
         #[inline]
-        pub(super) fn get_is_empty_CStr_(cstr : &std_ffi::CStr) -> bool {
+        pub(super) fn get_is_empty_CStr_(cstr : &core_ffi::CStr) -> bool {
             cstr.is_empty()
         }
 
+        #[cfg(any(feature = "alloc", not(feature = "nostd")))]
         #[inline]
-        pub(super) fn get_is_empty_CString_(cstring : &std_ffi::CString) -> bool {
+        pub(super) fn get_is_empty_CString_(cstring : &alloc_ffi::CString) -> bool {
             cstring.is_empty()
         }
+
+        #[cfg(not(feature = "nostd"))]
+        #[inline]
+        pub(super) fn get_is_empty_OsStr_(os_str : &std_ffi::OsStr) -> bool {
+            os_str.is_empty()
+        }
     }
 
 
     // CStr
 
-    impl super::IsEmpty for std_ffi::CStr {
+    impl super::IsEmpty for core_ffi::CStr {
         #[inline]
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_CStr_(self)
         }
     }
 
-    impl super::IsEmpty for &std_ffi::CStr {
+    impl super::IsEmpty for &core_ffi::CStr {
         #[inline]
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_CStr_(self)
@@ -346,12 +401,83 @@ mod impl_for_std_ffi_types {
 
     // CString
 
-    impl super::IsEmpty for std_ffi::CString {
+    #[cfg(any(feature = "alloc", not(feature = "nostd")))]
+    impl super::IsEmpty for alloc_ffi::CString {
         #[inline]
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_CString_(self)
         }
     }
+
+    // OsStr
+
+    #[cfg(not(feature = "nostd"))]
+    impl super::IsEmpty for std_ffi::OsStr {
+        #[inline]
+        fn is_empty(&self) -> bool {
+            isolate_::get_is_empty_OsStr_(self)
+        }
+    }
+
+    #[cfg(not(feature = "nostd"))]
+    impl super::IsEmpty for &std_ffi::OsStr {
+        #[inline]
+        fn is_empty(&self) -> bool {
+            isolate_::get_is_empty_OsStr_(self)
+        }
+    }
+
+    // OsString
+
+    #[cfg(not(feature = "nostd"))]
+    impl super::IsEmpty for std_ffi::OsString {
+        #[inline]
+        fn is_empty(&self) -> bool {
+            isolate_::get_is_empty_OsStr_(self)
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-IsEmpty-for-standard_iter_types")]
+mod impl_for_std_iter_types {
+    use core::iter as core_iter;
+
+
+    // Empty<>
+
+    impl<T> super::IsEmpty for core_iter::Empty<T> {
+        #[inline]
+        fn is_empty(&self) -> bool {
+            true
+        }
+    }
+
+    // Once<>
+
+    impl<T> super::IsEmpty for core_iter::Once<T> {
+        #[inline]
+        fn is_empty(&self) -> bool {
+            ExactSizeIterator::len(self) == 0
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-IsEmpty-for-standard_option_types")]
+mod impl_for_std_option_types {
+
+    // Option<>
+
+    impl<T : super::IsEmpty> super::IsEmpty for Option<T> {
+        #[inline]
+        fn is_empty(&self) -> bool {
+            match self {
+                Some(t) => t.is_empty(),
+                None => true,
+            }
+        }
+    }
 }
 
 
@@ -388,14 +514,12 @@ mod impl_for_std_path_types {
 mod impl_for_std_process_types {
     #![allow(non_snake_case)]
 
-    #[cfg(feature = "experimental-exact_size_is_empty")]
     use std::process as std_process;
 
 
     mod isolate_ {
         #![allow(non_snake_case)]
 
-        #[cfg(feature = "experimental-exact_size_is_empty")]
         use std::process as std_process;
 
 
@@ -405,17 +529,28 @@ mod impl_for_std_process_types {
             ca.is_empty()
         }
 
+        #[cfg(not(feature = "experimental-exact_size_is_empty"))]
+        #[inline]
+        pub(super) fn get_is_empty_CommandArgs_<'a>(ca : &std_process::CommandArgs<'a>) -> bool {
+            0 == ca.len()
+        }
+
         #[cfg(feature = "experimental-exact_size_is_empty")]
         #[inline]
         pub(super) fn get_is_empty_CommandEnvs_<'a>(ce : &std_process::CommandEnvs<'a>) -> bool {
             ce.is_empty()
         }
+
+        #[cfg(not(feature = "experimental-exact_size_is_empty"))]
+        #[inline]
+        pub(super) fn get_is_empty_CommandEnvs_<'a>(ce : &std_process::CommandEnvs<'a>) -> bool {
+            0 == ce.len()
+        }
     }
 
 
     // CommandArgs<'>
 
-    #[cfg(feature = "experimental-exact_size_is_empty")]
     impl<'a> super::IsEmpty for &std_process::CommandArgs<'a> {
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_CommandArgs_(self)
@@ -424,7 +559,6 @@ mod impl_for_std_process_types {
 
     // CommandEnvs<'>
 
-    #[cfg(feature = "experimental-exact_size_is_empty")]
     impl<'a> super::IsEmpty for &std_process::CommandEnvs<'a> {
         fn is_empty(&self) -> bool {
             isolate_::get_is_empty_CommandEnvs_(self)
@@ -509,6 +643,23 @@ mod impl_for_std_range_types {
 }
 
 
+#[cfg(feature = "implement-IsEmpty-for-standard_result_types")]
+mod impl_for_std_result_types {
+
+    // Result<>
+
+    impl<T : super::IsEmpty, E> super::IsEmpty for Result<T, E> {
+        #[inline]
+        fn is_empty(&self) -> bool {
+            match self {
+                Ok(t) => t.is_empty(),
+                Err(_) => true,
+            }
+        }
+    }
+}
+
+
 #[cfg(all(not(feature = "nostd"), feature = "implement-IsEmpty-for-standard_time_types"))]
 mod impl_for_std_time_types {
     #![allow(non_snake_case)]
@@ -785,11 +936,14 @@ mod tests {
             BTreeMap,
             BTreeSet,
             BinaryHeap,
-            HashMap,
-            HashSet,
             LinkedList,
             VecDeque,
         };
+        #[cfg(not(feature = "nostd"))]
+        use std::collections::{
+            HashMap,
+            HashSet,
+        };
 
 
         mod TEST_BTreeMapTU {
@@ -882,6 +1036,7 @@ mod tests {
         }
 
 
+        #[cfg(not(feature = "nostd"))]
         mod TEST_HashMapTU {
             #![allow(non_snake_case)]
 
@@ -912,6 +1067,7 @@ mod tests {
         }
 
 
+        #[cfg(not(feature = "nostd"))]
         mod TEST_HashSetT {
             #![allow(non_snake_case)]
 
@@ -1103,6 +1259,11 @@ mod tests {
             CStr,
             CString,
         };
+        #[cfg(not(feature = "nostd"))]
+        use std::ffi::{
+            OsStr,
+            OsString,
+        };
 
 
         mod TEST_CStr {
@@ -1163,10 +1324,132 @@ mod tests {
                 assert!(!ie.is_empty());
             }
         }
+
+
+        #[cfg(not(feature = "nostd"))]
+        mod TEST_OsStr {
+            #![allow(non_snake_case)]
+
+            use super::*;
+
+
+            #[test]
+            fn TEST_EMPTY() {
+                let s : &OsStr = OsStr::new("");
+
+                let ie = as_IsEmpty(&s);
+
+                assert!(ie.is_empty());
+            }
+
+            #[test]
+            fn TEST_NONEMPTY() {
+                let s : &OsStr = OsStr::new("abc");
+
+                let ie = as_IsEmpty(&s);
+
+                assert!(!ie.is_empty());
+            }
+        }
+
+
+        #[cfg(not(feature = "nostd"))]
+        mod TEST_OsString {
+            #![allow(non_snake_case)]
+
+            use super::*;
+
+
+            #[test]
+            fn TEST_EMPTY() {
+                let s : OsString = OsString::from("");
+
+                let ie = as_IsEmpty(&s);
+
+                assert!(ie.is_empty());
+            }
+
+            #[test]
+            fn TEST_NONEMPTY() {
+                let s : OsString = OsString::from("abc");
+
+                let ie = as_IsEmpty(&s);
+
+                assert!(!ie.is_empty());
+            }
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsEmpty-for-standard_iter_types")]
+    mod TEST_ITER_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::iter as std_iter;
+
+
+        #[test]
+        fn TEST_Empty() {
+            let i = std_iter::empty::<i32>();
+
+            assert!(IsEmpty::is_empty(&i));
+
+            let ie = as_IsEmpty(&i);
+
+            assert!(ie.is_empty());
+        }
+
+        #[test]
+        fn TEST_Once() {
+            let mut i = std_iter::once(1);
+
+            assert!(!IsEmpty::is_empty(&i));
+
+            let _ = i.next();
+
+            assert!(IsEmpty::is_empty(&i));
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsEmpty-for-standard_option_types")]
+    mod TEST_OPTION_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_None() {
+            let o : Option<&str> = None;
+
+            assert!(o.is_empty());
+
+            let ie = as_IsEmpty(&o);
+
+            assert!(ie.is_empty());
+        }
+
+        #[test]
+        fn TEST_Some() {
+            let o = Some("");
+
+            assert!(o.is_empty());
+
+            let o = Some("abc");
+
+            assert!(!o.is_empty());
+
+            let ie = as_IsEmpty(&o);
+
+            assert!(!ie.is_empty());
+        }
     }
 
 
-    #[cfg(feature = "implement-IsEmpty-for-standard_path_types")]
+    #[cfg(all(not(feature = "nostd"), feature = "implement-IsEmpty-for-standard_path_types"))]
     mod TEST_PATH_TYPES {
         #![allow(non_snake_case)]
 
@@ -1188,7 +1471,7 @@ mod tests {
             fn TEST_EMPTY() {
                 let p = Path::new("");
 
-                assert!(p.is_empty());
+                assert!(IsEmpty::is_empty(&p));
 
                 let ie = as_IsEmpty(&p);
 
@@ -1199,7 +1482,7 @@ mod tests {
             fn TEST_NOTEMPTY() {
                 let p = Path::new("./foo/bar.txt");
 
-                assert!(!p.is_empty());
+                assert!(!IsEmpty::is_empty(&p));
 
                 let ie = as_IsEmpty(&p);
 
@@ -1241,14 +1524,56 @@ mod tests {
     }
 
 
-    #[cfg(feature = "implement-IsEmpty-for-standard_process_types")]
+    #[cfg(all(not(feature = "nostd"), feature = "implement-IsEmpty-for-standard_process_types"))]
     mod TEST_PROCESS_TYPES {
         #![allow(non_snake_case)]
 
+        use super::*;
+
+        use std::process::Command;
+
+
+        #[test]
+        fn TEST_CommandArgs() {
+            let mut command = Command::new("ls");
+
+            {
+                let args = command.get_args();
+
+                assert!(as_IsEmpty(&&args).is_empty());
+            }
+
+            command.arg("-l").arg("-a");
+
+            {
+                let args = command.get_args();
+
+                assert!(!as_IsEmpty(&&args).is_empty());
+            }
+        }
+
+        #[test]
+        fn TEST_CommandEnvs() {
+            let mut command = Command::new("ls");
+
+            {
+                let envs = command.get_envs();
+
+                assert!(as_IsEmpty(&&envs).is_empty());
+            }
+
+            command.env("ABC", "abc");
+
+            {
+                let envs = command.get_envs();
+
+                assert!(!as_IsEmpty(&&envs).is_empty());
+            }
+        }
     }
 
 
-    #[cfg(feature = "implement-IsEmpty-for-standard_range_types")]
+    #[cfg(all(not(feature = "nostd"), feature = "implement-IsEmpty-for-standard_range_types"))]
     mod TEST_RANGE_TYPES {
         #![allow(non_snake_case)]
 
@@ -1362,7 +1687,38 @@ mod tests {
     }
 
 
-    #[cfg(feature = "implement-IsEmpty-for-standard_time_types")]
+    #[cfg(feature = "implement-IsEmpty-for-standard_result_types")]
+    mod TEST_RESULT_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_Err() {
+            let r : Result<&str, i32> = Err(-1);
+
+            assert!(r.is_empty());
+
+            let ie = as_IsEmpty(&r);
+
+            assert!(ie.is_empty());
+        }
+
+        #[test]
+        fn TEST_Ok() {
+            let r : Result<&str, i32> = Ok("");
+
+            assert!(r.is_empty());
+
+            let r : Result<&str, i32> = Ok("abc");
+
+            assert!(!r.is_empty());
+        }
+    }
+
+
+    #[cfg(all(not(feature = "nostd"), feature = "implement-IsEmpty-for-standard_time_types"))]
     mod TEST_TIME_TYPES {
         #![allow(non_snake_case)]
 
@@ -0,0 +1,108 @@
+// src/traits/is_epoch.rs : `IsEpoch`
+
+/// Trait defining instance method `is_epoch() : bool` that indicates
+/// whether the implementing type instance is the epoch from which its
+/// values are measured.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-IsEpoch-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`std::time::SystemTime`], whose epoch is [`std::time::UNIX_EPOCH`];
+///
+/// NOTE: it is not implemented for [`std::time::Instant`], since an instant
+/// has no epoch.
+pub trait IsEpoch {
+    fn is_epoch(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsEpoch {
+    fn is_epoch(&self) -> bool;
+});
+
+
+#[cfg(all(not(feature = "nostd"), feature = "implement-IsEpoch-for-standard_time_types"))]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use std::time as std_time;
+
+
+    // SystemTime
+
+    impl super::IsEpoch for std_time::SystemTime {
+        #[inline]
+        fn is_epoch(&self) -> bool {
+            std_time::UNIX_EPOCH == *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsEpoch;
+
+
+    #[allow(unused)]
+    fn as_IsEpoch<T : IsEpoch>(t : &T) -> &impl IsEpoch {
+        t
+    }
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct Generation(u32);
+
+        impl IsEpoch for Generation {
+            fn is_epoch(&self) -> bool {
+                0 == self.0
+            }
+        }
+
+
+        #[test]
+        fn TEST_is_epoch() {
+            assert!(Generation(0).is_epoch());
+            assert!(!Generation(1).is_epoch());
+            assert!(as_IsEpoch(&Box::new(Generation(0))).is_epoch());
+        }
+    }
+
+
+    #[cfg(all(not(feature = "nostd"), feature = "implement-IsEpoch-for-standard_time_types"))]
+    mod TEST_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time::{
+            Duration,
+            UNIX_EPOCH,
+        };
+
+
+        #[test]
+        fn TEST_SystemTime() {
+            assert!(UNIX_EPOCH.is_epoch());
+            assert!(as_IsEpoch(&UNIX_EPOCH).is_epoch());
+            assert!(!(UNIX_EPOCH + Duration::from_nanos(1)).is_epoch());
+            assert!(!(UNIX_EPOCH - Duration::from_nanos(1)).is_epoch());
+            assert!((UNIX_EPOCH + Duration::from_secs(5) - Duration::from_secs(5)).is_epoch());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,240 @@
+// src/traits/is_finite.rs : `IsFinite`
+
+/// Trait defining instance method `is_finite() : bool` that indicates
+/// whether the implementing type instance is finite, i.e. is neither
+/// infinite nor "not a number".
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsFinite-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+///
+/// NOTE: an integer is always finite.
+pub trait IsFinite {
+    fn is_finite(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsFinite {
+    fn is_finite(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsFinite-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_IsFinite_for_integer_ {
+        ($type:tt) => {
+            impl super::IsFinite for $type {
+                #[inline]
+                fn is_finite(&self) -> bool {
+                    true
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsFinite_for_float_ {
+        ($type:tt) => {
+            impl super::IsFinite for $type {
+                #[inline]
+                fn is_finite(&self) -> bool {
+                    $type::is_finite(*self)
+                }
+            }
+        };
+    }
+
+    implement_IsFinite_for_integer_!(i8);
+    implement_IsFinite_for_integer_!(i16);
+    implement_IsFinite_for_integer_!(i32);
+    implement_IsFinite_for_integer_!(i64);
+    implement_IsFinite_for_integer_!(i128);
+    implement_IsFinite_for_integer_!(isize);
+
+    implement_IsFinite_for_integer_!(u8);
+    implement_IsFinite_for_integer_!(u16);
+    implement_IsFinite_for_integer_!(u32);
+    implement_IsFinite_for_integer_!(u64);
+    implement_IsFinite_for_integer_!(u128);
+    implement_IsFinite_for_integer_!(usize);
+
+    implement_IsFinite_for_float_!(f32);
+    implement_IsFinite_for_float_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsFinite;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// A wrapper type that adopts the semantics of its `f64` value.
+        #[derive(Debug)]
+        struct Price {
+            value : f64,
+        }
+
+        impl IsFinite for Price {
+            fn is_finite(&self) -> bool {
+                self.value.is_finite()
+            }
+        }
+
+
+        #[test]
+        fn TEST_IsFinite() {
+            assert!(Price { value : 1.0 }.is_finite());
+            assert!(Price { value : 0.0 }.is_finite());
+            assert!(Price { value : f64::MAX }.is_finite());
+            assert!(Price { value : f64::MIN_POSITIVE / 2.0 }.is_finite());
+
+            assert!(!Price { value : f64::INFINITY }.is_finite());
+            assert!(!Price { value : f64::NEG_INFINITY }.is_finite());
+            assert!(!Price { value : f64::NAN }.is_finite());
+        }
+
+        #[test]
+        fn TEST_IsFinite_IN_Box() {
+            let price : Box<dyn IsFinite> = Box::new(Price { value : 1.0 });
+
+            assert!(price.is_finite());
+        }
+
+        #[test]
+        fn TEST_IsFinite_IN_Rc() {
+            let price = Rc::new(Price { value : f64::INFINITY });
+
+            assert!(!price.is_finite());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsFinite-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        // NOTE: the floating-point tests use the fully-qualified form, since
+        // `f32` and `f64` have inherent methods of the same name
+
+        #[test]
+        fn TEST_integers() {
+            assert!((0i8).is_finite());
+            assert!((1i8).is_finite());
+            assert!((i8::MAX).is_finite());
+            assert!((i8::MIN).is_finite());
+
+            assert!((0i16).is_finite());
+            assert!((1i16).is_finite());
+            assert!((i16::MAX).is_finite());
+            assert!((i16::MIN).is_finite());
+
+            assert!((0i32).is_finite());
+            assert!((1i32).is_finite());
+            assert!((i32::MAX).is_finite());
+            assert!((i32::MIN).is_finite());
+
+            assert!((0i64).is_finite());
+            assert!((1i64).is_finite());
+            assert!((i64::MAX).is_finite());
+            assert!((i64::MIN).is_finite());
+
+            assert!((0i128).is_finite());
+            assert!((1i128).is_finite());
+            assert!((i128::MAX).is_finite());
+            assert!((i128::MIN).is_finite());
+
+            assert!((0isize).is_finite());
+            assert!((1isize).is_finite());
+            assert!((isize::MAX).is_finite());
+            assert!((isize::MIN).is_finite());
+
+            assert!((0u8).is_finite());
+            assert!((1u8).is_finite());
+            assert!((u8::MAX).is_finite());
+            assert!((u8::MIN).is_finite());
+
+            assert!((0u16).is_finite());
+            assert!((1u16).is_finite());
+            assert!((u16::MAX).is_finite());
+            assert!((u16::MIN).is_finite());
+
+            assert!((0u32).is_finite());
+            assert!((1u32).is_finite());
+            assert!((u32::MAX).is_finite());
+            assert!((u32::MIN).is_finite());
+
+            assert!((0u64).is_finite());
+            assert!((1u64).is_finite());
+            assert!((u64::MAX).is_finite());
+            assert!((u64::MIN).is_finite());
+
+            assert!((0u128).is_finite());
+            assert!((1u128).is_finite());
+            assert!((u128::MAX).is_finite());
+            assert!((u128::MIN).is_finite());
+
+            assert!((0usize).is_finite());
+            assert!((1usize).is_finite());
+            assert!((usize::MAX).is_finite());
+            assert!((usize::MIN).is_finite());
+        }
+
+        #[test]
+        fn TEST_f32() {
+            assert!(IsFinite::is_finite(&0.0f32));
+            assert!(IsFinite::is_finite(&(-1.0f32)));
+            assert!(IsFinite::is_finite(&f32::MAX));
+            assert!(IsFinite::is_finite(&f32::MIN));
+            assert!(IsFinite::is_finite(&(f32::MIN_POSITIVE / 2.0)));
+
+            assert!(!IsFinite::is_finite(&f32::INFINITY));
+            assert!(!IsFinite::is_finite(&f32::NEG_INFINITY));
+            assert!(!IsFinite::is_finite(&f32::NAN));
+        }
+
+        #[test]
+        fn TEST_f64() {
+            assert!(IsFinite::is_finite(&0.0f64));
+            assert!(IsFinite::is_finite(&(-1.0f64)));
+            assert!(IsFinite::is_finite(&f64::MAX));
+            assert!(IsFinite::is_finite(&f64::MIN));
+            assert!(IsFinite::is_finite(&(f64::MIN_POSITIVE / 2.0)));
+
+            assert!(!IsFinite::is_finite(&f64::INFINITY));
+            assert!(!IsFinite::is_finite(&f64::NEG_INFINITY));
+            assert!(!IsFinite::is_finite(&f64::NAN));
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(0.0f64).is_finite());
+            assert!(Rc::new(0i32).is_finite());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,247 @@
+// src/traits/is_max_value.rs : `IsMaxValue`
+
+/// Trait defining instance method `is_max_value() : bool` that indicates whether
+/// the implementing type instance has the maximum value of its type.
+///
+/// For the floating-point types, this is the largest finite value, i.e. [`f64::MAX`],
+/// so that infinity is not deemed to be the maximum value.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsMaxValue-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`];
+/// - [`i16`];
+/// - [`i32`];
+/// - [`i64`];
+/// - [`i128`];
+/// - [`u8`];
+/// - [`u16`];
+/// - [`u32`];
+/// - [`u64`];
+/// - [`u128`];
+/// - [`isize`];
+/// - [`usize`];
+/// - [`f32`];
+/// - [`f64`];
+/// - [`char`];
+/// - [`bool`];
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-IsMaxValue-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::time::Duration`];
+pub trait IsMaxValue {
+    fn is_max_value(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsMaxValue {
+    fn is_max_value(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsMaxValue-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_IsMaxValue_ {
+        ($type:tt) => {
+            impl super::IsMaxValue for $type {
+                #[inline]
+                fn is_max_value(&self) -> bool {
+                    $type::MAX == *self
+                }
+            }
+        };
+        ($type:tt, $value:expr) => {
+            impl super::IsMaxValue for $type {
+                #[inline]
+                fn is_max_value(&self) -> bool {
+                    $value == *self
+                }
+            }
+        };
+    }
+
+    implement_IsMaxValue_!(i8);
+    implement_IsMaxValue_!(i16);
+    implement_IsMaxValue_!(i32);
+    implement_IsMaxValue_!(i64);
+    implement_IsMaxValue_!(i128);
+
+    implement_IsMaxValue_!(u8);
+    implement_IsMaxValue_!(u16);
+    implement_IsMaxValue_!(u32);
+    implement_IsMaxValue_!(u64);
+    implement_IsMaxValue_!(u128);
+
+    implement_IsMaxValue_!(isize);
+    implement_IsMaxValue_!(usize);
+
+    implement_IsMaxValue_!(f32);
+    implement_IsMaxValue_!(f64);
+
+    implement_IsMaxValue_!(char);
+
+    implement_IsMaxValue_!(bool, true);
+}
+
+
+#[cfg(feature = "implement-IsMaxValue-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl super::IsMaxValue for core_time::Duration {
+        #[inline]
+        fn is_max_value(&self) -> bool {
+            core_time::Duration::MAX == *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsMaxValue;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : i32,
+        }
+
+        impl IsMaxValue for CustomType {
+            fn is_max_value(&self) -> bool {
+                1000 == self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_IsMaxValue() {
+            assert!(CustomType { value : 1000 }.is_max_value());
+            assert!(!CustomType { value : 0 }.is_max_value());
+        }
+
+        #[test]
+        fn TEST_IsMaxValue_IN_Box() {
+            let ct : Box<dyn IsMaxValue> = Box::new(CustomType { value : 1000 });
+
+            assert!(ct.is_max_value());
+        }
+
+        #[test]
+        fn TEST_IsMaxValue_IN_Rc() {
+            let ct = Rc::new(CustomType { value : 0 });
+
+            assert!(!ct.is_max_value());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsMaxValue-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_INTEGERS() {
+            assert!(i8::MAX.is_max_value());
+            assert!(i16::MAX.is_max_value());
+            assert!(i32::MAX.is_max_value());
+            assert!(i64::MAX.is_max_value());
+            assert!(i128::MAX.is_max_value());
+            assert!(u8::MAX.is_max_value());
+            assert!(u16::MAX.is_max_value());
+            assert!(u32::MAX.is_max_value());
+            assert!(u64::MAX.is_max_value());
+            assert!(u128::MAX.is_max_value());
+            assert!(isize::MAX.is_max_value());
+            assert!(usize::MAX.is_max_value());
+
+            assert!(!i8::MIN.is_max_value());
+            assert!(!i16::MIN.is_max_value());
+            assert!(!i32::MIN.is_max_value());
+            assert!(!i64::MIN.is_max_value());
+            assert!(!i128::MIN.is_max_value());
+            assert!(!u8::MIN.is_max_value());
+            assert!(!u16::MIN.is_max_value());
+            assert!(!u32::MIN.is_max_value());
+            assert!(!u64::MIN.is_max_value());
+            assert!(!u128::MIN.is_max_value());
+            assert!(!isize::MIN.is_max_value());
+            assert!(!usize::MIN.is_max_value());
+        }
+
+        #[test]
+        fn TEST_FLOATS() {
+            assert!(f32::MAX.is_max_value());
+            assert!(f64::MAX.is_max_value());
+
+            assert!(!0.0f32.is_max_value());
+            assert!(!f64::INFINITY.is_max_value());
+            assert!(!f64::NAN.is_max_value());
+        }
+
+        #[test]
+        fn TEST_char() {
+            assert!(char::MAX.is_max_value());
+            assert!(!'a'.is_max_value());
+        }
+
+        #[test]
+        fn TEST_bool() {
+            assert!(true.is_max_value());
+            assert!(!false.is_max_value());
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(u8::MAX).is_max_value());
+            assert!(!Rc::new(1i64).is_max_value());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsMaxValue-for-standard_time_types")]
+    mod TEST_STANDARD_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time as std_time;
+
+
+        #[test]
+        fn TEST_Duration() {
+            assert!(std_time::Duration::MAX.is_max_value());
+            assert!(!std_time::Duration::from_secs(1).is_max_value());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,247 @@
+// src/traits/is_min_value.rs : `IsMinValue`
+
+/// Trait defining instance method `is_min_value() : bool` that indicates whether
+/// the implementing type instance has the minimum value of its type.
+///
+/// For the floating-point types, this is the most negative finite value, i.e. [`f64::MIN`],
+/// so that negative infinity is not deemed to be the minimum value.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsMinValue-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`];
+/// - [`i16`];
+/// - [`i32`];
+/// - [`i64`];
+/// - [`i128`];
+/// - [`u8`];
+/// - [`u16`];
+/// - [`u32`];
+/// - [`u64`];
+/// - [`u128`];
+/// - [`isize`];
+/// - [`usize`];
+/// - [`f32`];
+/// - [`f64`];
+/// - [`char`];
+/// - [`bool`];
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-IsMinValue-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::time::Duration`];
+pub trait IsMinValue {
+    fn is_min_value(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsMinValue {
+    fn is_min_value(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsMinValue-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_IsMinValue_ {
+        ($type:tt) => {
+            impl super::IsMinValue for $type {
+                #[inline]
+                fn is_min_value(&self) -> bool {
+                    $type::MIN == *self
+                }
+            }
+        };
+        ($type:tt, $value:expr) => {
+            impl super::IsMinValue for $type {
+                #[inline]
+                fn is_min_value(&self) -> bool {
+                    $value == *self
+                }
+            }
+        };
+    }
+
+    implement_IsMinValue_!(i8);
+    implement_IsMinValue_!(i16);
+    implement_IsMinValue_!(i32);
+    implement_IsMinValue_!(i64);
+    implement_IsMinValue_!(i128);
+
+    implement_IsMinValue_!(u8);
+    implement_IsMinValue_!(u16);
+    implement_IsMinValue_!(u32);
+    implement_IsMinValue_!(u64);
+    implement_IsMinValue_!(u128);
+
+    implement_IsMinValue_!(isize);
+    implement_IsMinValue_!(usize);
+
+    implement_IsMinValue_!(f32);
+    implement_IsMinValue_!(f64);
+
+    implement_IsMinValue_!(char);
+
+    implement_IsMinValue_!(bool, false);
+}
+
+
+#[cfg(feature = "implement-IsMinValue-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl super::IsMinValue for core_time::Duration {
+        #[inline]
+        fn is_min_value(&self) -> bool {
+            core_time::Duration::ZERO == *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsMinValue;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : i32,
+        }
+
+        impl IsMinValue for CustomType {
+            fn is_min_value(&self) -> bool {
+                -1000 == self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_IsMinValue() {
+            assert!(CustomType { value : -1000 }.is_min_value());
+            assert!(!CustomType { value : 0 }.is_min_value());
+        }
+
+        #[test]
+        fn TEST_IsMinValue_IN_Box() {
+            let ct : Box<dyn IsMinValue> = Box::new(CustomType { value : -1000 });
+
+            assert!(ct.is_min_value());
+        }
+
+        #[test]
+        fn TEST_IsMinValue_IN_Rc() {
+            let ct = Rc::new(CustomType { value : 0 });
+
+            assert!(!ct.is_min_value());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsMinValue-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_INTEGERS() {
+            assert!(i8::MIN.is_min_value());
+            assert!(i16::MIN.is_min_value());
+            assert!(i32::MIN.is_min_value());
+            assert!(i64::MIN.is_min_value());
+            assert!(i128::MIN.is_min_value());
+            assert!(u8::MIN.is_min_value());
+            assert!(u16::MIN.is_min_value());
+            assert!(u32::MIN.is_min_value());
+            assert!(u64::MIN.is_min_value());
+            assert!(u128::MIN.is_min_value());
+            assert!(isize::MIN.is_min_value());
+            assert!(usize::MIN.is_min_value());
+
+            assert!(!i8::MAX.is_min_value());
+            assert!(!i16::MAX.is_min_value());
+            assert!(!i32::MAX.is_min_value());
+            assert!(!i64::MAX.is_min_value());
+            assert!(!i128::MAX.is_min_value());
+            assert!(!u8::MAX.is_min_value());
+            assert!(!u16::MAX.is_min_value());
+            assert!(!u32::MAX.is_min_value());
+            assert!(!u64::MAX.is_min_value());
+            assert!(!u128::MAX.is_min_value());
+            assert!(!isize::MAX.is_min_value());
+            assert!(!usize::MAX.is_min_value());
+        }
+
+        #[test]
+        fn TEST_FLOATS() {
+            assert!(f32::MIN.is_min_value());
+            assert!(f64::MIN.is_min_value());
+
+            assert!(!0.0f32.is_min_value());
+            assert!(!f64::NEG_INFINITY.is_min_value());
+            assert!(!f64::NAN.is_min_value());
+        }
+
+        #[test]
+        fn TEST_char() {
+            assert!(char::MIN.is_min_value());
+            assert!(!'a'.is_min_value());
+        }
+
+        #[test]
+        fn TEST_bool() {
+            assert!(false.is_min_value());
+            assert!(!true.is_min_value());
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(u8::MIN).is_min_value());
+            assert!(!Rc::new(1i64).is_min_value());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsMinValue-for-standard_time_types")]
+    mod TEST_STANDARD_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time as std_time;
+
+
+        #[test]
+        fn TEST_Duration() {
+            assert!(std_time::Duration::ZERO.is_min_value());
+            assert!(!std_time::Duration::from_secs(1).is_min_value());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,352 @@
+// src/traits/is_negative.rs : `IsNegative`
+
+/// Trait defining instance method `is_negative() : bool` that indicates
+/// whether the implementing type instance is (strictly) less than zero.
+///
+/// NOTE: neither negative zero nor "not a number" is negative; see
+/// [`IsSignNegative`](crate::IsSignNegative) for the former.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsNegative-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+///
+/// ## Standard Numeric Types
+///
+/// If the feature `"implement-IsNegative-for-standard_num_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::num::NonZeroI8`], ..., [`core::num::NonZeroI128`],
+///   [`core::num::NonZeroIsize`];
+/// - [`core::num::NonZeroU8`], ..., [`core::num::NonZeroU128`],
+///   [`core::num::NonZeroUsize`];
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-IsNegative-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::time::Duration`];
+///
+/// NOTE: an unsigned integer, or a duration, is never negative.
+///
+/// NOTE: many toolchains provide deprecated inherent methods
+/// `f32::is_negative()` and `f64::is_negative()`, which are equivalent to
+/// `is_sign_negative()` and so yield `true` for `-0.0`. Where they are
+/// provided, they take precedence over this trait's method when it is
+/// called with method syntax on a concrete float, as in `x.is_negative()`,
+/// so the two spellings can give different answers. To obtain this trait's
+/// semantics, call it as `IsNegative::is_negative(&x)`, or via a generic
+/// bound:
+///
+/// ```
+/// # #[cfg(feature = "implement-IsNegative-for-built_ins")]
+/// # {
+/// use base_traits::IsNegative;
+///
+/// fn is_negative_<T : IsNegative>(t : &T) -> bool {
+///     t.is_negative()
+/// }
+///
+/// assert!(!IsNegative::is_negative(&-0.0f64));
+/// assert!(!is_negative_(&-0.0f64));
+/// # }
+/// ```
+pub trait IsNegative {
+    fn is_negative(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsNegative {
+    fn is_negative(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsNegative-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_IsNegative_for_signed_ {
+        ($type:tt) => {
+            impl super::IsNegative for $type {
+                #[inline]
+                fn is_negative(&self) -> bool {
+                    $type::is_negative(*self)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsNegative_for_unsigned_ {
+        ($type:tt) => {
+            impl super::IsNegative for $type {
+                #[inline]
+                fn is_negative(&self) -> bool {
+                    false
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsNegative_for_float_ {
+        ($type:tt) => {
+            impl super::IsNegative for $type {
+                #[inline]
+                fn is_negative(&self) -> bool {
+                    *self < 0.0
+                }
+            }
+        };
+    }
+
+    implement_IsNegative_for_signed_!(i8);
+    implement_IsNegative_for_signed_!(i16);
+    implement_IsNegative_for_signed_!(i32);
+    implement_IsNegative_for_signed_!(i64);
+    implement_IsNegative_for_signed_!(i128);
+    implement_IsNegative_for_signed_!(isize);
+
+    implement_IsNegative_for_unsigned_!(u8);
+    implement_IsNegative_for_unsigned_!(u16);
+    implement_IsNegative_for_unsigned_!(u32);
+    implement_IsNegative_for_unsigned_!(u64);
+    implement_IsNegative_for_unsigned_!(u128);
+    implement_IsNegative_for_unsigned_!(usize);
+
+    implement_IsNegative_for_float_!(f32);
+    implement_IsNegative_for_float_!(f64);
+}
+
+
+#[cfg(feature = "implement-IsNegative-for-standard_num_types")]
+mod impl_for_std_num_types {
+    #![allow(non_snake_case)]
+
+    use super::IsNegative;
+
+    use core::num as core_num;
+
+
+    // NonZero*
+
+    macro_rules! implement_IsNegative_for_signed_NonZero_ {
+        ($type:ident) => {
+            impl IsNegative for core_num::$type {
+                #[inline]
+                fn is_negative(&self) -> bool {
+                    self.get() < 0
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsNegative_for_unsigned_NonZero_ {
+        ($type:ident) => {
+            impl IsNegative for core_num::$type {
+                #[inline]
+                fn is_negative(&self) -> bool {
+                    false
+                }
+            }
+        };
+    }
+
+    implement_IsNegative_for_signed_NonZero_!(NonZeroI8);
+    implement_IsNegative_for_signed_NonZero_!(NonZeroI16);
+    implement_IsNegative_for_signed_NonZero_!(NonZeroI32);
+    implement_IsNegative_for_signed_NonZero_!(NonZeroI64);
+    implement_IsNegative_for_signed_NonZero_!(NonZeroI128);
+    implement_IsNegative_for_signed_NonZero_!(NonZeroIsize);
+
+    implement_IsNegative_for_unsigned_NonZero_!(NonZeroU8);
+    implement_IsNegative_for_unsigned_NonZero_!(NonZeroU16);
+    implement_IsNegative_for_unsigned_NonZero_!(NonZeroU32);
+    implement_IsNegative_for_unsigned_NonZero_!(NonZeroU64);
+    implement_IsNegative_for_unsigned_NonZero_!(NonZeroU128);
+    implement_IsNegative_for_unsigned_NonZero_!(NonZeroUsize);
+}
+
+
+#[cfg(feature = "implement-IsNegative-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl super::IsNegative for core_time::Duration {
+        #[inline]
+        fn is_negative(&self) -> bool {
+            false
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsNegative;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// A fixed-point type, in which the value is held in hundredths.
+        #[derive(Debug, PartialEq)]
+        struct Price {
+            cents : i64,
+        }
+
+        impl IsNegative for Price {
+            fn is_negative(&self) -> bool {
+                self.cents < 0
+            }
+        }
+
+
+        #[test]
+        fn TEST_IsNegative() {
+            assert!(Price { cents : -1 }.is_negative());
+            assert!(!Price { cents : 0 }.is_negative());
+            assert!(!Price { cents : 1 }.is_negative());
+        }
+
+        #[test]
+        fn TEST_IsNegative_IN_Box_AND_Rc() {
+            let price : Box<dyn IsNegative> = Box::new(Price { cents : -1234 });
+
+            assert!(price.is_negative());
+            assert!(!Rc::new(Price { cents : 1234 }).is_negative());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsNegative-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        // NOTE: the fully-qualified form is used throughout, since many of
+        // the built-in types have inherent methods of the same name
+
+
+        #[test]
+        fn TEST_signed_integers() {
+            assert!(IsNegative::is_negative(&-1i8));
+            assert!(IsNegative::is_negative(&i16::MIN));
+            assert!(IsNegative::is_negative(&-1i32));
+            assert!(IsNegative::is_negative(&-1i64));
+            assert!(IsNegative::is_negative(&i128::MIN));
+            assert!(IsNegative::is_negative(&-1isize));
+
+            assert!(!IsNegative::is_negative(&0i8));
+            assert!(!IsNegative::is_negative(&1i32));
+            assert!(!IsNegative::is_negative(&i64::MAX));
+        }
+
+        #[test]
+        fn TEST_unsigned_integers() {
+            assert!(!0u8.is_negative());
+            assert!(!u16::MAX.is_negative());
+            assert!(!1u32.is_negative());
+            assert!(!1u64.is_negative());
+            assert!(!1u128.is_negative());
+            assert!(!1usize.is_negative());
+        }
+
+        #[test]
+        fn TEST_floats() {
+            assert!(IsNegative::is_negative(&-1.0f32));
+            assert!(IsNegative::is_negative(&f32::NEG_INFINITY));
+            assert!(IsNegative::is_negative(&-f64::MIN_POSITIVE));
+
+            assert!(!IsNegative::is_negative(&0.0f32));
+            assert!(!IsNegative::is_negative(&-0.0f32));
+            assert!(!IsNegative::is_negative(&f64::NAN));
+            assert!(!IsNegative::is_negative(&1.0f64));
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn TEST_floats_WITH_METHOD_SYNTAX() {
+            let x = -0.0f64;
+
+            let by_method = x.is_negative();
+            let by_trait = IsNegative::is_negative(&x);
+
+            assert!(!by_trait);
+
+            // NOTE: where the toolchain provides the deprecated inherent
+            // method, method syntax on a concrete float selects it, and its
+            // answer is that of the sign bit
+            if by_method != by_trait {
+                assert_eq!(x.is_sign_negative(), by_method);
+            }
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(-1i32).is_negative());
+            assert!(!Rc::new(1.0f64).is_negative());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsNegative-for-standard_num_types")]
+    mod TEST_STANDARD_NUM_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::num as std_num;
+
+
+        #[test]
+        fn TEST_NonZero() {
+            assert!(IsNegative::is_negative(&std_num::NonZeroI8::new(-1).unwrap()));
+            assert!(IsNegative::is_negative(&std_num::NonZeroI64::MIN));
+            assert!(!IsNegative::is_negative(&std_num::NonZeroI32::new(1).unwrap()));
+            assert!(!std_num::NonZeroU8::MIN.is_negative());
+            assert!(!std_num::NonZeroUsize::MAX.is_negative());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsNegative-for-standard_time_types")]
+    mod TEST_STANDARD_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time as std_time;
+
+
+        #[test]
+        fn TEST_Duration() {
+            assert!(!std_time::Duration::ZERO.is_negative());
+            assert!(!std_time::Duration::from_secs(1).is_negative());
+            assert!(!std_time::Duration::MAX.is_negative());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
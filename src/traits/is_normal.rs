@@ -0,0 +1,237 @@
+// src/traits/is_normal.rs : `IsNormal`
+
+/// Trait defining instance method `is_normal() : bool` that indicates
+/// whether the implementing type instance is normal, i.e. is neither zero,
+/// subnormal, infinite, nor "not a number".
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsNormal-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+///
+/// NOTE: an integer is normal if it is not zero (as determined by
+/// [`IsZero`](crate::IsZero)).
+pub trait IsNormal {
+    fn is_normal(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsNormal {
+    fn is_normal(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsNormal-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    use crate::IsZero;
+
+
+    macro_rules! implement_IsNormal_for_integer_ {
+        ($type:tt) => {
+            impl super::IsNormal for $type {
+                #[inline]
+                fn is_normal(&self) -> bool {
+                    !IsZero::is_zero(self)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsNormal_for_float_ {
+        ($type:tt) => {
+            impl super::IsNormal for $type {
+                #[inline]
+                fn is_normal(&self) -> bool {
+                    $type::is_normal(*self)
+                }
+            }
+        };
+    }
+
+    implement_IsNormal_for_integer_!(i8);
+    implement_IsNormal_for_integer_!(i16);
+    implement_IsNormal_for_integer_!(i32);
+    implement_IsNormal_for_integer_!(i64);
+    implement_IsNormal_for_integer_!(i128);
+    implement_IsNormal_for_integer_!(isize);
+
+    implement_IsNormal_for_integer_!(u8);
+    implement_IsNormal_for_integer_!(u16);
+    implement_IsNormal_for_integer_!(u32);
+    implement_IsNormal_for_integer_!(u64);
+    implement_IsNormal_for_integer_!(u128);
+    implement_IsNormal_for_integer_!(usize);
+
+    implement_IsNormal_for_float_!(f32);
+    implement_IsNormal_for_float_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsNormal;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// A wrapper type that adopts the semantics of its `f64` value.
+        #[derive(Debug)]
+        struct Price {
+            value : f64,
+        }
+
+        impl IsNormal for Price {
+            fn is_normal(&self) -> bool {
+                self.value.is_normal()
+            }
+        }
+
+
+        #[test]
+        fn TEST_IsNormal() {
+            assert!(Price { value : 1.0 }.is_normal());
+            assert!(Price { value : f64::MAX }.is_normal());
+            assert!(Price { value : f64::MIN_POSITIVE }.is_normal());
+
+            assert!(!Price { value : 0.0 }.is_normal());
+            assert!(!Price { value : f64::MIN_POSITIVE / 2.0 }.is_normal());
+            assert!(!Price { value : f64::INFINITY }.is_normal());
+            assert!(!Price { value : f64::NAN }.is_normal());
+        }
+
+        #[test]
+        fn TEST_IsNormal_IN_Box() {
+            let price : Box<dyn IsNormal> = Box::new(Price { value : 1.0 });
+
+            assert!(price.is_normal());
+        }
+
+        #[test]
+        fn TEST_IsNormal_IN_Rc() {
+            let price = Rc::new(Price { value : 0.0 });
+
+            assert!(!price.is_normal());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsNormal-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        // NOTE: the floating-point tests use the fully-qualified form, since
+        // `f32` and `f64` have inherent methods of the same name
+
+        #[test]
+        fn TEST_integers() {
+            assert!((1i8).is_normal());
+            assert!((i8::MAX).is_normal());
+            assert!(!(0i8).is_normal());
+
+            assert!((1i16).is_normal());
+            assert!((i16::MAX).is_normal());
+            assert!(!(0i16).is_normal());
+
+            assert!((1i32).is_normal());
+            assert!((i32::MAX).is_normal());
+            assert!(!(0i32).is_normal());
+
+            assert!((1i64).is_normal());
+            assert!((i64::MAX).is_normal());
+            assert!(!(0i64).is_normal());
+
+            assert!((1i128).is_normal());
+            assert!((i128::MAX).is_normal());
+            assert!(!(0i128).is_normal());
+
+            assert!((1isize).is_normal());
+            assert!((isize::MAX).is_normal());
+            assert!(!(0isize).is_normal());
+
+            assert!((1u8).is_normal());
+            assert!((u8::MAX).is_normal());
+            assert!(!(0u8).is_normal());
+
+            assert!((1u16).is_normal());
+            assert!((u16::MAX).is_normal());
+            assert!(!(0u16).is_normal());
+
+            assert!((1u32).is_normal());
+            assert!((u32::MAX).is_normal());
+            assert!(!(0u32).is_normal());
+
+            assert!((1u64).is_normal());
+            assert!((u64::MAX).is_normal());
+            assert!(!(0u64).is_normal());
+
+            assert!((1u128).is_normal());
+            assert!((u128::MAX).is_normal());
+            assert!(!(0u128).is_normal());
+
+            assert!((1usize).is_normal());
+            assert!((usize::MAX).is_normal());
+            assert!(!(0usize).is_normal());
+        }
+
+        #[test]
+        fn TEST_f32() {
+            assert!(IsNormal::is_normal(&1.0f32));
+            assert!(IsNormal::is_normal(&(-1.0f32)));
+            assert!(IsNormal::is_normal(&f32::MAX));
+            assert!(IsNormal::is_normal(&f32::MIN));
+            assert!(IsNormal::is_normal(&f32::MIN_POSITIVE));
+
+            assert!(!IsNormal::is_normal(&0.0f32));
+            assert!(!IsNormal::is_normal(&(-0.0f32)));
+            assert!(!IsNormal::is_normal(&(f32::MIN_POSITIVE / 2.0)));
+            assert!(!IsNormal::is_normal(&f32::INFINITY));
+            assert!(!IsNormal::is_normal(&f32::NEG_INFINITY));
+            assert!(!IsNormal::is_normal(&f32::NAN));
+        }
+
+        #[test]
+        fn TEST_f64() {
+            assert!(IsNormal::is_normal(&1.0f64));
+            assert!(IsNormal::is_normal(&(-1.0f64)));
+            assert!(IsNormal::is_normal(&f64::MAX));
+            assert!(IsNormal::is_normal(&f64::MIN));
+            assert!(IsNormal::is_normal(&f64::MIN_POSITIVE));
+
+            assert!(!IsNormal::is_normal(&0.0f64));
+            assert!(!IsNormal::is_normal(&(-0.0f64)));
+            assert!(!IsNormal::is_normal(&(f64::MIN_POSITIVE / 2.0)));
+            assert!(!IsNormal::is_normal(&f64::INFINITY));
+            assert!(!IsNormal::is_normal(&f64::NEG_INFINITY));
+            assert!(!IsNormal::is_normal(&f64::NAN));
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(1.0f64).is_normal());
+            assert!(Rc::new(1i32).is_normal());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,312 @@
+// src/traits/is_one.rs : `IsOne`
+
+/// Trait defining instance method `is_one() : bool` that indicates whether
+/// the implementing type instance is numerically one, i.e. the
+/// multiplicative identity.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsOne-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`];
+/// - [`i16`];
+/// - [`i32`];
+/// - [`i64`];
+/// - [`i128`];
+/// - [`u8`];
+/// - [`u16`];
+/// - [`u32`];
+/// - [`u64`];
+/// - [`u128`];
+/// - [`isize`];
+/// - [`usize`];
+/// - [`f32`];
+/// - [`f64`];
+///
+/// ## Standard Numeric Types
+///
+/// If the feature `"implement-IsOne-for-standard_num_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::num::NonZeroI8`], ..., [`core::num::NonZeroI128`],
+///   [`core::num::NonZeroIsize`];
+/// - [`core::num::NonZeroU8`], ..., [`core::num::NonZeroU128`],
+///   [`core::num::NonZeroUsize`];
+/// - [`core::num::Saturating<T>`], where `T : IsOne`;
+/// - [`core::num::Wrapping<T>`], where `T : IsOne`;
+///
+/// NOTE: it is not implemented for [`std::time::Duration`], since a
+/// duration has no multiplicative identity (a duration may be multiplied
+/// only by a scalar).
+pub trait IsOne {
+    fn is_one(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsOne {
+    fn is_one(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsOne-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_IsOne_ {
+        ($type:tt, $one_value:expr) => {
+            impl super::IsOne for $type {
+                #[inline]
+                fn is_one(&self) -> bool {
+                    $one_value == *self
+                }
+            }
+        };
+    }
+
+    implement_IsOne_!(i8, 1);
+    implement_IsOne_!(i16, 1);
+    implement_IsOne_!(i32, 1);
+    implement_IsOne_!(i64, 1);
+    implement_IsOne_!(i128, 1);
+
+    implement_IsOne_!(u8, 1);
+    implement_IsOne_!(u16, 1);
+    implement_IsOne_!(u32, 1);
+    implement_IsOne_!(u64, 1);
+    implement_IsOne_!(u128, 1);
+
+    implement_IsOne_!(isize, 1);
+    implement_IsOne_!(usize, 1);
+
+    implement_IsOne_!(f32, 1.0);
+    implement_IsOne_!(f64, 1.0);
+}
+
+
+#[cfg(feature = "implement-IsOne-for-standard_num_types")]
+mod impl_for_std_num_types {
+    #![allow(non_snake_case)]
+
+    use super::IsOne;
+
+    use core::num as core_num;
+
+
+    // NonZero*
+
+    macro_rules! implement_IsOne_for_NonZero_ {
+        ($type:ident) => {
+            impl IsOne for core_num::$type {
+                #[inline]
+                fn is_one(&self) -> bool {
+                    1 == self.get()
+                }
+            }
+        };
+    }
+
+    implement_IsOne_for_NonZero_!(NonZeroI8);
+    implement_IsOne_for_NonZero_!(NonZeroI16);
+    implement_IsOne_for_NonZero_!(NonZeroI32);
+    implement_IsOne_for_NonZero_!(NonZeroI64);
+    implement_IsOne_for_NonZero_!(NonZeroI128);
+    implement_IsOne_for_NonZero_!(NonZeroIsize);
+
+    implement_IsOne_for_NonZero_!(NonZeroU8);
+    implement_IsOne_for_NonZero_!(NonZeroU16);
+    implement_IsOne_for_NonZero_!(NonZeroU32);
+    implement_IsOne_for_NonZero_!(NonZeroU64);
+    implement_IsOne_for_NonZero_!(NonZeroU128);
+    implement_IsOne_for_NonZero_!(NonZeroUsize);
+
+
+    // Saturating<T>
+
+    impl<T : IsOne> IsOne for core_num::Saturating<T> {
+        #[inline]
+        fn is_one(&self) -> bool {
+            self.0.is_one()
+        }
+    }
+
+
+    // Wrapping<T>
+
+    impl<T : IsOne> IsOne for core_num::Wrapping<T> {
+        #[inline]
+        fn is_one(&self) -> bool {
+            self.0.is_one()
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsOne;
+
+    use std::rc::Rc;
+
+
+    #[allow(unused)]
+    fn as_IsOne<T : IsOne>(t : &T) -> &impl IsOne {
+        t
+    }
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : i32,
+        }
+
+        impl IsOne for CustomType {
+            fn is_one(&self) -> bool {
+                1 == self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_WHEN_ONE() {
+            let ct = CustomType { value : 1 };
+
+            assert!(ct.is_one());
+
+            let ct = &ct;
+
+            assert!(ct.is_one());
+        }
+
+        #[test]
+        fn TEST_WHEN_NOT_ONE() {
+            let ct = CustomType { value : 0 };
+
+            assert!(!ct.is_one());
+
+            let ct = &ct;
+
+            assert!(!ct.is_one());
+        }
+
+        #[test]
+        fn TEST_WHEN_ONE_IN_Box() {
+            let ct = Box::new(CustomType { value : 1 });
+
+            assert!(ct.is_one());
+
+            let ct : Box<dyn IsOne> = Box::new(CustomType { value : -1 });
+
+            assert!(!ct.is_one());
+        }
+
+        #[test]
+        fn TEST_WHEN_ONE_IN_Rc() {
+            let ct = Rc::new(CustomType { value : 1 });
+
+            assert!(ct.is_one());
+
+            let ct = &Rc::new(CustomType { value : 2 });
+
+            assert!(!ct.is_one());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsOne-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_INTEGERS() {
+            assert!(1i8.is_one());
+            assert!(1i16.is_one());
+            assert!(1i32.is_one());
+            assert!(1i64.is_one());
+            assert!(1i128.is_one());
+
+            assert!(1u8.is_one());
+            assert!(1u16.is_one());
+            assert!(1u32.is_one());
+            assert!(1u64.is_one());
+            assert!(1u128.is_one());
+
+            assert!(1isize.is_one());
+            assert!(1usize.is_one());
+
+            assert!(!0i8.is_one());
+            assert!(!(-1i32).is_one());
+            assert!(!u64::MAX.is_one());
+            assert!(!2usize.is_one());
+        }
+
+        #[test]
+        fn TEST_FLOATS() {
+            assert!(1.0f32.is_one());
+            assert!(1.0f64.is_one());
+
+            assert!(!0.0f32.is_one());
+            assert!(!(-1.0f64).is_one());
+            assert!(!1.000001f64.is_one());
+            assert!(!f64::NAN.is_one());
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(1u8).is_one());
+            assert!(!Box::new(0u8).is_one());
+            assert!(Rc::new(1.0f32).is_one());
+            assert!(!Rc::new(0.5f32).is_one());
+        }
+    }
+
+
+    #[cfg(all(feature = "implement-IsOne-for-built_ins", feature = "implement-IsOne-for-standard_num_types"))]
+    mod TEST_STANDARD_NUM_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::num as std_num;
+
+
+        #[test]
+        fn TEST_NonZero() {
+            assert!(std_num::NonZeroI8::new(1).unwrap().is_one());
+            assert!(!std_num::NonZeroI8::new(-1).unwrap().is_one());
+            assert!(std_num::NonZeroU32::new(1).unwrap().is_one());
+            assert!(!std_num::NonZeroU32::new(2).unwrap().is_one());
+            assert!(std_num::NonZeroUsize::MIN.is_one());
+            assert!(!std_num::NonZeroUsize::MAX.is_one());
+        }
+
+        #[test]
+        fn TEST_Saturating() {
+            assert!(std_num::Saturating(1i16).is_one());
+            assert!(!std_num::Saturating(0i16).is_one());
+        }
+
+        #[test]
+        fn TEST_Wrapping() {
+            assert!(std_num::Wrapping(1u8).is_one());
+            assert!(!(std_num::Wrapping(u8::MAX) + std_num::Wrapping(1)).is_one());
+            assert!((std_num::Wrapping(u8::MAX) + std_num::Wrapping(2)).is_one());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,353 @@
+// src/traits/is_positive.rs : `IsPositive`
+
+/// Trait defining instance method `is_positive() : bool` that indicates
+/// whether the implementing type instance is (strictly) greater than zero.
+///
+/// NOTE: neither zero nor "not a number" is positive.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsPositive-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+///
+/// ## Standard Numeric Types
+///
+/// If the feature `"implement-IsPositive-for-standard_num_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::num::NonZeroI8`], ..., [`core::num::NonZeroI128`],
+///   [`core::num::NonZeroIsize`];
+/// - [`core::num::NonZeroU8`], ..., [`core::num::NonZeroU128`],
+///   [`core::num::NonZeroUsize`];
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-IsPositive-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::time::Duration`];
+///
+/// NOTE: many toolchains provide deprecated inherent methods
+/// `f32::is_positive()` and `f64::is_positive()`, which are equivalent to
+/// `is_sign_positive()` and so yield `true` for `0.0`. Where they are
+/// provided, they take precedence over this trait's method when it is
+/// called with method syntax on a concrete float, as in `x.is_positive()`,
+/// so the two spellings can give different answers. To obtain this trait's
+/// semantics, call it as `IsPositive::is_positive(&x)`, or via a generic
+/// bound:
+///
+/// ```
+/// # #[cfg(feature = "implement-IsPositive-for-built_ins")]
+/// # {
+/// use base_traits::IsPositive;
+///
+/// fn is_positive_<T : IsPositive>(t : &T) -> bool {
+///     t.is_positive()
+/// }
+///
+/// assert!(!IsPositive::is_positive(&0.0f64));
+/// assert!(!is_positive_(&0.0f64));
+/// # }
+/// ```
+pub trait IsPositive {
+    fn is_positive(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsPositive {
+    fn is_positive(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsPositive-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_IsPositive_for_signed_ {
+        ($type:tt) => {
+            impl super::IsPositive for $type {
+                #[inline]
+                fn is_positive(&self) -> bool {
+                    $type::is_positive(*self)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsPositive_for_unsigned_ {
+        ($type:tt) => {
+            impl super::IsPositive for $type {
+                #[inline]
+                fn is_positive(&self) -> bool {
+                    0 != *self
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsPositive_for_float_ {
+        ($type:tt) => {
+            impl super::IsPositive for $type {
+                #[inline]
+                fn is_positive(&self) -> bool {
+                    *self > 0.0
+                }
+            }
+        };
+    }
+
+    implement_IsPositive_for_signed_!(i8);
+    implement_IsPositive_for_signed_!(i16);
+    implement_IsPositive_for_signed_!(i32);
+    implement_IsPositive_for_signed_!(i64);
+    implement_IsPositive_for_signed_!(i128);
+    implement_IsPositive_for_signed_!(isize);
+
+    implement_IsPositive_for_unsigned_!(u8);
+    implement_IsPositive_for_unsigned_!(u16);
+    implement_IsPositive_for_unsigned_!(u32);
+    implement_IsPositive_for_unsigned_!(u64);
+    implement_IsPositive_for_unsigned_!(u128);
+    implement_IsPositive_for_unsigned_!(usize);
+
+    implement_IsPositive_for_float_!(f32);
+    implement_IsPositive_for_float_!(f64);
+}
+
+
+#[cfg(feature = "implement-IsPositive-for-standard_num_types")]
+mod impl_for_std_num_types {
+    #![allow(non_snake_case)]
+
+    use super::IsPositive;
+
+    use core::num as core_num;
+
+
+    // NonZero*
+
+    macro_rules! implement_IsPositive_for_signed_NonZero_ {
+        ($type:ident) => {
+            impl IsPositive for core_num::$type {
+                #[inline]
+                fn is_positive(&self) -> bool {
+                    self.get() > 0
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsPositive_for_unsigned_NonZero_ {
+        ($type:ident) => {
+            impl IsPositive for core_num::$type {
+                #[inline]
+                fn is_positive(&self) -> bool {
+                    true
+                }
+            }
+        };
+    }
+
+    implement_IsPositive_for_signed_NonZero_!(NonZeroI8);
+    implement_IsPositive_for_signed_NonZero_!(NonZeroI16);
+    implement_IsPositive_for_signed_NonZero_!(NonZeroI32);
+    implement_IsPositive_for_signed_NonZero_!(NonZeroI64);
+    implement_IsPositive_for_signed_NonZero_!(NonZeroI128);
+    implement_IsPositive_for_signed_NonZero_!(NonZeroIsize);
+
+    implement_IsPositive_for_unsigned_NonZero_!(NonZeroU8);
+    implement_IsPositive_for_unsigned_NonZero_!(NonZeroU16);
+    implement_IsPositive_for_unsigned_NonZero_!(NonZeroU32);
+    implement_IsPositive_for_unsigned_NonZero_!(NonZeroU64);
+    implement_IsPositive_for_unsigned_NonZero_!(NonZeroU128);
+    implement_IsPositive_for_unsigned_NonZero_!(NonZeroUsize);
+}
+
+
+#[cfg(feature = "implement-IsPositive-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl super::IsPositive for core_time::Duration {
+        #[inline]
+        fn is_positive(&self) -> bool {
+            core_time::Duration::ZERO != *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsPositive;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// A fixed-point type, in which the value is held in hundredths.
+        #[derive(Debug, PartialEq)]
+        struct Price {
+            cents : i64,
+        }
+
+        impl IsPositive for Price {
+            fn is_positive(&self) -> bool {
+                self.cents > 0
+            }
+        }
+
+
+        #[test]
+        fn TEST_IsPositive() {
+            assert!(Price { cents : 1 }.is_positive());
+            assert!(!Price { cents : 0 }.is_positive());
+            assert!(!Price { cents : -1 }.is_positive());
+        }
+
+        #[test]
+        fn TEST_IsPositive_IN_Box_AND_Rc() {
+            let price : Box<dyn IsPositive> = Box::new(Price { cents : 1234 });
+
+            assert!(price.is_positive());
+            assert!(!Rc::new(Price { cents : -1234 }).is_positive());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsPositive-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        // NOTE: the fully-qualified form is used throughout, since many of
+        // the built-in types have inherent methods of the same name
+
+
+        #[test]
+        fn TEST_signed_integers() {
+            assert!(IsPositive::is_positive(&1i8));
+            assert!(IsPositive::is_positive(&i16::MAX));
+            assert!(IsPositive::is_positive(&1i32));
+            assert!(IsPositive::is_positive(&1i64));
+            assert!(IsPositive::is_positive(&1i128));
+            assert!(IsPositive::is_positive(&1isize));
+
+            assert!(!IsPositive::is_positive(&0i8));
+            assert!(!IsPositive::is_positive(&-1i32));
+            assert!(!IsPositive::is_positive(&i64::MIN));
+        }
+
+        #[test]
+        fn TEST_unsigned_integers() {
+            assert!(1u8.is_positive());
+            assert!(u16::MAX.is_positive());
+            assert!(1u32.is_positive());
+            assert!(1u64.is_positive());
+            assert!(1u128.is_positive());
+            assert!(1usize.is_positive());
+
+            assert!(!0u8.is_positive());
+            assert!(!0usize.is_positive());
+        }
+
+        #[test]
+        fn TEST_floats() {
+            assert!(IsPositive::is_positive(&1.0f32));
+            assert!(IsPositive::is_positive(&f32::INFINITY));
+            assert!(IsPositive::is_positive(&f64::MIN_POSITIVE));
+
+            assert!(!IsPositive::is_positive(&0.0f32));
+            assert!(!IsPositive::is_positive(&-0.0f32));
+            assert!(!IsPositive::is_positive(&f64::NAN));
+            assert!(!IsPositive::is_positive(&-1.0f64));
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn TEST_floats_WITH_METHOD_SYNTAX() {
+            let x = 0.0f64;
+
+            let by_method = x.is_positive();
+            let by_trait = IsPositive::is_positive(&x);
+
+            assert!(!by_trait);
+
+            // NOTE: where the toolchain provides the deprecated inherent
+            // method, method syntax on a concrete float selects it, and its
+            // answer is that of the sign bit
+            if by_method != by_trait {
+                assert_eq!(x.is_sign_positive(), by_method);
+            }
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(1i32).is_positive());
+            assert!(!Rc::new(-1.0f64).is_positive());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsPositive-for-standard_num_types")]
+    mod TEST_STANDARD_NUM_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::num as std_num;
+
+
+        #[test]
+        fn TEST_NonZero() {
+            assert!(IsPositive::is_positive(&std_num::NonZeroI8::new(1).unwrap()));
+            assert!(IsPositive::is_positive(&std_num::NonZeroI64::MAX));
+            assert!(!IsPositive::is_positive(&std_num::NonZeroI32::new(-1).unwrap()));
+            assert!(std_num::NonZeroU8::MIN.is_positive());
+            assert!(std_num::NonZeroUsize::MAX.is_positive());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsPositive-for-standard_time_types")]
+    mod TEST_STANDARD_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time as std_time;
+
+
+        #[test]
+        fn TEST_Duration() {
+            assert!(std_time::Duration::from_nanos(1).is_positive());
+            assert!(std_time::Duration::MAX.is_positive());
+
+            assert!(!std_time::Duration::ZERO.is_positive());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,138 @@
+// src/traits/is_positive_infinity.rs : `IsPositiveInfinity`
+
+/// Trait defining instance method `is_positive_infinity() : bool` that indicates
+/// whether the implementing type instance is conceptually (or actually)
+/// positive infinity.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsPositiveInfinity-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`f32`];
+/// - [`f64`];
+pub trait IsPositiveInfinity {
+    fn is_positive_infinity(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsPositiveInfinity {
+    fn is_positive_infinity(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsPositiveInfinity-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_IsPositiveInfinity_ {
+        ($type:tt) => {
+            impl super::IsPositiveInfinity for $type {
+                #[inline]
+                fn is_positive_infinity(&self) -> bool {
+                    $type::INFINITY == *self
+                }
+            }
+        };
+    }
+
+    implement_IsPositiveInfinity_!(f32);
+    implement_IsPositiveInfinity_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsPositiveInfinity;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// A wrapper type that adopts the semantics of its `f64` value.
+        #[derive(Debug)]
+        struct Price {
+            value : f64,
+        }
+
+        impl IsPositiveInfinity for Price {
+            fn is_positive_infinity(&self) -> bool {
+                f64::INFINITY == self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_IsPositiveInfinity() {
+            assert!(Price { value : f64::INFINITY }.is_positive_infinity());
+            assert!(!Price { value : f64::NEG_INFINITY }.is_positive_infinity());
+            assert!(!Price { value : f64::NAN }.is_positive_infinity());
+            assert!(!Price { value : f64::MAX }.is_positive_infinity());
+            assert!(!Price { value : 0.0f64 }.is_positive_infinity());
+            assert!(!Price { value : 1.0f64 }.is_positive_infinity());
+        }
+
+        #[test]
+        fn TEST_IsPositiveInfinity_IN_Box() {
+            let price : Box<dyn IsPositiveInfinity> = Box::new(Price { value : f64::INFINITY });
+
+            assert!(price.is_positive_infinity());
+        }
+
+        #[test]
+        fn TEST_IsPositiveInfinity_IN_Rc() {
+            let price = Rc::new(Price { value : 0.0 });
+
+            assert!(!price.is_positive_infinity());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsPositiveInfinity-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_f32() {
+            assert!(f32::INFINITY.is_positive_infinity());
+
+            assert!(!(f32::NEG_INFINITY).is_positive_infinity());
+            assert!(!(f32::NAN).is_positive_infinity());
+            assert!(!(f32::MAX).is_positive_infinity());
+            assert!(!(0.0f32).is_positive_infinity());
+            assert!(!(1.0f32).is_positive_infinity());
+        }
+
+        #[test]
+        fn TEST_f64() {
+            assert!(f64::INFINITY.is_positive_infinity());
+
+            assert!(!(f64::NEG_INFINITY).is_positive_infinity());
+            assert!(!(f64::NAN).is_positive_infinity());
+            assert!(!(f64::MAX).is_positive_infinity());
+            assert!(!(0.0f64).is_positive_infinity());
+            assert!(!(1.0f64).is_positive_infinity());
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(f64::INFINITY).is_positive_infinity());
+            assert!(!Rc::new(1.0f32).is_positive_infinity());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,311 @@
+// src/traits/is_sign_negative.rs : `IsSignNegative`
+
+/// Trait defining instance method `is_sign_negative() : bool` that indicates
+/// whether the implementing type instance has a negative sign, which, in
+/// contrast to [`IsNegative`](crate::IsNegative), includes negative zero
+/// (and "not a number" values that have the sign bit set).
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsSignNegative-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+///
+/// ## Standard Numeric Types
+///
+/// If the feature `"implement-IsSignNegative-for-standard_num_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::num::NonZeroI8`], ..., [`core::num::NonZeroI128`],
+///   [`core::num::NonZeroIsize`];
+/// - [`core::num::NonZeroU8`], ..., [`core::num::NonZeroU128`],
+///   [`core::num::NonZeroUsize`];
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-IsSignNegative-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::time::Duration`];
+///
+/// NOTE: an unsigned integer, or a duration, never has a negative sign.
+pub trait IsSignNegative {
+    fn is_sign_negative(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsSignNegative {
+    fn is_sign_negative(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsSignNegative-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_IsSignNegative_for_signed_ {
+        ($type:tt) => {
+            impl super::IsSignNegative for $type {
+                #[inline]
+                fn is_sign_negative(&self) -> bool {
+                    *self < 0
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsSignNegative_for_unsigned_ {
+        ($type:tt) => {
+            impl super::IsSignNegative for $type {
+                #[inline]
+                fn is_sign_negative(&self) -> bool {
+                    false
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsSignNegative_for_float_ {
+        ($type:tt) => {
+            impl super::IsSignNegative for $type {
+                #[inline]
+                fn is_sign_negative(&self) -> bool {
+                    $type::is_sign_negative(*self)
+                }
+            }
+        };
+    }
+
+    implement_IsSignNegative_for_signed_!(i8);
+    implement_IsSignNegative_for_signed_!(i16);
+    implement_IsSignNegative_for_signed_!(i32);
+    implement_IsSignNegative_for_signed_!(i64);
+    implement_IsSignNegative_for_signed_!(i128);
+    implement_IsSignNegative_for_signed_!(isize);
+
+    implement_IsSignNegative_for_unsigned_!(u8);
+    implement_IsSignNegative_for_unsigned_!(u16);
+    implement_IsSignNegative_for_unsigned_!(u32);
+    implement_IsSignNegative_for_unsigned_!(u64);
+    implement_IsSignNegative_for_unsigned_!(u128);
+    implement_IsSignNegative_for_unsigned_!(usize);
+
+    implement_IsSignNegative_for_float_!(f32);
+    implement_IsSignNegative_for_float_!(f64);
+}
+
+
+#[cfg(feature = "implement-IsSignNegative-for-standard_num_types")]
+mod impl_for_std_num_types {
+    #![allow(non_snake_case)]
+
+    use super::IsSignNegative;
+
+    use core::num as core_num;
+
+
+    // NonZero*
+
+    macro_rules! implement_IsSignNegative_for_signed_NonZero_ {
+        ($type:ident) => {
+            impl IsSignNegative for core_num::$type {
+                #[inline]
+                fn is_sign_negative(&self) -> bool {
+                    self.get() < 0
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsSignNegative_for_unsigned_NonZero_ {
+        ($type:ident) => {
+            impl IsSignNegative for core_num::$type {
+                #[inline]
+                fn is_sign_negative(&self) -> bool {
+                    false
+                }
+            }
+        };
+    }
+
+    implement_IsSignNegative_for_signed_NonZero_!(NonZeroI8);
+    implement_IsSignNegative_for_signed_NonZero_!(NonZeroI16);
+    implement_IsSignNegative_for_signed_NonZero_!(NonZeroI32);
+    implement_IsSignNegative_for_signed_NonZero_!(NonZeroI64);
+    implement_IsSignNegative_for_signed_NonZero_!(NonZeroI128);
+    implement_IsSignNegative_for_signed_NonZero_!(NonZeroIsize);
+
+    implement_IsSignNegative_for_unsigned_NonZero_!(NonZeroU8);
+    implement_IsSignNegative_for_unsigned_NonZero_!(NonZeroU16);
+    implement_IsSignNegative_for_unsigned_NonZero_!(NonZeroU32);
+    implement_IsSignNegative_for_unsigned_NonZero_!(NonZeroU64);
+    implement_IsSignNegative_for_unsigned_NonZero_!(NonZeroU128);
+    implement_IsSignNegative_for_unsigned_NonZero_!(NonZeroUsize);
+}
+
+
+#[cfg(feature = "implement-IsSignNegative-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl super::IsSignNegative for core_time::Duration {
+        #[inline]
+        fn is_sign_negative(&self) -> bool {
+            false
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsSignNegative;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// A wrapper type that adopts the semantics of its `f64` value.
+        #[derive(Debug)]
+        struct Price {
+            value : f64,
+        }
+
+        impl IsSignNegative for Price {
+            fn is_sign_negative(&self) -> bool {
+                self.value.is_sign_negative()
+            }
+        }
+
+
+        #[test]
+        fn TEST_IsSignNegative() {
+            assert!(Price { value : -1.0 }.is_sign_negative());
+            assert!(Price { value : -0.0 }.is_sign_negative());
+
+            assert!(!Price { value : 0.0 }.is_sign_negative());
+            assert!(!Price { value : 1.0 }.is_sign_negative());
+        }
+
+        #[test]
+        fn TEST_IsSignNegative_IN_Box_AND_Rc() {
+            let price : Box<dyn IsSignNegative> = Box::new(Price { value : -0.0 });
+
+            assert!(price.is_sign_negative());
+            assert!(!Rc::new(Price { value : 0.0 }).is_sign_negative());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsSignNegative-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        // NOTE: the fully-qualified form is used throughout, since many of
+        // the built-in types have inherent methods of the same name
+
+
+        #[test]
+        fn TEST_signed_integers() {
+            assert!((-1i8).is_sign_negative());
+            assert!(i16::MIN.is_sign_negative());
+            assert!((-1i32).is_sign_negative());
+            assert!((-1i64).is_sign_negative());
+            assert!((-1i128).is_sign_negative());
+            assert!((-1isize).is_sign_negative());
+
+            assert!(!0i8.is_sign_negative());
+            assert!(!1i32.is_sign_negative());
+            assert!(!i64::MAX.is_sign_negative());
+        }
+
+        #[test]
+        fn TEST_unsigned_integers() {
+            assert!(!0u8.is_sign_negative());
+            assert!(!u16::MAX.is_sign_negative());
+            assert!(!1u32.is_sign_negative());
+            assert!(!1u64.is_sign_negative());
+            assert!(!1u128.is_sign_negative());
+            assert!(!1usize.is_sign_negative());
+        }
+
+        #[test]
+        fn TEST_floats() {
+            assert!(IsSignNegative::is_sign_negative(&-1.0f32));
+            assert!(IsSignNegative::is_sign_negative(&-0.0f32));
+            assert!(IsSignNegative::is_sign_negative(&f64::NEG_INFINITY));
+            assert!(IsSignNegative::is_sign_negative(&-0.0f64));
+
+            assert!(!IsSignNegative::is_sign_negative(&0.0f32));
+            assert!(!IsSignNegative::is_sign_negative(&f64::INFINITY));
+            assert!(!IsSignNegative::is_sign_negative(&1.0f64));
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(-0.0f64).is_sign_negative());
+            assert!(!Rc::new(0u8).is_sign_negative());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsSignNegative-for-standard_num_types")]
+    mod TEST_STANDARD_NUM_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::num as std_num;
+
+
+        #[test]
+        fn TEST_NonZero() {
+            assert!(std_num::NonZeroI8::new(-1).unwrap().is_sign_negative());
+            assert!(std_num::NonZeroI64::MIN.is_sign_negative());
+            assert!(!std_num::NonZeroI32::new(1).unwrap().is_sign_negative());
+            assert!(!std_num::NonZeroU8::MIN.is_sign_negative());
+            assert!(!std_num::NonZeroUsize::MAX.is_sign_negative());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsSignNegative-for-standard_time_types")]
+    mod TEST_STANDARD_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time as std_time;
+
+
+        #[test]
+        fn TEST_Duration() {
+            assert!(!std_time::Duration::ZERO.is_sign_negative());
+            assert!(!std_time::Duration::MAX.is_sign_negative());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,227 @@
+// src/traits/is_subnormal.rs : `IsSubnormal`
+
+/// Trait defining instance method `is_subnormal() : bool` that indicates
+/// whether the implementing type instance is subnormal, i.e. is non-zero
+/// and too small in magnitude to be represented in normalized form.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-IsSubnormal-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+///
+/// NOTE: an integer is never subnormal.
+pub trait IsSubnormal {
+    fn is_subnormal(&self) -> bool;
+}
+
+
+implement_forwarding_!(IsSubnormal {
+    fn is_subnormal(&self) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsSubnormal-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_IsSubnormal_for_integer_ {
+        ($type:tt) => {
+            impl super::IsSubnormal for $type {
+                #[inline]
+                fn is_subnormal(&self) -> bool {
+                    false
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_IsSubnormal_for_float_ {
+        ($type:tt) => {
+            impl super::IsSubnormal for $type {
+                #[inline]
+                fn is_subnormal(&self) -> bool {
+                    $type::is_subnormal(*self)
+                }
+            }
+        };
+    }
+
+    implement_IsSubnormal_for_integer_!(i8);
+    implement_IsSubnormal_for_integer_!(i16);
+    implement_IsSubnormal_for_integer_!(i32);
+    implement_IsSubnormal_for_integer_!(i64);
+    implement_IsSubnormal_for_integer_!(i128);
+    implement_IsSubnormal_for_integer_!(isize);
+
+    implement_IsSubnormal_for_integer_!(u8);
+    implement_IsSubnormal_for_integer_!(u16);
+    implement_IsSubnormal_for_integer_!(u32);
+    implement_IsSubnormal_for_integer_!(u64);
+    implement_IsSubnormal_for_integer_!(u128);
+    implement_IsSubnormal_for_integer_!(usize);
+
+    implement_IsSubnormal_for_float_!(f32);
+    implement_IsSubnormal_for_float_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsSubnormal;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// A wrapper type that adopts the semantics of its `f64` value.
+        #[derive(Debug)]
+        struct Price {
+            value : f64,
+        }
+
+        impl IsSubnormal for Price {
+            fn is_subnormal(&self) -> bool {
+                self.value.is_subnormal()
+            }
+        }
+
+
+        #[test]
+        fn TEST_IsSubnormal() {
+            assert!(Price { value : f64::MIN_POSITIVE / 2.0 }.is_subnormal());
+
+            assert!(!Price { value : 0.0 }.is_subnormal());
+            assert!(!Price { value : 1.0 }.is_subnormal());
+            assert!(!Price { value : f64::MIN_POSITIVE }.is_subnormal());
+            assert!(!Price { value : f64::INFINITY }.is_subnormal());
+            assert!(!Price { value : f64::NAN }.is_subnormal());
+        }
+
+        #[test]
+        fn TEST_IsSubnormal_IN_Box() {
+            let price : Box<dyn IsSubnormal> = Box::new(Price { value : f64::MIN_POSITIVE / 2.0 });
+
+            assert!(price.is_subnormal());
+        }
+
+        #[test]
+        fn TEST_IsSubnormal_IN_Rc() {
+            let price = Rc::new(Price { value : 0.0 });
+
+            assert!(!price.is_subnormal());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsSubnormal-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        // NOTE: the floating-point tests use the fully-qualified form, since
+        // `f32` and `f64` have inherent methods of the same name
+
+        #[test]
+        fn TEST_integers() {
+            assert!(!(0i8).is_subnormal());
+            assert!(!(1i8).is_subnormal());
+            assert!(!(i8::MAX).is_subnormal());
+
+            assert!(!(0i16).is_subnormal());
+            assert!(!(1i16).is_subnormal());
+            assert!(!(i16::MAX).is_subnormal());
+
+            assert!(!(0i32).is_subnormal());
+            assert!(!(1i32).is_subnormal());
+            assert!(!(i32::MAX).is_subnormal());
+
+            assert!(!(0i64).is_subnormal());
+            assert!(!(1i64).is_subnormal());
+            assert!(!(i64::MAX).is_subnormal());
+
+            assert!(!(0i128).is_subnormal());
+            assert!(!(1i128).is_subnormal());
+            assert!(!(i128::MAX).is_subnormal());
+
+            assert!(!(0isize).is_subnormal());
+            assert!(!(1isize).is_subnormal());
+            assert!(!(isize::MAX).is_subnormal());
+
+            assert!(!(0u8).is_subnormal());
+            assert!(!(1u8).is_subnormal());
+            assert!(!(u8::MAX).is_subnormal());
+
+            assert!(!(0u16).is_subnormal());
+            assert!(!(1u16).is_subnormal());
+            assert!(!(u16::MAX).is_subnormal());
+
+            assert!(!(0u32).is_subnormal());
+            assert!(!(1u32).is_subnormal());
+            assert!(!(u32::MAX).is_subnormal());
+
+            assert!(!(0u64).is_subnormal());
+            assert!(!(1u64).is_subnormal());
+            assert!(!(u64::MAX).is_subnormal());
+
+            assert!(!(0u128).is_subnormal());
+            assert!(!(1u128).is_subnormal());
+            assert!(!(u128::MAX).is_subnormal());
+
+            assert!(!(0usize).is_subnormal());
+            assert!(!(1usize).is_subnormal());
+            assert!(!(usize::MAX).is_subnormal());
+        }
+
+        #[test]
+        fn TEST_f32() {
+            assert!(IsSubnormal::is_subnormal(&(f32::MIN_POSITIVE / 2.0)));
+            assert!(IsSubnormal::is_subnormal(&(-f32::MIN_POSITIVE / 2.0)));
+
+            assert!(!IsSubnormal::is_subnormal(&0.0f32));
+            assert!(!IsSubnormal::is_subnormal(&1.0f32));
+            assert!(!IsSubnormal::is_subnormal(&f32::MIN_POSITIVE));
+            assert!(!IsSubnormal::is_subnormal(&f32::MAX));
+            assert!(!IsSubnormal::is_subnormal(&f32::INFINITY));
+            assert!(!IsSubnormal::is_subnormal(&f32::NAN));
+        }
+
+        #[test]
+        fn TEST_f64() {
+            assert!(IsSubnormal::is_subnormal(&(f64::MIN_POSITIVE / 2.0)));
+            assert!(IsSubnormal::is_subnormal(&(-f64::MIN_POSITIVE / 2.0)));
+
+            assert!(!IsSubnormal::is_subnormal(&0.0f64));
+            assert!(!IsSubnormal::is_subnormal(&1.0f64));
+            assert!(!IsSubnormal::is_subnormal(&f64::MIN_POSITIVE));
+            assert!(!IsSubnormal::is_subnormal(&f64::MAX));
+            assert!(!IsSubnormal::is_subnormal(&f64::INFINITY));
+            assert!(!IsSubnormal::is_subnormal(&f64::NAN));
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            assert!(Box::new(f64::MIN_POSITIVE / 2.0).is_subnormal());
+            assert!(!Rc::new(0i32).is_subnormal());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
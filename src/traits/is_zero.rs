@@ -26,6 +26,44 @@
 /// - [`f64`];
 /// - [`char`];
 ///
+/// ## Standard Cell Types
+///
+/// If the feature `"implement-IsZero-for-standard_cell_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::cell::Cell<T>`], where `T : Copy + IsZero`;
+///
+/// ## Standard Network Types
+///
+/// If the feature `"implement-IsZero-for-standard_net_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::net::IpAddr`];
+/// - [`core::net::Ipv4Addr`];
+/// - [`core::net::Ipv6Addr`];
+/// - [`core::net::SocketAddr`];
+/// - [`core::net::SocketAddrV4`];
+/// - [`core::net::SocketAddrV6`];
+///
+/// An address is zero if it is the "unspecified" address, i.e. `0.0.0.0`
+/// or `::`; a socket address is zero if its address is unspecified and
+/// its port is 0 (regardless, for `SocketAddrV6`, of its flow information
+/// and scope identifier).
+///
+/// ## Standard Numeric Types
+///
+/// If the feature `"implement-IsZero-for-standard_num_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::num::NonZeroI8`], ..., [`core::num::NonZeroI128`],
+///   [`core::num::NonZeroIsize`];
+/// - [`core::num::NonZeroU8`], ..., [`core::num::NonZeroU128`],
+///   [`core::num::NonZeroUsize`];
+/// - [`core::num::Saturating<T>`], where `T : IsZero`;
+/// - [`core::num::Wrapping<T>`], where `T : IsZero`;
+///
+/// NOTE: an instance of a `NonZero*` type is, by definition, never zero.
+///
 /// ## Standard Process Types
 ///
 /// If the feature `"implement-IsZero-for-standard_process_types"`
@@ -33,30 +71,39 @@
 /// for the following types:
 /// - [`std::process::ExitStatus`];
 ///
+/// ## Standard Synchronisation Types
+///
+/// If the feature `"implement-IsZero-for-standard_sync_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types (where supported by the target):
+/// - [`core::sync::atomic::AtomicI8`], ..., [`core::sync::atomic::AtomicI64`],
+///   [`core::sync::atomic::AtomicIsize`];
+/// - [`core::sync::atomic::AtomicU8`], ..., [`core::sync::atomic::AtomicU64`],
+///   [`core::sync::atomic::AtomicUsize`];
+///
+/// NOTE: the value is loaded with [`core::sync::atomic::Ordering::Relaxed`].
+/// Where a different ordering is required, use
+/// [`crate::IsZeroWithOrdering`].
+///
 /// ## Standard Time Types
 ///
 /// If the feature `"implement-IsZero-for-standard_time_types"`
 /// is defined (as it is by `"default"`), then this is also implemented
 /// for the following types:
 /// - [`std::time::Duration`];
+/// - [`std::time::SystemTime`], which is zero if it is
+///   [`std::time::UNIX_EPOCH`];
+///
+/// NOTE: it is not implemented for [`std::time::Instant`], since an instant
+/// has no epoch.
 pub trait IsZero {
     fn is_zero(&self) -> bool;
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : IsZero + ?Sized> IsZero for Box<T> {
-    fn is_zero(&self) -> bool {
-        (**self).is_zero()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : IsZero + ?Sized> IsZero for std::rc::Rc<T> {
-    fn is_zero(&self) -> bool {
-        (**self).is_zero()
-    }
-}
+implement_forwarding_!(IsZero {
+    fn is_zero(&self) -> bool;
+});
 
 
 #[cfg(feature = "implement-IsZero-for-built_ins")]
@@ -96,25 +143,146 @@ mod impl_for_built_ins {
 }
 
 
+#[cfg(feature = "implement-IsZero-for-standard_cell_types")]
+mod impl_for_std_cell_types {
+    #![allow(non_snake_case)]
+
+    use super::IsZero;
+
+    use core::cell as core_cell;
+
+
+    // Cell<T>
+
+    impl<T : Copy + IsZero> IsZero for core_cell::Cell<T> {
+        #[inline]
+        fn is_zero(&self) -> bool {
+            self.get().is_zero()
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-IsZero-for-standard_net_types")]
+mod impl_for_std_net_types {
+    #![allow(non_snake_case)]
+
+    use super::IsZero;
+
+    use core::net as core_net;
+
+
+    // Ipv4Addr
+
+    impl IsZero for core_net::Ipv4Addr {
+        #[inline]
+        fn is_zero(&self) -> bool {
+            self.is_unspecified()
+        }
+    }
+
+    // Ipv6Addr
+
+    impl IsZero for core_net::Ipv6Addr {
+        #[inline]
+        fn is_zero(&self) -> bool {
+            self.is_unspecified()
+        }
+    }
+
+    // IpAddr
+
+    impl IsZero for core_net::IpAddr {
+        #[inline]
+        fn is_zero(&self) -> bool {
+            self.is_unspecified()
+        }
+    }
+
+    // SocketAddrV4
+
+    impl IsZero for core_net::SocketAddrV4 {
+        #[inline]
+        fn is_zero(&self) -> bool {
+            self.ip().is_unspecified() && 0 == self.port()
+        }
+    }
+
+    // SocketAddrV6
+
+    impl IsZero for core_net::SocketAddrV6 {
+        #[inline]
+        fn is_zero(&self) -> bool {
+            self.ip().is_unspecified() && 0 == self.port()
+        }
+    }
+
+    // SocketAddr
+
+    impl IsZero for core_net::SocketAddr {
+        #[inline]
+        fn is_zero(&self) -> bool {
+            self.ip().is_unspecified() && 0 == self.port()
+        }
+    }
+}
+
+
 #[cfg(feature = "implement-IsZero-for-standard_num_types")]
 mod impl_for_std_num_types {
     #![allow(non_snake_case)]
 
-    /*
-    use std::num as std_num;
-     */
+    use super::IsZero;
+
+    use core::num as core_num;
 
 
-    // NonZero<T>
+    // NonZero*
 
-    /*
-    impl<T> super::IsZero for std_num::NonZero<T>
-    {
+    macro_rules! implement_IsZero_for_NonZero_ {
+        ($type:ident) => {
+            impl IsZero for core_num::$type {
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    false
+                }
+            }
+        };
+    }
+
+    implement_IsZero_for_NonZero_!(NonZeroI8);
+    implement_IsZero_for_NonZero_!(NonZeroI16);
+    implement_IsZero_for_NonZero_!(NonZeroI32);
+    implement_IsZero_for_NonZero_!(NonZeroI64);
+    implement_IsZero_for_NonZero_!(NonZeroI128);
+    implement_IsZero_for_NonZero_!(NonZeroIsize);
+
+    implement_IsZero_for_NonZero_!(NonZeroU8);
+    implement_IsZero_for_NonZero_!(NonZeroU16);
+    implement_IsZero_for_NonZero_!(NonZeroU32);
+    implement_IsZero_for_NonZero_!(NonZeroU64);
+    implement_IsZero_for_NonZero_!(NonZeroU128);
+    implement_IsZero_for_NonZero_!(NonZeroUsize);
+
+
+    // Saturating<T>
+
+    impl<T : IsZero> IsZero for core_num::Saturating<T> {
+        #[inline]
         fn is_zero(&self) -> bool {
-            false
+            self.0.is_zero()
+        }
+    }
+
+
+    // Wrapping<T>
+
+    impl<T : IsZero> IsZero for core_num::Wrapping<T> {
+        #[inline]
+        fn is_zero(&self) -> bool {
+            self.0.is_zero()
         }
     }
-     */
 }
 
 
@@ -141,6 +309,55 @@ mod impl_for_std_process_types {
 }
 
 
+#[cfg(feature = "implement-IsZero-for-standard_sync_types")]
+mod impl_for_std_sync_types {
+    #![allow(non_snake_case)]
+
+    use super::IsZero;
+
+    use core::sync::atomic as core_atomic;
+
+
+    // Atomic*
+
+    macro_rules! implement_IsZero_for_Atomic_ {
+        ($type:ident) => {
+            impl IsZero for core_atomic::$type {
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    0 == self.load(core_atomic::Ordering::Relaxed)
+                }
+            }
+        };
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    implement_IsZero_for_Atomic_!(AtomicI8);
+    #[cfg(target_has_atomic = "8")]
+    implement_IsZero_for_Atomic_!(AtomicU8);
+
+    #[cfg(target_has_atomic = "16")]
+    implement_IsZero_for_Atomic_!(AtomicI16);
+    #[cfg(target_has_atomic = "16")]
+    implement_IsZero_for_Atomic_!(AtomicU16);
+
+    #[cfg(target_has_atomic = "32")]
+    implement_IsZero_for_Atomic_!(AtomicI32);
+    #[cfg(target_has_atomic = "32")]
+    implement_IsZero_for_Atomic_!(AtomicU32);
+
+    #[cfg(target_has_atomic = "64")]
+    implement_IsZero_for_Atomic_!(AtomicI64);
+    #[cfg(target_has_atomic = "64")]
+    implement_IsZero_for_Atomic_!(AtomicU64);
+
+    #[cfg(target_has_atomic = "ptr")]
+    implement_IsZero_for_Atomic_!(AtomicIsize);
+    #[cfg(target_has_atomic = "ptr")]
+    implement_IsZero_for_Atomic_!(AtomicUsize);
+}
+
+
 #[cfg(all(not(feature = "nostd"), feature = "implement-IsZero-for-standard_time_types"))]
 mod impl_for_std_time_types {
     #![allow(non_snake_case)]
@@ -158,6 +375,11 @@ mod impl_for_std_time_types {
         pub(super) fn get_is_zero_Duration_(d : &std_time::Duration) -> bool {
             d.is_zero()
         }
+
+        #[inline]
+        pub(super) fn get_is_zero_SystemTime_(st : &std_time::SystemTime) -> bool {
+            std_time::UNIX_EPOCH == *st
+        }
     }
 
 
@@ -168,6 +390,14 @@ mod impl_for_std_time_types {
             isolate_::get_is_zero_Duration_(self)
         }
     }
+
+    // SystemTime
+
+    impl super::IsZero for std_time::SystemTime {
+        fn is_zero(&self) -> bool {
+            isolate_::get_is_zero_SystemTime_(self)
+        }
+    }
 }
 
 
@@ -477,14 +707,208 @@ mod tests {
     }
 
 
-    #[cfg(feature = "implement-IsZero-for-standard_process_types")]
+    #[cfg(all(feature = "implement-IsZero-for-built_ins", feature = "implement-IsZero-for-standard_cell_types"))]
+    mod TEST_CELL_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::cell::Cell;
+
+
+        #[test]
+        fn TEST_Cell() {
+            let c = Cell::new(0u32);
+
+            assert!(c.is_zero());
+
+            c.set(1);
+
+            assert!(!c.is_zero());
+            assert!(!as_IsZero(&c).is_zero());
+
+            c.set(0);
+
+            assert!(as_IsZero(&c).is_zero());
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsZero-for-standard_net_types")]
+    mod TEST_NET_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::net::{
+            IpAddr,
+            Ipv4Addr,
+            Ipv6Addr,
+            SocketAddr,
+            SocketAddrV4,
+            SocketAddrV6,
+        };
+
+
+        #[test]
+        fn TEST_Ipv4Addr() {
+            assert!(Ipv4Addr::UNSPECIFIED.is_zero());
+            assert!(!Ipv4Addr::LOCALHOST.is_zero());
+            assert!(!Ipv4Addr::new(0, 0, 0, 1).is_zero());
+        }
+
+        #[test]
+        fn TEST_Ipv6Addr() {
+            assert!(Ipv6Addr::UNSPECIFIED.is_zero());
+            assert!(!Ipv6Addr::LOCALHOST.is_zero());
+        }
+
+        #[test]
+        fn TEST_IpAddr() {
+            assert!(IpAddr::from(Ipv4Addr::UNSPECIFIED).is_zero());
+            assert!(IpAddr::from(Ipv6Addr::UNSPECIFIED).is_zero());
+            assert!(!IpAddr::from(Ipv4Addr::LOCALHOST).is_zero());
+
+            let addr : IpAddr = "::".parse().unwrap();
+
+            assert!(as_IsZero(&addr).is_zero());
+        }
+
+        #[test]
+        fn TEST_SocketAddrV4() {
+            assert!(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0).is_zero());
+            assert!(!SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 80).is_zero());
+            assert!(!SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0).is_zero());
+        }
+
+        #[test]
+        fn TEST_SocketAddrV6() {
+            assert!(SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0).is_zero());
+            assert!(SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 1, 2).is_zero());
+            assert!(!SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 443, 0, 0).is_zero());
+            assert!(!SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0).is_zero());
+        }
+
+        #[test]
+        fn TEST_SocketAddr() {
+            let addr : SocketAddr = "0.0.0.0:0".parse().unwrap();
+
+            assert!(addr.is_zero());
+
+            let addr : SocketAddr = "[::]:0".parse().unwrap();
+
+            assert!(addr.is_zero());
+
+            let addr : SocketAddr = "0.0.0.0:8080".parse().unwrap();
+
+            assert!(!as_IsZero(&addr).is_zero());
+        }
+    }
+
+
+    #[cfg(all(feature = "implement-IsZero-for-built_ins", feature = "implement-IsZero-for-standard_num_types"))]
+    mod TEST_STANDARD_NUM_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::num as std_num;
+
+
+        #[test]
+        fn TEST_NonZero() {
+            assert!(!std_num::NonZeroI8::new(1).unwrap().is_zero());
+            assert!(!std_num::NonZeroI8::new(-1).unwrap().is_zero());
+            assert!(!std_num::NonZeroI16::MIN.is_zero());
+            assert!(!std_num::NonZeroI32::MAX.is_zero());
+            assert!(!std_num::NonZeroI64::MIN.is_zero());
+            assert!(!std_num::NonZeroI128::MAX.is_zero());
+            assert!(!std_num::NonZeroIsize::MIN.is_zero());
+            assert!(!std_num::NonZeroU8::MIN.is_zero());
+            assert!(!std_num::NonZeroU16::MAX.is_zero());
+            assert!(!std_num::NonZeroU32::new(2).unwrap().is_zero());
+            assert!(!std_num::NonZeroU64::MIN.is_zero());
+            assert!(!std_num::NonZeroU128::MAX.is_zero());
+            assert!(!std_num::NonZeroUsize::MIN.is_zero());
+
+            let nz = std_num::NonZeroU32::MIN;
+
+            assert!(!as_IsZero(&nz).is_zero());
+        }
+
+        #[test]
+        fn TEST_Saturating() {
+            assert!(std_num::Saturating(0i16).is_zero());
+            assert!(!std_num::Saturating(1i16).is_zero());
+            assert!(!(std_num::Saturating(u8::MAX) + std_num::Saturating(1)).is_zero());
+            assert!((std_num::Saturating(0u8) - std_num::Saturating(1)).is_zero());
+        }
+
+        #[test]
+        fn TEST_Wrapping() {
+            assert!(std_num::Wrapping(0u8).is_zero());
+            assert!(!std_num::Wrapping(1u8).is_zero());
+            assert!((std_num::Wrapping(u8::MAX) + std_num::Wrapping(1)).is_zero());
+            assert!(std_num::Wrapping(0.0f64).is_zero());
+        }
+    }
+
+
+    #[cfg(all(not(feature = "nostd"), feature = "implement-IsZero-for-standard_process_types"))]
     mod TEST_PROCESS_TYPES {
         #![allow(non_snake_case)]
 
     }
 
 
-    #[cfg(feature = "implement-IsZero-for-standard_time_types")]
+    #[cfg(feature = "implement-IsZero-for-standard_sync_types")]
+    mod TEST_SYNC_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::sync::atomic::{
+            AtomicI16,
+            AtomicI32,
+            AtomicU64,
+            AtomicUsize,
+            Ordering,
+        };
+
+
+        #[test]
+        fn TEST_AtomicU64() {
+            let counter = AtomicU64::new(0);
+
+            assert!(counter.is_zero());
+
+            counter.fetch_add(1, Ordering::Relaxed);
+
+            assert!(!counter.is_zero());
+            assert!(!as_IsZero(&counter).is_zero());
+        }
+
+        #[test]
+        fn TEST_AtomicUsize() {
+            let counter = AtomicUsize::new(usize::MAX);
+
+            assert!(!counter.is_zero());
+
+            counter.fetch_add(1, Ordering::Relaxed);
+
+            assert!(counter.is_zero());
+        }
+
+        #[test]
+        fn TEST_SIGNED() {
+            assert!(AtomicI16::new(0).is_zero());
+            assert!(!AtomicI16::new(-1).is_zero());
+            assert!(!AtomicI32::new(i32::MIN).is_zero());
+        }
+    }
+
+
+    #[cfg(all(not(feature = "nostd"), feature = "implement-IsZero-for-standard_time_types"))]
     mod TEST_TIME_TYPES {
         #![allow(non_snake_case)]
 
@@ -492,6 +916,8 @@ mod tests {
 
         use std::time::{
             Duration,
+            SystemTime,
+            UNIX_EPOCH,
         };
 
 
@@ -523,6 +949,38 @@ mod tests {
                 assert!(!ie.is_zero());
             }
         }
+
+
+        mod TEST_SystemTime {
+            #![allow(non_snake_case)]
+
+            use super::*;
+
+
+            #[test]
+            fn TEST_ZERO() {
+                let st = UNIX_EPOCH;
+
+                assert!(st.is_zero());
+
+                let ie = as_IsZero(&st);
+
+                assert!(ie.is_zero());
+            }
+
+            #[test]
+            fn TEST_NONZERO() {
+                let st = UNIX_EPOCH + Duration::from_nanos(1);
+
+                assert!(!st.is_zero());
+
+                let st : SystemTime = UNIX_EPOCH - Duration::from_secs(1);
+
+                let ie = as_IsZero(&st);
+
+                assert!(!ie.is_zero());
+            }
+        }
     }
 }
 
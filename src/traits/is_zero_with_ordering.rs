@@ -0,0 +1,205 @@
+// src/traits/is_zero_with_ordering.rs : `IsZeroWithOrdering`
+
+use core::sync::atomic as core_atomic;
+
+
+/// Trait defining instance method
+/// `is_zero_with_ordering(order : Ordering) : bool` that indicates whether
+/// the implementing type instance is numerically zero, where the value is
+/// obtained with the given memory ordering.
+///
+/// This is the companion of [`crate::IsZero`] for atomic types, whose
+/// implementations of `IsZero` use [`core_atomic::Ordering::Relaxed`].
+///
+/// # Panics
+///
+/// As for [`core_atomic::AtomicUsize::load()`], implementations for the
+/// atomic types panic if `order` is `Release` or `AcqRel`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Standard Synchronisation Types
+///
+/// If the feature `"implement-IsZeroWithOrdering-for-standard_sync_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types (where supported by the target):
+/// - [`core::sync::atomic::AtomicI8`], ..., [`core::sync::atomic::AtomicI64`],
+///   [`core::sync::atomic::AtomicIsize`];
+/// - [`core::sync::atomic::AtomicU8`], ..., [`core::sync::atomic::AtomicU64`],
+///   [`core::sync::atomic::AtomicUsize`];
+pub trait IsZeroWithOrdering {
+    fn is_zero_with_ordering(
+        &self,
+        order : core_atomic::Ordering,
+    ) -> bool;
+}
+
+
+implement_forwarding_!(IsZeroWithOrdering {
+    fn is_zero_with_ordering(&self, order : core_atomic::Ordering) -> bool;
+});
+
+
+#[cfg(feature = "implement-IsZeroWithOrdering-for-standard_sync_types")]
+mod impl_for_std_sync_types {
+    #![allow(non_snake_case)]
+
+    use super::IsZeroWithOrdering;
+
+    use core::sync::atomic as core_atomic;
+
+
+    // Atomic*
+
+    macro_rules! implement_IsZeroWithOrdering_for_Atomic_ {
+        ($type:ident) => {
+            impl IsZeroWithOrdering for core_atomic::$type {
+                #[inline]
+                fn is_zero_with_ordering(
+                    &self,
+                    order : core_atomic::Ordering,
+                ) -> bool {
+                    0 == self.load(order)
+                }
+            }
+        };
+    }
+
+    #[cfg(target_has_atomic = "8")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicI8);
+    #[cfg(target_has_atomic = "8")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicU8);
+
+    #[cfg(target_has_atomic = "16")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicI16);
+    #[cfg(target_has_atomic = "16")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicU16);
+
+    #[cfg(target_has_atomic = "32")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicI32);
+    #[cfg(target_has_atomic = "32")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicU32);
+
+    #[cfg(target_has_atomic = "64")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicI64);
+    #[cfg(target_has_atomic = "64")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicU64);
+
+    #[cfg(target_has_atomic = "ptr")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicIsize);
+    #[cfg(target_has_atomic = "ptr")]
+    implement_IsZeroWithOrdering_for_Atomic_!(AtomicUsize);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::IsZeroWithOrdering;
+
+    use core::sync::atomic::Ordering;
+
+
+    #[allow(unused)]
+    fn as_IsZeroWithOrdering<T : IsZeroWithOrdering>(t : &T) -> &impl IsZeroWithOrdering {
+        t
+    }
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::rc::Rc;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            value : i32,
+        }
+
+        impl IsZeroWithOrdering for CustomType {
+            fn is_zero_with_ordering(
+                &self,
+                _order : Ordering,
+            ) -> bool {
+                0 == self.value
+            }
+        }
+
+
+        #[test]
+        fn TEST_is_zero_with_ordering() {
+            let ct = CustomType { value : 0 };
+
+            assert!(ct.is_zero_with_ordering(Ordering::SeqCst));
+
+            let ct = Rc::new(CustomType { value : 1 });
+
+            assert!(!as_IsZeroWithOrdering(&ct).is_zero_with_ordering(Ordering::SeqCst));
+        }
+    }
+
+
+    #[cfg(feature = "implement-IsZeroWithOrdering-for-standard_sync_types")]
+    mod TEST_SYNC_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::sync::{
+            atomic::{
+                AtomicI8,
+                AtomicU64,
+                AtomicUsize,
+            },
+            Arc,
+        };
+
+
+        #[test]
+        fn TEST_AtomicU64() {
+            let counter = AtomicU64::new(0);
+
+            assert!(counter.is_zero_with_ordering(Ordering::Acquire));
+
+            counter.fetch_add(1, Ordering::Release);
+
+            assert!(!counter.is_zero_with_ordering(Ordering::Acquire));
+            assert!(!counter.is_zero_with_ordering(Ordering::SeqCst));
+        }
+
+        #[test]
+        fn TEST_AtomicI8() {
+            let counter = AtomicI8::new(-1);
+
+            assert!(!as_IsZeroWithOrdering(&counter).is_zero_with_ordering(Ordering::Relaxed));
+
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            assert!(as_IsZeroWithOrdering(&counter).is_zero_with_ordering(Ordering::Relaxed));
+        }
+
+        #[test]
+        fn TEST_AtomicUsize_IN_Arc() {
+            let counter = Arc::new(AtomicUsize::new(0));
+
+            assert!(counter.is_zero_with_ordering(Ordering::Acquire));
+
+            let other = Arc::clone(&counter);
+
+            std::thread::spawn(move || {
+                other.fetch_add(3, Ordering::Release);
+            })
+            .join()
+            .unwrap();
+
+            assert!(!counter.is_zero_with_ordering(Ordering::Acquire));
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
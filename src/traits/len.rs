@@ -19,23 +19,49 @@
 /// If the feature `"implement-Len-for-standard_collection_types"`
 /// is defined (as it is by `"default"`), then this is also implemented
 /// for the following types:
-/// - [`std::collections::BTreeMap`];
-/// - [`std::collections::BTreeSet`];
-/// - [`std::collections::BinaryHeap`];
+/// - [`alloc::collections::BTreeMap`];
+/// - [`alloc::collections::BTreeSet`];
+/// - [`alloc::collections::BinaryHeap`];
 /// - [`std::collections::HashMap`];
 /// - [`std::collections::HashSet`];
-/// - [`std::collections::LinkedList`];
+/// - [`alloc::collections::LinkedList`];
 /// - [`String`];
 /// - [`Vec`];
-/// - [`std::collections::VecDeque`];
+/// - [`alloc::collections::VecDeque`];
+///
+/// NOTE: when the feature `"nostd"` is defined, these implementations are
+/// retained only if the feature `"alloc"` is also defined, in which case
+/// those for [`std::collections::HashMap`] and [`std::collections::HashSet`]
+/// are omitted.
 ///
 /// ## Standard FFI Types
 ///
 /// If the feature `"implement-Len-for-standard_ffi_types"`
 /// is defined (as it is by `"default"`), then this is also implemented
 /// for the following types:
-/// - [`std::ffi::CStr`];
-/// - [`std::ffi::CString`];
+/// - [`core::ffi::CStr`];
+/// - [`alloc::ffi::CString`];
+/// - [`std::ffi::OsStr`];
+/// - [`std::ffi::OsString`];
+///
+/// ## Standard Iterator Types
+///
+/// If the feature `"implement-Len-for-standard_iter_types"`
+/// is defined (which is NOT by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::iter::Empty`];
+/// - [`core::iter::Once`];
+///
+/// ## Standard Option Types
+///
+/// If the feature `"implement-Len-for-standard_option_types"`
+/// is defined (which is NOT by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`Option`];
+///
+/// An instance of `Option<T>` has length 0 if it is `None`, and otherwise
+/// has the length of its value. To treat instead any `Some` as having
+/// length 1, use [`crate::Presence`].
 ///
 /// ## Standard Path Types
 ///
@@ -48,29 +74,46 @@
 /// ## Standard Process Types
 ///
 /// If the feature `"implement-Len-for-standard_process_types"`
-/// is defined (as it is by `"default"`), then this is also implemented
+/// is defined (which is NOT by `"default"`), then this is also implemented
 /// for the following types:
 /// - [`std::process::CommandArgs`];
 /// - [`std::process::CommandEnvs`];
+///
+/// ## Standard Range Types
+///
+/// If the feature `"implement-Len-for-standard_range_types"`
+/// is defined (which is NOT by `"default"`), then this is also implemented
+/// for the following types, where `Idx` is any built-in integer type:
+/// - [`core::ops::Range<Idx>`];
+/// - [`core::ops::RangeInclusive<Idx>`];
+///
+/// The length is the number of values in the range, as would be obtained
+/// by `ExactSizeIterator#len()` were it implemented for `Idx`, except that,
+/// where that number exceeds `usize::MAX` (as it may for ranges of `i64`,
+/// `u64`, `i128`, `u128`, or for `RangeInclusive<usize>` spanning the whole
+/// of `usize`), it saturates to `usize::MAX`.
+///
+/// NOTE: since these ranges are also iterators, and those of most integer
+/// types implement [`ExactSizeIterator`], a call with method syntax such as
+/// `(0..10).len()` is ambiguous (error E0034) in any code that imports this
+/// trait; it must instead be called as `Len::len(&(0..10))`, or via a
+/// generic bound. This is why the feature is not defined by `"default"`.
+///
+/// ## Standard Result Types
+///
+/// If the feature `"implement-Len-for-standard_result_types"`
+/// is defined (which is NOT by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`Result`];
 #[allow(clippy::len_without_is_empty)]
 pub trait Len {
     fn len(&self) -> usize;
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : Len + ?Sized> Len for Box<T> {
-    fn len(&self) -> usize {
-        (**self).len()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : Len + ?Sized> Len for std::rc::Rc<T> {
-    fn len(&self) -> usize {
-        (**self).len()
-    }
-}
+implement_forwarding_!(Len {
+    fn len(&self) -> usize;
+});
 
 
 #[cfg(feature = "implement-Len-for-built_ins")]
@@ -136,44 +179,54 @@ mod impl_for_built_ins {
 }
 
 
-#[cfg(all(not(feature = "nostd"), feature = "implement-Len-for-standard_collection_types"))]
+#[cfg(all(any(feature = "alloc", not(feature = "nostd")), feature = "implement-Len-for-standard_collection_types"))]
 mod impl_for_std_coll_types {
+    use alloc::{
+        collections as alloc_collections,
+        string::String,
+        vec::Vec,
+    };
+    #[cfg(not(feature = "nostd"))]
     use std::collections as std_collections;
 
 
     mod isolate_ {
         #![allow(non_snake_case)]
 
+        use alloc::collections as alloc_collections;
+        #[cfg(not(feature = "nostd"))]
         use std::collections as std_collections;
 
 
         #[inline]
-        pub(super) fn get_len_BTreeMap_<K, V>(coll : &std_collections::BTreeMap<K, V>) -> usize {
+        pub(super) fn get_len_BTreeMap_<K, V>(coll : &alloc_collections::BTreeMap<K, V>) -> usize {
             coll.len()
         }
 
         #[inline]
-        pub(super) fn get_len_BTreeSet_<T>(coll : &std_collections::BTreeSet<T>) -> usize {
+        pub(super) fn get_len_BTreeSet_<T>(coll : &alloc_collections::BTreeSet<T>) -> usize {
             coll.len()
         }
 
         #[inline]
-        pub(super) fn get_len_BinaryHeap_<T>(coll : &std_collections::BinaryHeap<T>) -> usize {
+        pub(super) fn get_len_BinaryHeap_<T>(coll : &alloc_collections::BinaryHeap<T>) -> usize {
             coll.len()
         }
 
+        #[cfg(not(feature = "nostd"))]
         #[inline]
         pub(super) fn get_len_HashMap_<K, V>(coll : &std_collections::HashMap<K, V>) -> usize {
             coll.len()
         }
 
+        #[cfg(not(feature = "nostd"))]
         #[inline]
         pub(super) fn get_len_HashSet_<T>(coll : &std_collections::HashSet<T>) -> usize {
             coll.len()
         }
 
         #[inline]
-        pub(super) fn get_len_LinkedList_<T>(coll : &std_collections::LinkedList<T>) -> usize {
+        pub(super) fn get_len_LinkedList_<T>(coll : &alloc_collections::LinkedList<T>) -> usize {
             coll.len()
         }
 
@@ -189,7 +242,7 @@ mod impl_for_std_coll_types {
         }
 
         #[inline]
-        pub(super) fn get_len_VecDeque_<T>(coll : &std_collections::VecDeque<T>) -> usize {
+        pub(super) fn get_len_VecDeque_<T>(coll : &alloc_collections::VecDeque<T>) -> usize {
             coll.len()
         }
     }
@@ -197,7 +250,7 @@ mod impl_for_std_coll_types {
 
     // BTreeMap<>
 
-    impl<K, V> super::Len for std_collections::BTreeMap<K, V> {
+    impl<K, V> super::Len for alloc_collections::BTreeMap<K, V> {
         fn len(&self) -> usize {
             isolate_::get_len_BTreeMap_(self)
         }
@@ -205,7 +258,7 @@ mod impl_for_std_coll_types {
 
     // BTreeSet<>
 
-    impl<T> super::Len for std_collections::BTreeSet<T> {
+    impl<T> super::Len for alloc_collections::BTreeSet<T> {
         fn len(&self) -> usize {
             isolate_::get_len_BTreeSet_(self)
         }
@@ -213,7 +266,7 @@ mod impl_for_std_coll_types {
 
     // BinaryHeap<>
 
-    impl<T> super::Len for std_collections::BinaryHeap<T> {
+    impl<T> super::Len for alloc_collections::BinaryHeap<T> {
         fn len(&self) -> usize {
             isolate_::get_len_BinaryHeap_(self)
         }
@@ -221,6 +274,7 @@ mod impl_for_std_coll_types {
 
     // HashMap<>
 
+    #[cfg(not(feature = "nostd"))]
     impl<K, V> super::Len for std_collections::HashMap<K, V> {
         fn len(&self) -> usize {
             isolate_::get_len_HashMap_(self)
@@ -229,6 +283,7 @@ mod impl_for_std_coll_types {
 
     // HashSet<>
 
+    #[cfg(not(feature = "nostd"))]
     impl<T> super::Len for std_collections::HashSet<T> {
         fn len(&self) -> usize {
             isolate_::get_len_HashSet_(self)
@@ -237,7 +292,7 @@ mod impl_for_std_coll_types {
 
     // LinkedList<>
 
-    impl<T> super::Len for std_collections::LinkedList<T> {
+    impl<T> super::Len for alloc_collections::LinkedList<T> {
         fn len(&self) -> usize {
             isolate_::get_len_LinkedList_(self)
         }
@@ -261,7 +316,7 @@ mod impl_for_std_coll_types {
 
     // VecDeque<>
 
-    impl<T> super::Len for std_collections::VecDeque<T> {
+    impl<T> super::Len for alloc_collections::VecDeque<T> {
         fn len(&self) -> usize {
             isolate_::get_len_VecDeque_(self)
         }
@@ -269,40 +324,55 @@ mod impl_for_std_coll_types {
 }
 
 
-#[cfg(all(not(feature = "nostd"), feature = "implement-Len-for-standard_ffi_types"))]
+#[cfg(feature = "implement-Len-for-standard_ffi_types")]
 mod impl_for_std_ffi_types {
     #![allow(non_snake_case)]
 
+    use core::ffi as core_ffi;
+    #[cfg(any(feature = "alloc", not(feature = "nostd")))]
+    use alloc::ffi as alloc_ffi;
+    #[cfg(not(feature = "nostd"))]
     use std::ffi as std_ffi;
 
 
     mod isolate_ {
         #![allow(non_snake_case)]
 
+        use core::ffi as core_ffi;
+        #[cfg(any(feature = "alloc", not(feature = "nostd")))]
+        use alloc::ffi as alloc_ffi;
+        #[cfg(not(feature = "nostd"))]
         use std::ffi as std_ffi;
 
 
         #[inline]
-        pub(super) fn get_len_CStr_(cstr : &std_ffi::CStr) -> usize {
+        pub(super) fn get_len_CStr_(cstr : &core_ffi::CStr) -> usize {
             cstr.count_bytes()
         }
 
+        #[cfg(any(feature = "alloc", not(feature = "nostd")))]
         #[inline]
-        pub(super) fn get_len_CString_(cstring : &std_ffi::CString) -> usize {
+        pub(super) fn get_len_CString_(cstring : &alloc_ffi::CString) -> usize {
             cstring.count_bytes()
         }
+
+        #[cfg(not(feature = "nostd"))]
+        #[inline]
+        pub(super) fn get_len_OsStr_(os_str : &std_ffi::OsStr) -> usize {
+            os_str.len()
+        }
     }
 
 
     // CStr
 
-    impl super::Len for std_ffi::CStr {
+    impl super::Len for core_ffi::CStr {
         fn len(&self) -> usize {
             isolate_::get_len_CStr_(self)
         }
     }
 
-    impl super::Len for &std_ffi::CStr {
+    impl super::Len for &core_ffi::CStr {
         fn len(&self) -> usize {
             isolate_::get_len_CStr_(self)
         }
@@ -310,11 +380,79 @@ mod impl_for_std_ffi_types {
 
     // CString
 
-    impl super::Len for std_ffi::CString {
+    #[cfg(any(feature = "alloc", not(feature = "nostd")))]
+    impl super::Len for alloc_ffi::CString {
         fn len(&self) -> usize {
             isolate_::get_len_CString_(self)
         }
     }
+
+    // OsStr
+
+    #[cfg(not(feature = "nostd"))]
+    impl super::Len for std_ffi::OsStr {
+        fn len(&self) -> usize {
+            isolate_::get_len_OsStr_(self)
+        }
+    }
+
+    #[cfg(not(feature = "nostd"))]
+    impl super::Len for &std_ffi::OsStr {
+        fn len(&self) -> usize {
+            isolate_::get_len_OsStr_(self)
+        }
+    }
+
+    // OsString
+
+    #[cfg(not(feature = "nostd"))]
+    impl super::Len for std_ffi::OsString {
+        fn len(&self) -> usize {
+            isolate_::get_len_OsStr_(self)
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-Len-for-standard_iter_types")]
+mod impl_for_std_iter_types {
+    use core::iter as core_iter;
+
+
+    // Empty<>
+
+    impl<T> super::Len for core_iter::Empty<T> {
+        #[inline]
+        fn len(&self) -> usize {
+            0
+        }
+    }
+
+    // Once<>
+
+    impl<T> super::Len for core_iter::Once<T> {
+        #[inline]
+        fn len(&self) -> usize {
+            ExactSizeIterator::len(self)
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-Len-for-standard_option_types")]
+mod impl_for_std_option_types {
+
+    // Option<>
+
+    impl<T : super::Len> super::Len for Option<T> {
+        #[inline]
+        fn len(&self) -> usize {
+            match self {
+                Some(t) => t.len(),
+                None => 0,
+            }
+        }
+    }
 }
 
 
@@ -390,6 +528,79 @@ mod impl_for_std_process_types {
 }
 
 
+#[cfg(feature = "implement-Len-for-standard_range_types")]
+mod impl_for_std_range_types {
+    #![allow(non_snake_case)]
+
+    use core::ops as core_ops;
+
+
+    macro_rules! implement_Len_for_ranges_ {
+        ($type:tt) => {
+            // Range<>
+
+            impl super::Len for core_ops::Range<$type> {
+                #[inline]
+                fn len(&self) -> usize {
+                    if self.start < self.end {
+                        usize::try_from(self.end.abs_diff(self.start)).unwrap_or(usize::MAX)
+                    } else {
+                        0
+                    }
+                }
+            }
+
+            // RangeInclusive<>
+
+            impl super::Len for core_ops::RangeInclusive<$type> {
+                #[inline]
+                fn len(&self) -> usize {
+                    if self.is_empty() {
+                        0
+                    } else {
+                        usize::try_from(self.end().abs_diff(*self.start()))
+                            .ok()
+                            .and_then(|n| n.checked_add(1))
+                            .unwrap_or(usize::MAX)
+                    }
+                }
+            }
+        };
+    }
+
+
+    implement_Len_for_ranges_!(i8);
+    implement_Len_for_ranges_!(i16);
+    implement_Len_for_ranges_!(i32);
+    implement_Len_for_ranges_!(i64);
+    implement_Len_for_ranges_!(i128);
+    implement_Len_for_ranges_!(isize);
+    implement_Len_for_ranges_!(u8);
+    implement_Len_for_ranges_!(u16);
+    implement_Len_for_ranges_!(u32);
+    implement_Len_for_ranges_!(u64);
+    implement_Len_for_ranges_!(u128);
+    implement_Len_for_ranges_!(usize);
+}
+
+
+#[cfg(feature = "implement-Len-for-standard_result_types")]
+mod impl_for_std_result_types {
+
+    // Result<>
+
+    impl<T : super::Len, E> super::Len for Result<T, E> {
+        #[inline]
+        fn len(&self) -> usize {
+            match self {
+                Ok(t) => t.len(),
+                Err(_) => 0,
+            }
+        }
+    }
+}
+
+
 #[cfg(test)]
 mod tests {
     #![allow(non_snake_case)]
@@ -556,11 +767,14 @@ mod tests {
             BTreeMap,
             BTreeSet,
             BinaryHeap,
-            HashMap,
-            HashSet,
             LinkedList,
             VecDeque,
         };
+        #[cfg(not(feature = "nostd"))]
+        use std::collections::{
+            HashMap,
+            HashSet,
+        };
 
 
         mod TEST_BTreeMapTU {
@@ -653,6 +867,7 @@ mod tests {
         }
 
 
+        #[cfg(not(feature = "nostd"))]
         mod TEST_HashMapTU {
             #![allow(non_snake_case)]
 
@@ -683,6 +898,7 @@ mod tests {
         }
 
 
+        #[cfg(not(feature = "nostd"))]
         mod TEST_HashSetT {
             #![allow(non_snake_case)]
 
@@ -904,6 +1120,11 @@ mod tests {
             CStr,
             CString,
         };
+        #[cfg(not(feature = "nostd"))]
+        use std::ffi::{
+            OsStr,
+            OsString,
+        };
 
 
         mod TEST_CStr {
@@ -964,10 +1185,132 @@ mod tests {
                 assert_ne!(0, ie.len());
             }
         }
+
+
+        #[cfg(not(feature = "nostd"))]
+        mod TEST_OsStr {
+            #![allow(non_snake_case)]
+
+            use super::*;
+
+
+            #[test]
+            fn TEST_EMPTY() {
+                let s : &OsStr = OsStr::new("");
+
+                let ie = as_Len(&s);
+
+                assert_eq!(0, ie.len());
+            }
+
+            #[test]
+            fn TEST_NONEMPTY() {
+                let s : &OsStr = OsStr::new("abc");
+
+                let ie = as_Len(&s);
+
+                assert_eq!(3, ie.len());
+            }
+        }
+
+
+        #[cfg(not(feature = "nostd"))]
+        mod TEST_OsString {
+            #![allow(non_snake_case)]
+
+            use super::*;
+
+
+            #[test]
+            fn TEST_EMPTY() {
+                let s : OsString = OsString::from("");
+
+                let ie = as_Len(&s);
+
+                assert_eq!(0, ie.len());
+            }
+
+            #[test]
+            fn TEST_NONEMPTY() {
+                let s : OsString = OsString::from("abc");
+
+                let ie = as_Len(&s);
+
+                assert_eq!(3, ie.len());
+            }
+        }
     }
 
 
-    #[cfg(feature = "implement-Len-for-standard_path_types")]
+    #[cfg(feature = "implement-Len-for-standard_iter_types")]
+    mod TEST_ITER_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::iter as std_iter;
+
+
+        #[test]
+        fn TEST_Empty() {
+            let i = std_iter::empty::<i32>();
+
+            assert_eq!(0, Len::len(&i));
+
+            let ie = as_Len(&i);
+
+            assert_eq!(0, ie.len());
+        }
+
+        #[test]
+        fn TEST_Once() {
+            let mut i = std_iter::once(1);
+
+            assert_eq!(1, Len::len(&i));
+
+            let _ = i.next();
+
+            assert_eq!(0, Len::len(&i));
+        }
+    }
+
+
+    #[cfg(feature = "implement-Len-for-standard_option_types")]
+    mod TEST_OPTION_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_None() {
+            let o : Option<&str> = None;
+
+            assert_eq!(0, o.len());
+
+            let ie = as_Len(&o);
+
+            assert_eq!(0, ie.len());
+        }
+
+        #[test]
+        fn TEST_Some() {
+            let o = Some("");
+
+            assert_eq!(0, o.len());
+
+            let o = Some("abc");
+
+            assert_eq!(3, o.len());
+
+            let ie = as_Len(&o);
+
+            assert_eq!(3, ie.len());
+        }
+    }
+
+
+    #[cfg(all(not(feature = "nostd"), feature = "implement-Len-for-standard_path_types"))]
     mod TEST_PATH_TYPES {
         #![allow(non_snake_case)]
 
@@ -1042,10 +1385,191 @@ mod tests {
     }
 
 
-    #[cfg(feature = "implement-Len-for-standard_process_types")]
+    #[cfg(all(not(feature = "nostd"), feature = "implement-Len-for-standard_process_types"))]
     mod TEST_PROCESS_TYPES {
         #![allow(non_snake_case)]
 
+        use super::*;
+
+        use std::process::Command;
+
+
+        #[test]
+        fn TEST_CommandArgs() {
+            let mut command = Command::new("ls");
+
+            {
+                let args = command.get_args();
+
+                assert_eq!(0, as_Len(&&args).len());
+            }
+
+            command.arg("-l").arg("-a");
+
+            {
+                let args = command.get_args();
+
+                assert_eq!(2, as_Len(&&args).len());
+            }
+        }
+
+        #[test]
+        fn TEST_CommandEnvs() {
+            let mut command = Command::new("ls");
+
+            {
+                let envs = command.get_envs();
+
+                assert_eq!(0, as_Len(&&envs).len());
+            }
+
+            command.env("ABC", "abc");
+
+            {
+                let envs = command.get_envs();
+
+                assert_eq!(1, as_Len(&&envs).len());
+            }
+        }
+    }
+
+
+    #[cfg(feature = "implement-Len-for-standard_range_types")]
+    mod TEST_RANGE_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        mod TEST_Range {
+            #![allow(non_snake_case)]
+
+            use super::*;
+
+
+            #[test]
+            #[allow(clippy::reversed_empty_ranges)]
+            fn TEST_EMPTY() {
+                assert_eq!(0, Len::len(&(0..0)));
+                assert_eq!(0, Len::len(&(5u64..5)));
+                assert_eq!(0, Len::len(&(5i128..-5)));
+
+                let r = 3..3;
+
+                assert_eq!(0, as_Len(&r).len());
+            }
+
+            #[test]
+            fn TEST_NONEMPTY() {
+                assert_eq!(10, Len::len(&(0..10)));
+                assert_eq!(10, Len::len(&(-5i8..5)));
+                assert_eq!(10, Len::len(&(5u64..15)));
+                assert_eq!(255, Len::len(&(0u8..255)));
+
+                let r = -3i64..3;
+
+                assert_eq!(6, as_Len(&r).len());
+            }
+
+            #[test]
+            #[allow(clippy::reversed_empty_ranges)]
+            fn TEST_MATCHES_ExactSizeIterator() {
+                for r in [ 0u32..0, 0..1, 7..3, 3..70000 ] {
+                    assert_eq!(ExactSizeIterator::len(&r), Len::len(&r));
+                }
+
+                for r in [ i32::MIN..i32::MAX, -1..1, 1..-1 ] {
+                    assert_eq!(ExactSizeIterator::len(&r), Len::len(&r));
+                }
+            }
+
+            #[test]
+            fn TEST_SATURATES() {
+                assert_eq!(usize::MAX, Len::len(&(0..u128::MAX)));
+                assert_eq!(usize::MAX, Len::len(&(i128::MIN..i128::MAX)));
+            }
+        }
+
+
+        mod TEST_RangeInclusive {
+            #![allow(non_snake_case)]
+
+            use super::*;
+
+
+            #[test]
+            #[allow(clippy::reversed_empty_ranges)]
+            fn TEST_EMPTY() {
+                assert_eq!(0, Len::len(&(1..=0)));
+                assert_eq!(0, Len::len(&(5i64..=-5)));
+
+                let mut r = 3u8..=3;
+
+                r.next();
+
+                assert_eq!(0, as_Len(&r).len());
+            }
+
+            #[test]
+            fn TEST_NONEMPTY() {
+                assert_eq!(1, Len::len(&(0..=0)));
+                assert_eq!(11, Len::len(&(-5i32..=5)));
+                assert_eq!(256, Len::len(&(0u8..=255)));
+                assert_eq!(65536, Len::len(&(i16::MIN..=i16::MAX)));
+
+                let mut r = 1u64..=10;
+
+                r.next();
+
+                assert_eq!(9, as_Len(&r).len());
+            }
+
+            #[test]
+            #[allow(clippy::reversed_empty_ranges)]
+            fn TEST_MATCHES_ExactSizeIterator() {
+                for r in [ 0u16..=0, 0..=1, 7..=3, 3..=u16::MAX ] {
+                    assert_eq!(ExactSizeIterator::len(&r), Len::len(&r));
+                }
+            }
+
+            #[test]
+            fn TEST_SATURATES() {
+                assert_eq!(usize::MAX, Len::len(&(0..=usize::MAX)));
+                assert_eq!(usize::MAX, Len::len(&(0..=u128::MAX)));
+                assert_eq!(usize::MAX, Len::len(&(i128::MIN..=i128::MAX)));
+            }
+        }
+    }
+
+
+    #[cfg(feature = "implement-Len-for-standard_result_types")]
+    mod TEST_RESULT_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_Err() {
+            let r : Result<&str, i32> = Err(-1);
+
+            assert_eq!(0, r.len());
+
+            let ie = as_Len(&r);
+
+            assert_eq!(0, ie.len());
+        }
+
+        #[test]
+        fn TEST_Ok() {
+            let r : Result<&str, i32> = Ok("");
+
+            assert_eq!(0, r.len());
+
+            let r : Result<&str, i32> = Ok("abc");
+
+            assert_eq!(3, r.len());
+        }
     }
 }
 
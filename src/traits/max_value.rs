@@ -0,0 +1,211 @@
+// src/traits/max_value.rs : `MaxValue`
+
+/// Trait defining class method `max_value() : T` that creates an instance of
+/// the implementing type that has the maximum value of that type.
+///
+/// For the floating-point types, this is the largest finite value, i.e. [`f64::MAX`],
+/// rather than infinity (for which see [`crate::Infinity`]).
+///
+/// NOTE: the built-in integer types each have an inherent (and
+/// deprecated) function `max_value()`, which takes precedence when
+/// called via the type, as in `i32::max_value()`; this trait's function
+/// is obtained via a bound, as in `T::max_value()` for
+/// `T : MaxValue`, or via the trait, as in `<i32 as MaxValue>::max_value()`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-MaxValue-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`];
+/// - [`i16`];
+/// - [`i32`];
+/// - [`i64`];
+/// - [`i128`];
+/// - [`u8`];
+/// - [`u16`];
+/// - [`u32`];
+/// - [`u64`];
+/// - [`u128`];
+/// - [`isize`];
+/// - [`usize`];
+/// - [`f32`];
+/// - [`f64`];
+/// - [`char`];
+/// - [`bool`];
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-MaxValue-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::time::Duration`];
+pub trait MaxValue {
+    fn max_value() -> Self;
+}
+
+
+#[cfg(feature = "implement-MaxValue-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_MaxValue_ {
+        ($type:tt) => {
+            impl super::MaxValue for $type {
+                #[inline]
+                fn max_value() -> Self {
+                    $type::MAX
+                }
+            }
+        };
+        ($type:tt, $value:expr) => {
+            impl super::MaxValue for $type {
+                #[inline]
+                fn max_value() -> Self {
+                    $value
+                }
+            }
+        };
+    }
+
+    implement_MaxValue_!(i8);
+    implement_MaxValue_!(i16);
+    implement_MaxValue_!(i32);
+    implement_MaxValue_!(i64);
+    implement_MaxValue_!(i128);
+
+    implement_MaxValue_!(u8);
+    implement_MaxValue_!(u16);
+    implement_MaxValue_!(u32);
+    implement_MaxValue_!(u64);
+    implement_MaxValue_!(u128);
+
+    implement_MaxValue_!(isize);
+    implement_MaxValue_!(usize);
+
+    implement_MaxValue_!(f32);
+    implement_MaxValue_!(f64);
+
+    implement_MaxValue_!(char);
+
+    implement_MaxValue_!(bool, true);
+}
+
+
+#[cfg(feature = "implement-MaxValue-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl super::MaxValue for core_time::Duration {
+        #[inline]
+        fn max_value() -> Self {
+            core_time::Duration::MAX
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::MaxValue;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            pub value : i32,
+        }
+
+        impl MaxValue for CustomType {
+            fn max_value() -> Self {
+                Self {
+                    value : 1000,
+                }
+            }
+        }
+
+
+        #[test]
+        fn TEST_MaxValue() {
+            let ct = CustomType::max_value();
+
+            assert_eq!(1000, ct.value);
+        }
+    }
+
+
+    #[cfg(feature = "implement-MaxValue-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_INTEGERS() {
+            assert_eq!(i8::MAX, <i8 as MaxValue>::max_value());
+            assert_eq!(i16::MAX, <i16 as MaxValue>::max_value());
+            assert_eq!(i32::MAX, <i32 as MaxValue>::max_value());
+            assert_eq!(i64::MAX, <i64 as MaxValue>::max_value());
+            assert_eq!(i128::MAX, <i128 as MaxValue>::max_value());
+            assert_eq!(u8::MAX, <u8 as MaxValue>::max_value());
+            assert_eq!(u16::MAX, <u16 as MaxValue>::max_value());
+            assert_eq!(u32::MAX, <u32 as MaxValue>::max_value());
+            assert_eq!(u64::MAX, <u64 as MaxValue>::max_value());
+            assert_eq!(u128::MAX, <u128 as MaxValue>::max_value());
+            assert_eq!(isize::MAX, <isize as MaxValue>::max_value());
+            assert_eq!(usize::MAX, <usize as MaxValue>::max_value());
+        }
+
+        #[test]
+        fn TEST_FLOATS() {
+            assert_eq!(f32::MAX, f32::max_value());
+            assert_eq!(f64::MAX, f64::max_value());
+
+            assert!(f64::max_value().is_finite());
+        }
+
+        #[test]
+        fn TEST_char() {
+            assert_eq!('\u{10FFFF}', char::max_value());
+        }
+
+        #[test]
+        fn TEST_bool() {
+            assert!(bool::max_value());
+        }
+    }
+
+
+    #[cfg(feature = "implement-MaxValue-for-standard_time_types")]
+    mod TEST_STANDARD_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time as std_time;
+
+
+        #[test]
+        fn TEST_Duration() {
+            assert_eq!(std_time::Duration::MAX, std_time::Duration::max_value());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
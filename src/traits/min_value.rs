@@ -0,0 +1,211 @@
+// src/traits/min_value.rs : `MinValue`
+
+/// Trait defining class method `min_value() : T` that creates an instance of
+/// the implementing type that has the minimum value of that type.
+///
+/// For the floating-point types, this is the most negative finite value, i.e. [`f64::MIN`],
+/// rather than negative infinity (for which see [`crate::Infinity`]).
+///
+/// NOTE: the built-in integer types each have an inherent (and
+/// deprecated) function `min_value()`, which takes precedence when
+/// called via the type, as in `i32::min_value()`; this trait's function
+/// is obtained via a bound, as in `T::min_value()` for
+/// `T : MinValue`, or via the trait, as in `<i32 as MinValue>::min_value()`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-MinValue-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`];
+/// - [`i16`];
+/// - [`i32`];
+/// - [`i64`];
+/// - [`i128`];
+/// - [`u8`];
+/// - [`u16`];
+/// - [`u32`];
+/// - [`u64`];
+/// - [`u128`];
+/// - [`isize`];
+/// - [`usize`];
+/// - [`f32`];
+/// - [`f64`];
+/// - [`char`];
+/// - [`bool`];
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-MinValue-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::time::Duration`];
+pub trait MinValue {
+    fn min_value() -> Self;
+}
+
+
+#[cfg(feature = "implement-MinValue-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_MinValue_ {
+        ($type:tt) => {
+            impl super::MinValue for $type {
+                #[inline]
+                fn min_value() -> Self {
+                    $type::MIN
+                }
+            }
+        };
+        ($type:tt, $value:expr) => {
+            impl super::MinValue for $type {
+                #[inline]
+                fn min_value() -> Self {
+                    $value
+                }
+            }
+        };
+    }
+
+    implement_MinValue_!(i8);
+    implement_MinValue_!(i16);
+    implement_MinValue_!(i32);
+    implement_MinValue_!(i64);
+    implement_MinValue_!(i128);
+
+    implement_MinValue_!(u8);
+    implement_MinValue_!(u16);
+    implement_MinValue_!(u32);
+    implement_MinValue_!(u64);
+    implement_MinValue_!(u128);
+
+    implement_MinValue_!(isize);
+    implement_MinValue_!(usize);
+
+    implement_MinValue_!(f32);
+    implement_MinValue_!(f64);
+
+    implement_MinValue_!(char);
+
+    implement_MinValue_!(bool, false);
+}
+
+
+#[cfg(feature = "implement-MinValue-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl super::MinValue for core_time::Duration {
+        #[inline]
+        fn min_value() -> Self {
+            core_time::Duration::ZERO
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::MinValue;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            pub value : i32,
+        }
+
+        impl MinValue for CustomType {
+            fn min_value() -> Self {
+                Self {
+                    value : -1000,
+                }
+            }
+        }
+
+
+        #[test]
+        fn TEST_MinValue() {
+            let ct = CustomType::min_value();
+
+            assert_eq!(-1000, ct.value);
+        }
+    }
+
+
+    #[cfg(feature = "implement-MinValue-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_INTEGERS() {
+            assert_eq!(i8::MIN, <i8 as MinValue>::min_value());
+            assert_eq!(i16::MIN, <i16 as MinValue>::min_value());
+            assert_eq!(i32::MIN, <i32 as MinValue>::min_value());
+            assert_eq!(i64::MIN, <i64 as MinValue>::min_value());
+            assert_eq!(i128::MIN, <i128 as MinValue>::min_value());
+            assert_eq!(u8::MIN, <u8 as MinValue>::min_value());
+            assert_eq!(u16::MIN, <u16 as MinValue>::min_value());
+            assert_eq!(u32::MIN, <u32 as MinValue>::min_value());
+            assert_eq!(u64::MIN, <u64 as MinValue>::min_value());
+            assert_eq!(u128::MIN, <u128 as MinValue>::min_value());
+            assert_eq!(isize::MIN, <isize as MinValue>::min_value());
+            assert_eq!(usize::MIN, <usize as MinValue>::min_value());
+        }
+
+        #[test]
+        fn TEST_FLOATS() {
+            assert_eq!(f32::MIN, f32::min_value());
+            assert_eq!(f64::MIN, f64::min_value());
+
+            assert!(f64::min_value().is_finite());
+        }
+
+        #[test]
+        fn TEST_char() {
+            assert_eq!('\0', char::min_value());
+        }
+
+        #[test]
+        fn TEST_bool() {
+            assert!(!bool::min_value());
+        }
+    }
+
+
+    #[cfg(feature = "implement-MinValue-for-standard_time_types")]
+    mod TEST_STANDARD_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time as std_time;
+
+
+        #[test]
+        fn TEST_Duration() {
+            assert_eq!(std_time::Duration::ZERO, std_time::Duration::min_value());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -10,45 +10,456 @@ macro_rules! declare_and_publish {
     };
 }
 
+/// Implements the given trait - which must have only (non-mutating)
+/// instance methods - for the standard pointer and wrapper types, by
+/// forwarding each method to the pointee:
+/// - `Box<T>`, `Rc<T>`, `Arc<T>`, and `Cow<'_, T>`, unless `"nostd"` is
+///   specified without `"alloc"` (and, for `Arc<T>`, only on targets that
+///   support atomic pointers);
+/// - `&mut T`, and `Pin<P>`;
+macro_rules! implement_forwarding_ {
+    ($trait_name:ident { $(fn $method_name:ident(&self $(, $arg_name:ident : $arg_type:ty)*) -> $return_type:ty;)+ }) => {
+        #[cfg(any(feature = "alloc", not(feature = "nostd")))]
+        impl<T : $trait_name + ?Sized> $trait_name for alloc::boxed::Box<T> {
+            $(
+                fn $method_name(&self $(, $arg_name : $arg_type)*) -> $return_type {
+                    (**self).$method_name($($arg_name),*)
+                }
+            )+
+        }
+
+        #[cfg(any(feature = "alloc", not(feature = "nostd")))]
+        impl<T : $trait_name + ?Sized> $trait_name for alloc::rc::Rc<T> {
+            $(
+                fn $method_name(&self $(, $arg_name : $arg_type)*) -> $return_type {
+                    (**self).$method_name($($arg_name),*)
+                }
+            )+
+        }
+
+        #[cfg(all(any(feature = "alloc", not(feature = "nostd")), target_has_atomic = "ptr"))]
+        impl<T : $trait_name + ?Sized> $trait_name for alloc::sync::Arc<T> {
+            $(
+                fn $method_name(&self $(, $arg_name : $arg_type)*) -> $return_type {
+                    (**self).$method_name($($arg_name),*)
+                }
+            )+
+        }
+
+        #[cfg(any(feature = "alloc", not(feature = "nostd")))]
+        impl<T : $trait_name + alloc::borrow::ToOwned + ?Sized> $trait_name for alloc::borrow::Cow<'_, T> {
+            $(
+                fn $method_name(&self $(, $arg_name : $arg_type)*) -> $return_type {
+                    (**self).$method_name($($arg_name),*)
+                }
+            )+
+        }
+
+        impl<T : $trait_name + ?Sized> $trait_name for &mut T {
+            $(
+                fn $method_name(&self $(, $arg_name : $arg_type)*) -> $return_type {
+                    (**self).$method_name($($arg_name),*)
+                }
+            )+
+        }
+
+        impl<P> $trait_name for core::pin::Pin<P>
+        where
+            P : core::ops::Deref,
+            P::Target : $trait_name,
+        {
+            $(
+                fn $method_name(&self $(, $arg_name : $arg_type)*) -> $return_type {
+                    (**self).$method_name($($arg_name),*)
+                }
+            )+
+        }
+    };
+}
+
+declare_and_publish!(as_f32, AsF32);
 declare_and_publish!(as_f64, AsF64);
 declare_and_publish!(as_i128, AsI128);
+declare_and_publish!(as_i16, AsI16);
 declare_and_publish!(as_i32, AsI32);
 declare_and_publish!(as_i64, AsI64);
+declare_and_publish!(as_i8, AsI8);
 declare_and_publish!(as_isize, AsISize);
+#[cfg(not(feature = "nostd"))]
+declare_and_publish!(as_os_str, AsOsStr);
 declare_and_publish!(as_str, AsStr);
 declare_and_publish!(as_u128, AsU128);
+declare_and_publish!(as_u16, AsU16);
 declare_and_publish!(as_u32, AsU32);
 declare_and_publish!(as_u64, AsU64);
+declare_and_publish!(as_u8, AsU8);
 declare_and_publish!(as_usize, AsUSize);
+declare_and_publish!(classify, Classify);
+declare_and_publish!(component_count, ComponentCount);
+declare_and_publish!(elapsed, Elapsed);
 declare_and_publish!(infinity, Infinity);
 declare_and_publish!(integer, Integer);
 declare_and_publish!(is_default, IsDefault);
 declare_and_publish!(is_empty, IsEmpty);
+declare_and_publish!(is_epoch, IsEpoch);
+declare_and_publish!(is_finite, IsFinite);
 declare_and_publish!(is_infinity, IsInfinity);
+declare_and_publish!(is_max_value, IsMaxValue);
+declare_and_publish!(is_min_value, IsMinValue);
 declare_and_publish!(is_nan, IsNAN);
+declare_and_publish!(is_negative, IsNegative);
+declare_and_publish!(is_negative_infinity, IsNegativeInfinity);
+declare_and_publish!(is_normal, IsNormal);
+declare_and_publish!(is_one, IsOne);
+declare_and_publish!(is_positive, IsPositive);
+declare_and_publish!(is_positive_infinity, IsPositiveInfinity);
+declare_and_publish!(is_sign_negative, IsSignNegative);
+declare_and_publish!(is_subnormal, IsSubnormal);
 declare_and_publish!(is_zero, IsZero);
+declare_and_publish!(is_zero_with_ordering, IsZeroWithOrdering);
 declare_and_publish!(len, Len);
+declare_and_publish!(max_value, MaxValue);
+declare_and_publish!(min_value, MinValue);
+declare_and_publish!(negative_infinity, NegativeInfinity);
+declare_and_publish!(not_a_number, NotANumber);
 declare_and_publish!(numeric, Numeric);
+declare_and_publish!(one, One);
 declare_and_publish!(real, Real);
+declare_and_publish!(saturating_to, SaturatingToI8, SaturatingToI16, SaturatingToI32, SaturatingToI64, SaturatingToI128, SaturatingToISize, SaturatingToU8, SaturatingToU16, SaturatingToU32, SaturatingToU64, SaturatingToU128, SaturatingToUSize);
 declare_and_publish!(scalar, Scalar);
 declare_and_publish!(signed, Signed);
+declare_and_publish!(signum, Signum);
+declare_and_publish!(to_f32, ToF32);
 declare_and_publish!(to_f64, ToF64);
+declare_and_publish!(to_i8, ToI8);
 declare_and_publish!(to_i16, ToI16);
 declare_and_publish!(to_i32, ToI32);
 declare_and_publish!(to_i64, ToI64);
 declare_and_publish!(to_i128, ToI128);
 declare_and_publish!(to_isize, ToISize);
+declare_and_publish!(to_u8, ToU8);
 declare_and_publish!(to_u16, ToU16);
 declare_and_publish!(to_u32, ToU32);
 declare_and_publish!(to_u64, ToU64);
 declare_and_publish!(to_u128, ToU128);
 declare_and_publish!(to_usize, ToUSize);
+declare_and_publish!(try_to, TryToI8, TryToI16, TryToI32, TryToI64, TryToI128, TryToISize, TryToU8, TryToU16, TryToU32, TryToU64, TryToU128, TryToUSize);
 declare_and_publish!(unsigned, Unsigned);
+declare_and_publish!(wrapping_to, WrappingToI8, WrappingToI16, WrappingToI32, WrappingToI64, WrappingToI128, WrappingToISize, WrappingToU8, WrappingToU16, WrappingToU32, WrappingToU64, WrappingToU128, WrappingToUSize);
 declare_and_publish!(zero, Zero);
 
+pub mod primitive;
+
 mod sealed;
 pub(crate) use sealed::Sealed;
 
 
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use crate::{
+        AsStr,
+        IsEmpty,
+        IsZero,
+        Len,
+        ToF64,
+    };
+
+    use std::{
+        borrow::Cow,
+        pin::Pin,
+        rc::Rc,
+        sync::Arc,
+    };
+
+
+    fn as_str_<T : AsStr>(t : &T) -> &str {
+        t.as_str()
+    }
+
+    fn is_empty_<T : IsEmpty>(t : &T) -> bool {
+        t.is_empty()
+    }
+
+    fn is_zero_<T : IsZero>(t : &T) -> bool {
+        t.is_zero()
+    }
+
+    fn len_<T : Len>(t : &T) -> usize {
+        t.len()
+    }
+
+    fn to_f64_<T : ToF64>(t : &T) -> f64 {
+        t.to_f64()
+    }
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Clone, Debug)]
+        struct Name {
+            value : String,
+        }
+
+        impl Name {
+            fn new(value : &str) -> Self {
+                Self {
+                    value : value.into(),
+                }
+            }
+        }
+
+        impl AsStr for Name {
+            fn as_str(&self) -> &str {
+                &self.value
+            }
+        }
+
+        impl IsEmpty for Name {
+            fn is_empty(&self) -> bool {
+                self.value.is_empty()
+            }
+        }
+
+        impl Len for Name {
+            fn len(&self) -> usize {
+                self.value.len()
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        struct Quantity(i32);
+
+        impl IsZero for Quantity {
+            fn is_zero(&self) -> bool {
+                0 == self.0
+            }
+        }
+
+        impl ToF64 for Quantity {
+            fn to_f64(&self) -> f64 {
+                self.0 as f64
+            }
+        }
+
+
+        #[test]
+        fn TEST_Box() {
+            let name = Box::new(Name::new("abc"));
+
+            assert_eq!("abc", as_str_(&name));
+            assert!(!is_empty_(&name));
+            assert_eq!(3, len_(&name));
+
+            let quantity = Box::new(Quantity(0));
+
+            assert!(is_zero_(&quantity));
+            assert_eq!(0.0, to_f64_(&quantity));
+        }
+
+        #[test]
+        fn TEST_Box_OF_dyn() {
+            let name : Box<dyn Len> = Box::new(Name::new("abcd"));
+
+            assert_eq!(4, len_(&name));
+
+            let quantity : Box<dyn ToF64> = Box::new(Quantity(-12));
+
+            assert_eq!(-12.0, to_f64_(&quantity));
+        }
+
+        #[test]
+        fn TEST_Rc() {
+            let name = Rc::new(Name::new(""));
+
+            assert_eq!("", as_str_(&name));
+            assert!(is_empty_(&name));
+            assert_eq!(0, len_(&name));
+
+            let quantity = Rc::new(Quantity(5));
+
+            assert!(!is_zero_(&quantity));
+            assert_eq!(5.0, to_f64_(&quantity));
+        }
+
+        #[test]
+        fn TEST_Rc_OF_dyn() {
+            let name : Rc<dyn IsEmpty> = Rc::new(Name::new("a"));
+
+            assert!(!is_empty_(&name));
+        }
+
+        #[test]
+        fn TEST_Arc() {
+            let name = Arc::new(Name::new("abc"));
+
+            assert_eq!("abc", as_str_(&name));
+            assert!(!is_empty_(&name));
+            assert_eq!(3, len_(&name));
+
+            let quantity = Arc::new(Quantity(0));
+
+            assert!(is_zero_(&quantity));
+            assert_eq!(0.0, to_f64_(&quantity));
+        }
+
+        #[test]
+        fn TEST_Arc_OF_dyn() {
+            let name : Arc<dyn Len> = Arc::new(Name::new("abcd"));
+
+            assert_eq!(4, len_(&name));
+
+            let quantity : Arc<dyn ToF64 + Send + Sync> = Arc::new(Quantity(-12));
+
+            assert_eq!(-12.0, to_f64_(&quantity));
+        }
+
+        #[test]
+        fn TEST_mut_ref() {
+            let mut name = Name::new("");
+
+            {
+                let name = &mut name;
+
+                assert_eq!("", as_str_(&name));
+                assert!(is_empty_(&name));
+                assert_eq!(0, len_(&name));
+
+                name.value.push_str("ab");
+
+                assert!(!is_empty_(&name));
+                assert_eq!(2, len_(&name));
+            }
+
+            let mut quantity = Quantity(3);
+            let quantity = &mut quantity;
+
+            assert!(!is_zero_(&quantity));
+            assert_eq!(3.0, to_f64_(&quantity));
+        }
+
+        #[test]
+        fn TEST_Cow() {
+            let name = Name::new("abc");
+
+            let borrowed : Cow<'_, Name> = Cow::Borrowed(&name);
+            let owned : Cow<'_, Name> = Cow::Owned(Name::new("defgh"));
+
+            assert_eq!("abc", as_str_(&borrowed));
+            assert!(!is_empty_(&borrowed));
+            assert_eq!(3, len_(&borrowed));
+
+            assert_eq!("defgh", as_str_(&owned));
+            assert!(!is_empty_(&owned));
+            assert_eq!(5, len_(&owned));
+
+            let quantity : Cow<'_, Quantity> = Cow::Owned(Quantity(0));
+
+            assert!(is_zero_(&quantity));
+        }
+
+        #[test]
+        fn TEST_Pin() {
+            let mut name = Name::new("abc");
+            let name = Pin::new(&mut name);
+
+            assert_eq!("abc", as_str_(&name));
+            assert!(!is_empty_(&name));
+            assert_eq!(3, len_(&name));
+
+            let quantity = Box::pin(Quantity(7));
+
+            assert!(!is_zero_(&quantity));
+            assert_eq!(7.0, to_f64_(&quantity));
+        }
+
+        #[test]
+        fn TEST_NESTED() {
+            let name = Arc::new(Cow::Owned::<Name>(Name::new("abc")));
+
+            assert_eq!(3, len_(&name));
+
+            let mut quantity = Quantity(0);
+            let quantity = Pin::new(&mut quantity);
+            let quantity = Arc::new(quantity);
+
+            assert!(is_zero_(&quantity));
+        }
+    }
+
+
+    #[cfg(all(
+        feature = "implement-AsStr-for-built_ins",
+        feature = "implement-IsEmpty-for-standard_collection_types",
+        feature = "implement-Len-for-built_ins",
+        feature = "implement-ToF64-for-built_ins",
+    ))]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_Arc() {
+            let s : Arc<str> = Arc::from("abc");
+
+            assert_eq!("abc", as_str_(&s));
+            assert_eq!(3, len_(&s));
+
+            let v : Arc<[i32]> = Arc::from(vec![ 1, 2 ]);
+
+            assert_eq!(2, len_(&v));
+        }
+
+        #[test]
+        fn TEST_mut_ref() {
+            let mut v = vec![ 1, 2, 3 ];
+            let v = &mut v;
+
+            assert!(!is_empty_(&v));
+
+            let mut x = 1.5f32;
+            let x = &mut x;
+
+            assert_eq!(1.5, to_f64_(&x));
+        }
+
+        #[test]
+        fn TEST_Cow() {
+            let borrowed : Cow<'_, str> = Cow::Borrowed("abc");
+            let owned : Cow<'_, str> = Cow::Owned(String::new());
+
+            assert_eq!("abc", as_str_(&borrowed));
+            assert_eq!(3, len_(&borrowed));
+
+            assert_eq!("", as_str_(&owned));
+            assert_eq!(0, len_(&owned));
+
+            let slice : Cow<'_, [u8]> = Cow::Borrowed(&[ 1, 2 ]);
+
+            assert_eq!(2, len_(&slice));
+        }
+
+        #[test]
+        fn TEST_Pin() {
+            let v = Box::pin(Vec::<i32>::new());
+
+            assert!(is_empty_(&v));
+
+            let mut s = String::from("abc");
+            let s = Pin::new(&mut s);
+
+            assert_eq!(3, len_(&s));
+        }
+    }
+}
+
+
 // ///////////////////////////// end of file //////////////////////////// //
 
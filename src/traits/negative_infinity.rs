@@ -0,0 +1,149 @@
+// src/traits/negative_infinity.rs : `NegativeInfinity`
+
+/// Trait defining class method `negative_infinity() : T` that creates an instance of
+/// the implementing type that is conceptually (or actually) negative infinity.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-NegativeInfinity-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`f32`];
+/// - [`f64`];
+pub trait NegativeInfinity {
+    fn negative_infinity() -> Self;
+}
+
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : NegativeInfinity> NegativeInfinity for alloc::boxed::Box<T> {
+    fn negative_infinity() -> Self {
+        alloc::boxed::Box::new(T::negative_infinity())
+    }
+}
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : NegativeInfinity> NegativeInfinity for alloc::rc::Rc<T> {
+    fn negative_infinity() -> Self {
+        alloc::rc::Rc::new(T::negative_infinity())
+    }
+}
+
+
+#[cfg(feature = "implement-NegativeInfinity-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_NegativeInfinity_ {
+        ($type:tt) => {
+            impl super::NegativeInfinity for $type {
+                #[inline]
+                fn negative_infinity() -> Self {
+                    $type::NEG_INFINITY
+                }
+            }
+        };
+    }
+
+    implement_NegativeInfinity_!(f32);
+    implement_NegativeInfinity_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::NegativeInfinity;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct Price {
+            value : f64,
+        }
+
+        impl NegativeInfinity for Price {
+            fn negative_infinity() -> Self {
+                Self {
+                    value : f64::NEG_INFINITY,
+                }
+            }
+        }
+
+
+        #[test]
+        fn TEST_NegativeInfinity() {
+            let price = Price::negative_infinity();
+
+            assert!(price.value.is_infinite());
+            assert!(price.value.is_sign_negative());
+        }
+
+        #[test]
+        fn TEST_NegativeInfinity_IN_Box() {
+            let price = Box::<Price>::negative_infinity();
+
+            assert!(price.value.is_infinite());
+            assert!(price.value.is_sign_negative());
+        }
+
+        #[test]
+        fn TEST_NegativeInfinity_IN_Rc() {
+            let price = Rc::<Price>::negative_infinity();
+
+            assert!(price.value.is_infinite());
+            assert!(price.value.is_sign_negative());
+        }
+    }
+
+
+    #[cfg(feature = "implement-NegativeInfinity-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_f32() {
+            let v = f32::negative_infinity();
+
+            assert!(v.is_infinite());
+            assert!(v.is_sign_negative());
+        }
+
+        #[test]
+        fn TEST_f64() {
+            let v = f64::negative_infinity();
+
+            assert!(v.is_infinite());
+            assert!(v.is_sign_negative());
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            let v = *Box::<f64>::negative_infinity();
+
+            assert!(v.is_infinite());
+            assert!(v.is_sign_negative());
+
+            let v = *Rc::<f32>::negative_infinity();
+
+            assert!(v.is_infinite());
+            assert!(v.is_sign_negative());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,142 @@
+// src/traits/not_a_number.rs : `NotANumber`
+
+/// Trait defining class method `not_a_number() : T` that creates an instance of
+/// the implementing type that is conceptually (or actually) "not a number" (as in so for [`f32::NAN`] [`f64::NAN`]).
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-NotANumber-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`f32`];
+/// - [`f64`];
+pub trait NotANumber {
+    fn not_a_number() -> Self;
+}
+
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : NotANumber> NotANumber for alloc::boxed::Box<T> {
+    fn not_a_number() -> Self {
+        alloc::boxed::Box::new(T::not_a_number())
+    }
+}
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : NotANumber> NotANumber for alloc::rc::Rc<T> {
+    fn not_a_number() -> Self {
+        alloc::rc::Rc::new(T::not_a_number())
+    }
+}
+
+
+#[cfg(feature = "implement-NotANumber-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_NotANumber_ {
+        ($type:tt) => {
+            impl super::NotANumber for $type {
+                #[inline]
+                fn not_a_number() -> Self {
+                    $type::NAN
+                }
+            }
+        };
+    }
+
+    implement_NotANumber_!(f32);
+    implement_NotANumber_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::NotANumber;
+
+    use std::rc::Rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct Price {
+            value : f64,
+        }
+
+        impl NotANumber for Price {
+            fn not_a_number() -> Self {
+                Self {
+                    value : f64::NAN,
+                }
+            }
+        }
+
+
+        #[test]
+        fn TEST_NotANumber() {
+            let price = Price::not_a_number();
+
+            assert!(price.value.is_nan());
+        }
+
+        #[test]
+        fn TEST_NotANumber_IN_Box() {
+            let price = Box::<Price>::not_a_number();
+
+            assert!(price.value.is_nan());
+        }
+
+        #[test]
+        fn TEST_NotANumber_IN_Rc() {
+            let price = Rc::<Price>::not_a_number();
+
+            assert!(price.value.is_nan());
+        }
+    }
+
+
+    #[cfg(feature = "implement-NotANumber-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_f32() {
+            let v = f32::not_a_number();
+
+            assert!(v.is_nan());
+        }
+
+        #[test]
+        fn TEST_f64() {
+            let v = f64::not_a_number();
+
+            assert!(v.is_nan());
+        }
+
+        #[test]
+        fn TEST_IN_Box_AND_Rc() {
+            let v = *Box::<f64>::not_a_number();
+
+            assert!(v.is_nan());
+
+            let v = *Rc::<f32>::not_a_number();
+
+            assert!(v.is_nan());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
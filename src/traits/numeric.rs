@@ -1,9 +1,50 @@
 // src/traits/numeric.rs : `Numeric`
 
-pub trait Numeric {
+use crate::{
+    IsZero,
+    Scalar,
+    Zero,
+};
+
+use core::ops as core_ops;
+
+
+/// Marker trait for (scalar) numeric types, which may be compared, may
+/// be zero, and support the basic arithmetic operations.
+///
+/// Hence, generic code that is bounded by `Numeric` - or by any of the
+/// traits that refine it, namely [`Integer`](crate::Integer),
+/// [`Real`](crate::Real), [`Signed`](crate::Signed), and
+/// [`Unsigned`](crate::Unsigned) - may copy, compare, add, subtract,
+/// multiply, divide, and take the remainder of, its values, and obtain
+/// and test for zero.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-Numeric-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait Numeric:
+    Scalar
+    + Copy
+    + PartialOrd
+    + Zero
+    + IsZero
+    + core_ops::Add<Output = Self>
+    + core_ops::Sub<Output = Self>
+    + core_ops::Mul<Output = Self>
+    + core_ops::Div<Output = Self>
+    + core_ops::Rem<Output = Self>
+{
 }
 
 
+#[cfg(feature = "implement-Numeric-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
 
@@ -29,5 +70,176 @@ mod impl_for_built_ins {
 }
 
 
-// ///////////////////////////// end of file //////////////////////////// //
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    /// Uses only the facilities implied by `Numeric`.
+    fn sum_of_non_zero<T : Numeric>(values : &[T]) -> (T, usize) {
+        let mut sum = T::zero();
+        let mut num_non_zero = 0;
+
+        for &value in values {
+            if !value.is_zero() {
+                sum = sum + value;
+                num_non_zero += 1;
+            }
+        }
+
+        (sum, num_non_zero)
+    }
+
+    fn mean<T : Numeric>(
+        values : &[T],
+        count : T,
+    ) -> Option<T> {
+        if count.is_zero() {
+            None
+        } else {
+            Some(sum_of_non_zero(values).0 / count)
+        }
+    }
+
+    fn requires_Scalar<T : Scalar>(t : T) -> T {
+        t
+    }
+
+    fn Numeric_is_Scalar<T : Numeric>(t : T) -> T {
+        requires_Scalar(t)
+    }
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        struct Cents(i64);
+
+        impl Scalar for Cents {}
+
+        impl Zero for Cents {
+            fn zero() -> Self {
+                Self(0)
+            }
+        }
+
+        impl IsZero for Cents {
+            fn is_zero(&self) -> bool {
+                0 == self.0
+            }
+        }
+
+        impl core_ops::Add for Cents {
+            type Output = Self;
+
+            fn add(
+                self,
+                rhs : Self,
+            ) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
 
+        impl core_ops::Sub for Cents {
+            type Output = Self;
+
+            fn sub(
+                self,
+                rhs : Self,
+            ) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl core_ops::Mul for Cents {
+            type Output = Self;
+
+            fn mul(
+                self,
+                rhs : Self,
+            ) -> Self {
+                Self(self.0 * rhs.0)
+            }
+        }
+
+        impl core_ops::Div for Cents {
+            type Output = Self;
+
+            fn div(
+                self,
+                rhs : Self,
+            ) -> Self {
+                Self(self.0 / rhs.0)
+            }
+        }
+
+        impl core_ops::Rem for Cents {
+            type Output = Self;
+
+            fn rem(
+                self,
+                rhs : Self,
+            ) -> Self {
+                Self(self.0 % rhs.0)
+            }
+        }
+
+        impl Numeric for Cents {}
+
+
+        #[test]
+        fn TEST_sum_of_non_zero() {
+            assert_eq!((Cents(0), 0), sum_of_non_zero::<Cents>(&[]));
+            assert_eq!((Cents(6), 3), sum_of_non_zero(&[Cents(1), Cents(0), Cents(2), Cents(3)]));
+        }
+
+        #[test]
+        fn TEST_mean() {
+            assert_eq!(None, mean(&[Cents(1)], Cents(0)));
+            assert_eq!(Some(Cents(2)), mean(&[Cents(1), Cents(2), Cents(3)], Cents(3)));
+        }
+
+        #[test]
+        fn TEST_Numeric_is_Scalar() {
+            assert_eq!(Cents(1), Numeric_is_Scalar(Cents(1)));
+        }
+    }
+
+
+    #[cfg(feature = "implement-Numeric-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_sum_of_non_zero() {
+            assert_eq!((6, 3), sum_of_non_zero(&[1i8, 0, 2, 3]));
+            assert_eq!((6, 3), sum_of_non_zero(&[1u64, 0, 2, 3]));
+            assert_eq!((6.5, 3), sum_of_non_zero(&[1.5f64, 0.0, 2.0, 3.0]));
+        }
+
+        #[test]
+        fn TEST_mean() {
+            assert_eq!(None, mean(&[1i32], 0));
+            assert_eq!(Some(2), mean(&[1usize, 2, 3], 3));
+            assert_eq!(Some(2.5), mean(&[2.0f32, 3.0], 2.0));
+        }
+
+        #[test]
+        fn TEST_Numeric_is_Scalar() {
+            assert_eq!(1, Numeric_is_Scalar(1u16));
+            assert_eq!(1.0, Numeric_is_Scalar(1.0f64));
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
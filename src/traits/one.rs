@@ -0,0 +1,283 @@
+// src/traits/one.rs : `One`
+
+/// Trait defining class method `one() : T` that creates an instance of
+/// the implementing type that is conceptually (or actually) one, i.e. the
+/// multiplicative identity.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-One-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`i8`];
+/// - [`i16`];
+/// - [`i32`];
+/// - [`i64`];
+/// - [`i128`];
+/// - [`u8`];
+/// - [`u16`];
+/// - [`u32`];
+/// - [`u64`];
+/// - [`u128`];
+/// - [`isize`];
+/// - [`usize`];
+/// - [`f32`];
+/// - [`f64`];
+///
+/// ## Standard Numeric Types
+///
+/// If the feature `"implement-One-for-standard_num_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`core::num::NonZeroI8`], ..., [`core::num::NonZeroI128`],
+///   [`core::num::NonZeroIsize`];
+/// - [`core::num::NonZeroU8`], ..., [`core::num::NonZeroU128`],
+///   [`core::num::NonZeroUsize`];
+/// - [`core::num::Saturating<T>`], where `T : One`;
+/// - [`core::num::Wrapping<T>`], where `T : One`;
+///
+/// NOTE: it is not implemented for [`std::time::Duration`], since a
+/// duration has no multiplicative identity (a duration may be multiplied
+/// only by a scalar).
+pub trait One {
+    fn one() -> Self;
+}
+
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : One> One for alloc::boxed::Box<T> {
+    fn one() -> Self {
+        alloc::boxed::Box::new(T::one())
+    }
+}
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : One> One for alloc::rc::Rc<T> {
+    fn one() -> Self {
+        alloc::rc::Rc::new(T::one())
+    }
+}
+
+
+#[cfg(feature = "implement-One-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_One_ {
+        ($type:tt, $one_value:expr) => {
+            impl super::One for $type {
+                #[inline]
+                fn one() -> Self {
+                    $one_value
+                }
+            }
+        };
+    }
+
+    implement_One_!(i8, 1);
+    implement_One_!(i16, 1);
+    implement_One_!(i32, 1);
+    implement_One_!(i64, 1);
+    implement_One_!(i128, 1);
+
+    implement_One_!(u8, 1);
+    implement_One_!(u16, 1);
+    implement_One_!(u32, 1);
+    implement_One_!(u64, 1);
+    implement_One_!(u128, 1);
+
+    implement_One_!(isize, 1);
+    implement_One_!(usize, 1);
+
+    implement_One_!(f32, 1.0);
+    implement_One_!(f64, 1.0);
+}
+
+
+#[cfg(feature = "implement-One-for-standard_num_types")]
+mod impl_for_std_num_types {
+    #![allow(non_snake_case)]
+
+    use super::One;
+
+    use core::num as core_num;
+
+
+    // NonZero*
+
+    macro_rules! implement_One_for_NonZero_ {
+        ($type:ident) => {
+            impl One for core_num::$type {
+                #[inline]
+                fn one() -> Self {
+                    match core_num::$type::new(1) {
+                        Some(one) => one,
+                        None => unreachable!(),
+                    }
+                }
+            }
+        };
+    }
+
+    implement_One_for_NonZero_!(NonZeroI8);
+    implement_One_for_NonZero_!(NonZeroI16);
+    implement_One_for_NonZero_!(NonZeroI32);
+    implement_One_for_NonZero_!(NonZeroI64);
+    implement_One_for_NonZero_!(NonZeroI128);
+    implement_One_for_NonZero_!(NonZeroIsize);
+
+    implement_One_for_NonZero_!(NonZeroU8);
+    implement_One_for_NonZero_!(NonZeroU16);
+    implement_One_for_NonZero_!(NonZeroU32);
+    implement_One_for_NonZero_!(NonZeroU64);
+    implement_One_for_NonZero_!(NonZeroU128);
+    implement_One_for_NonZero_!(NonZeroUsize);
+
+
+    // Saturating<T>
+
+    impl<T : One> One for core_num::Saturating<T> {
+        #[inline]
+        fn one() -> Self {
+            core_num::Saturating(T::one())
+        }
+    }
+
+
+    // Wrapping<T>
+
+    impl<T : One> One for core_num::Wrapping<T> {
+        #[inline]
+        fn one() -> Self {
+            core_num::Wrapping(T::one())
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::One;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Debug)]
+        struct CustomType {
+            pub value : i32,
+        }
+
+        impl One for CustomType {
+            fn one() -> Self {
+                Self {
+                    value : 100,
+                }
+            }
+        }
+
+
+        #[test]
+        fn TEST_One() {
+            let ct = CustomType::one();
+
+            assert_eq!(100, ct.value);
+        }
+
+        #[test]
+        fn TEST_One_IN_Box() {
+            let ct = Box::<CustomType>::one();
+
+            assert_eq!(100, ct.value);
+        }
+
+        #[test]
+        fn TEST_One_IN_Rc() {
+            let ct = std::rc::Rc::<CustomType>::one();
+
+            assert_eq!(100, ct.value);
+        }
+    }
+
+
+    #[cfg(feature = "implement-One-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_INTEGERS() {
+            assert_eq!(1, i8::one());
+            assert_eq!(1, i16::one());
+            assert_eq!(1, i32::one());
+            assert_eq!(1, i64::one());
+            assert_eq!(1, i128::one());
+
+            assert_eq!(1, u8::one());
+            assert_eq!(1, u16::one());
+            assert_eq!(1, u32::one());
+            assert_eq!(1, u64::one());
+            assert_eq!(1, u128::one());
+
+            assert_eq!(1, isize::one());
+            assert_eq!(1, usize::one());
+        }
+
+        #[test]
+        fn TEST_FLOATS() {
+            assert_eq!(1.0f32, f32::one());
+            assert_eq!(1.0f64, f64::one());
+        }
+
+        #[test]
+        fn TEST_MULTIPLICATIVE_IDENTITY() {
+            assert_eq!(-123, -123 * i32::one());
+            assert_eq!(1.5, 1.5 * f64::one());
+        }
+    }
+
+
+    #[cfg(all(feature = "implement-One-for-built_ins", feature = "implement-One-for-standard_num_types"))]
+    mod TEST_STANDARD_NUM_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::num as std_num;
+
+
+        #[test]
+        fn TEST_NonZero() {
+            assert_eq!(1, std_num::NonZeroI8::one().get());
+            assert_eq!(1, std_num::NonZeroI128::one().get());
+            assert_eq!(1, std_num::NonZeroIsize::one().get());
+            assert_eq!(1, std_num::NonZeroU8::one().get());
+            assert_eq!(1, std_num::NonZeroU64::one().get());
+            assert_eq!(1, std_num::NonZeroUsize::one().get());
+        }
+
+        #[test]
+        fn TEST_Saturating() {
+            assert_eq!(std_num::Saturating(1u8), std_num::Saturating::<u8>::one());
+            assert_eq!(std_num::Saturating(1.0f64).0, std_num::Saturating::<f64>::one().0);
+        }
+
+        #[test]
+        fn TEST_Wrapping() {
+            assert_eq!(std_num::Wrapping(1i32), std_num::Wrapping::<i32>::one());
+            assert_eq!(std_num::Wrapping(1u128), std_num::Wrapping::<u128>::one());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
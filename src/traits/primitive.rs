@@ -0,0 +1,287 @@
+// src/traits/primitive.rs : `PrimitiveFloat`, `PrimitiveInteger`, `PrimitiveSigned`, `PrimitiveUnsigned`
+
+//! Sealed counterparts of the marker traits [`Integer`], [`Real`],
+//! [`Signed`], and [`Unsigned`].
+//!
+//! The marker traits are open, so that they may be implemented by
+//! user-defined types, such as newtypes. By contrast, the traits in this
+//! module are implemented only by the built-in primitive types, and may
+//! not be implemented outside this crate, so that generic code that is
+//! bounded by them may rely upon the behaviour of the primitives.
+//!
+//! ```compile_fail
+//! use base_traits::{
+//!     primitive::PrimitiveInteger,
+//!     Integer,
+//!     IsZero,
+//!     Numeric,
+//!     Scalar,
+//!     Zero,
+//! };
+//!
+//! #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+//! struct Id(u32);
+//! #
+//! # macro_rules! implement_op_ {
+//! #     ($trait:ident, $method:ident, $op:tt) => {
+//! #         impl std::ops::$trait for Id {
+//! #             type Output = Self;
+//! #
+//! #             fn $method(self, rhs : Self) -> Self { Self(self.0 $op rhs.0) }
+//! #         }
+//! #     };
+//! # }
+//! #
+//! # implement_op_!(Add, add, +);
+//! # implement_op_!(Sub, sub, -);
+//! # implement_op_!(Mul, mul, *);
+//! # implement_op_!(Div, div, /);
+//! # implement_op_!(Rem, rem, %);
+//!
+//! impl Scalar for Id {}
+//!
+//! impl Zero for Id {
+//!     fn zero() -> Self { Self(0) }
+//! }
+//!
+//! impl IsZero for Id {
+//!     fn is_zero(&self) -> bool { 0 == self.0 }
+//! }
+//!
+//! impl Numeric for Id {}
+//!
+//! impl Integer for Id {} // ok
+//!
+//! impl PrimitiveInteger for Id {} // error: `Sealed` is not implemented
+//! ```
+
+use crate::{
+    private::Sealed,
+    Integer,
+    Real,
+    Signed,
+    Unsigned,
+};
+
+
+/// Sealed marker trait for the built-in floating-point types.
+///
+/// # Implementations
+///
+/// If the feature `"implement-Real-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is implemented for
+/// the following types:
+/// - [`f32`];
+/// - [`f64`];
+pub trait PrimitiveFloat : Real + Sealed {
+}
+
+/// Sealed marker trait for the built-in integer types.
+///
+/// # Implementations
+///
+/// If the feature `"implement-Integer-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is implemented for
+/// the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+pub trait PrimitiveInteger : Integer + Sealed {
+}
+
+/// Sealed marker trait for the built-in signed types.
+///
+/// # Implementations
+///
+/// If the feature `"implement-Signed-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is implemented for
+/// the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`f32`], [`f64`];
+pub trait PrimitiveSigned : Signed + Sealed {
+}
+
+/// Sealed marker trait for the built-in unsigned types.
+///
+/// # Implementations
+///
+/// If the feature `"implement-Unsigned-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is implemented for
+/// the following types:
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+pub trait PrimitiveUnsigned : Unsigned + Sealed {
+}
+
+
+mod impl_Sealed {
+    #![allow(non_snake_case)]
+
+    use super::Sealed;
+
+
+    impl Sealed for i8 {}
+    impl Sealed for u8 {}
+    impl Sealed for i16 {}
+    impl Sealed for u16 {}
+    impl Sealed for i32 {}
+    impl Sealed for u32 {}
+    impl Sealed for i64 {}
+    impl Sealed for u64 {}
+    impl Sealed for i128 {}
+    impl Sealed for u128 {}
+
+    impl Sealed for isize {}
+    impl Sealed for usize {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+
+#[cfg(feature = "implement-Real-for-built_ins")]
+mod impl_PrimitiveFloat {
+    #![allow(non_snake_case)]
+
+    use super::PrimitiveFloat;
+
+
+    impl PrimitiveFloat for f32 {}
+    impl PrimitiveFloat for f64 {}
+}
+
+#[cfg(feature = "implement-Integer-for-built_ins")]
+mod impl_PrimitiveInteger {
+    #![allow(non_snake_case)]
+
+    use super::PrimitiveInteger;
+
+
+    impl PrimitiveInteger for i8 {}
+    impl PrimitiveInteger for u8 {}
+    impl PrimitiveInteger for i16 {}
+    impl PrimitiveInteger for u16 {}
+    impl PrimitiveInteger for i32 {}
+    impl PrimitiveInteger for u32 {}
+    impl PrimitiveInteger for i64 {}
+    impl PrimitiveInteger for u64 {}
+    impl PrimitiveInteger for i128 {}
+    impl PrimitiveInteger for u128 {}
+
+    impl PrimitiveInteger for isize {}
+    impl PrimitiveInteger for usize {}
+}
+
+#[cfg(feature = "implement-Signed-for-built_ins")]
+mod impl_PrimitiveSigned {
+    #![allow(non_snake_case)]
+
+    use super::PrimitiveSigned;
+
+
+    impl PrimitiveSigned for i8 {}
+    impl PrimitiveSigned for i16 {}
+    impl PrimitiveSigned for i32 {}
+    impl PrimitiveSigned for i64 {}
+    impl PrimitiveSigned for i128 {}
+
+    impl PrimitiveSigned for isize {}
+
+    impl PrimitiveSigned for f32 {}
+    impl PrimitiveSigned for f64 {}
+}
+
+#[cfg(feature = "implement-Unsigned-for-built_ins")]
+mod impl_PrimitiveUnsigned {
+    #![allow(non_snake_case)]
+
+    use super::PrimitiveUnsigned;
+
+
+    impl PrimitiveUnsigned for u8 {}
+    impl PrimitiveUnsigned for u16 {}
+    impl PrimitiveUnsigned for u32 {}
+    impl PrimitiveUnsigned for u64 {}
+    impl PrimitiveUnsigned for u128 {}
+
+    impl PrimitiveUnsigned for usize {}
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    fn bits_of<T : PrimitiveInteger>(_ : T) -> usize {
+        // relies on the guarantee that `T` is a primitive, and so has
+        // no padding, indirection, etc.
+        8 * core::mem::size_of::<T>()
+    }
+
+    fn requires_Integer<T : Integer>(t : T) -> T {
+        t
+    }
+
+    fn PrimitiveInteger_is_Integer<T : PrimitiveInteger>(t : T) -> T {
+        requires_Integer(t)
+    }
+
+    fn requires_Real<T : Real>(t : T) -> T {
+        t
+    }
+
+    fn PrimitiveFloat_is_Real<T : PrimitiveFloat>(t : T) -> T {
+        requires_Real(t)
+    }
+
+    fn requires_Signed<T : Signed>(t : T) -> T {
+        t
+    }
+
+    fn PrimitiveSigned_is_Signed<T : PrimitiveSigned>(t : T) -> T {
+        requires_Signed(t)
+    }
+
+    fn requires_Unsigned<T : Unsigned>(t : T) -> T {
+        t
+    }
+
+    fn PrimitiveUnsigned_is_Unsigned<T : PrimitiveUnsigned>(t : T) -> T {
+        requires_Unsigned(t)
+    }
+
+
+    #[cfg(feature = "implement-Integer-for-built_ins")]
+    #[test]
+    fn TEST_PrimitiveInteger() {
+        assert_eq!(8, bits_of(0i8));
+        assert_eq!(128, bits_of(0u128));
+        assert_eq!(8 * core::mem::size_of::<usize>(), bits_of(0usize));
+
+        assert_eq!(-1, PrimitiveInteger_is_Integer(-1i32));
+    }
+
+    #[cfg(feature = "implement-Real-for-built_ins")]
+    #[test]
+    fn TEST_PrimitiveFloat() {
+        assert_eq!(-1.0, PrimitiveFloat_is_Real(-1.0f32));
+        assert_eq!(1.0, PrimitiveFloat_is_Real(1.0f64));
+    }
+
+    #[cfg(feature = "implement-Signed-for-built_ins")]
+    #[test]
+    fn TEST_PrimitiveSigned() {
+        assert_eq!(-1, PrimitiveSigned_is_Signed(-1i8));
+        assert_eq!(-1.0, PrimitiveSigned_is_Signed(-1.0f64));
+    }
+
+    #[cfg(feature = "implement-Unsigned-for-built_ins")]
+    #[test]
+    fn TEST_PrimitiveUnsigned() {
+        assert_eq!(1, PrimitiveUnsigned_is_Unsigned(1u16));
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
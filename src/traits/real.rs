@@ -1,9 +1,28 @@
 // src/traits/real.rs : `Real`
 
-pub trait Real {
+use crate::Numeric;
+
+use core::ops as core_ops;
+
+
+/// Marker trait for real numeric types, such as the floating-point types,
+/// which, in addition to the facilities implied by [`Numeric`], may be
+/// negated.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-Real-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`f32`];
+/// - [`f64`];
+pub trait Real : Numeric + core_ops::Neg<Output = Self> {
 }
 
 
+#[cfg(feature = "implement-Real-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
 
@@ -15,5 +34,57 @@ mod impl_for_built_ins {
 }
 
 
-// ///////////////////////////// end of file //////////////////////////// //
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    /// Uses only the facilities implied by `Real`.
+    fn absolute_difference<T : Real>(
+        a : T,
+        b : T,
+    ) -> T {
+        let d = a - b;
+
+        if d < T::zero() {
+            -d
+        } else {
+            d
+        }
+    }
+
+    fn requires_Numeric<T : Numeric>(t : T) -> T {
+        t
+    }
 
+    fn Real_is_Numeric<T : Real>(t : T) -> T {
+        requires_Numeric(t)
+    }
+
+
+    #[cfg(feature = "implement-Real-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_absolute_difference() {
+            assert_eq!(1.5, absolute_difference(1.0f32, 2.5));
+            assert_eq!(1.5, absolute_difference(2.5f64, 1.0));
+            assert_eq!(0.0, absolute_difference(2.5f64, 2.5));
+        }
+
+        #[test]
+        fn TEST_Real_is_Numeric() {
+            assert_eq!(-1.0, Real_is_Numeric(-1.0f32));
+            assert_eq!(1.0, Real_is_Numeric(1.0f64));
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,1517 @@
+// src/traits/saturating_to.rs : `SaturatingToI8`, `SaturatingToI16`, `SaturatingToI32`, `SaturatingToI64`, `SaturatingToI128`, `SaturatingToISize`, `SaturatingToU8`, `SaturatingToU16`, `SaturatingToU32`, `SaturatingToU64`, `SaturatingToU128`, `SaturatingToUSize`
+
+
+/// Trait defining instance method `saturating_to_i8() : i8` that provides a
+/// conversion into `i8` that saturates at the bounds of `i8`.
+///
+/// A value greater than [`i8::MAX`] yields `i8::MAX`, and a value less
+/// than [`i8::MIN`] yields `i8::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `i8::MAX`, negative infinity yielding `i8::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToI8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToI8 {
+    fn saturating_to_i8(&self) -> i8;
+}
+
+/// Trait defining instance method `saturating_to_i16() : i16` that provides a
+/// conversion into `i16` that saturates at the bounds of `i16`.
+///
+/// A value greater than [`i16::MAX`] yields `i16::MAX`, and a value less
+/// than [`i16::MIN`] yields `i16::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `i16::MAX`, negative infinity yielding `i16::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToI16-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToI16 {
+    fn saturating_to_i16(&self) -> i16;
+}
+
+/// Trait defining instance method `saturating_to_i32() : i32` that provides a
+/// conversion into `i32` that saturates at the bounds of `i32`.
+///
+/// A value greater than [`i32::MAX`] yields `i32::MAX`, and a value less
+/// than [`i32::MIN`] yields `i32::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `i32::MAX`, negative infinity yielding `i32::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToI32-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToI32 {
+    fn saturating_to_i32(&self) -> i32;
+}
+
+/// Trait defining instance method `saturating_to_i64() : i64` that provides a
+/// conversion into `i64` that saturates at the bounds of `i64`.
+///
+/// A value greater than [`i64::MAX`] yields `i64::MAX`, and a value less
+/// than [`i64::MIN`] yields `i64::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `i64::MAX`, negative infinity yielding `i64::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToI64-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToI64 {
+    fn saturating_to_i64(&self) -> i64;
+}
+
+/// Trait defining instance method `saturating_to_i128() : i128` that provides a
+/// conversion into `i128` that saturates at the bounds of `i128`.
+///
+/// A value greater than [`i128::MAX`] yields `i128::MAX`, and a value less
+/// than [`i128::MIN`] yields `i128::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `i128::MAX`, negative infinity yielding `i128::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToI128-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToI128 {
+    fn saturating_to_i128(&self) -> i128;
+}
+
+/// Trait defining instance method `saturating_to_isize() : isize` that provides a
+/// conversion into `isize` that saturates at the bounds of `isize`.
+///
+/// A value greater than [`isize::MAX`] yields `isize::MAX`, and a value less
+/// than [`isize::MIN`] yields `isize::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `isize::MAX`, negative infinity yielding `isize::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToISize-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToISize {
+    fn saturating_to_isize(&self) -> isize;
+}
+
+/// Trait defining instance method `saturating_to_u8() : u8` that provides a
+/// conversion into `u8` that saturates at the bounds of `u8`.
+///
+/// A value greater than [`u8::MAX`] yields `u8::MAX`, and a value less
+/// than [`u8::MIN`] yields `u8::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `u8::MAX`, negative infinity yielding `u8::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToU8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToU8 {
+    fn saturating_to_u8(&self) -> u8;
+}
+
+/// Trait defining instance method `saturating_to_u16() : u16` that provides a
+/// conversion into `u16` that saturates at the bounds of `u16`.
+///
+/// A value greater than [`u16::MAX`] yields `u16::MAX`, and a value less
+/// than [`u16::MIN`] yields `u16::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `u16::MAX`, negative infinity yielding `u16::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToU16-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToU16 {
+    fn saturating_to_u16(&self) -> u16;
+}
+
+/// Trait defining instance method `saturating_to_u32() : u32` that provides a
+/// conversion into `u32` that saturates at the bounds of `u32`.
+///
+/// A value greater than [`u32::MAX`] yields `u32::MAX`, and a value less
+/// than [`u32::MIN`] yields `u32::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `u32::MAX`, negative infinity yielding `u32::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToU32-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToU32 {
+    fn saturating_to_u32(&self) -> u32;
+}
+
+/// Trait defining instance method `saturating_to_u64() : u64` that provides a
+/// conversion into `u64` that saturates at the bounds of `u64`.
+///
+/// A value greater than [`u64::MAX`] yields `u64::MAX`, and a value less
+/// than [`u64::MIN`] yields `u64::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `u64::MAX`, negative infinity yielding `u64::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToU64-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToU64 {
+    fn saturating_to_u64(&self) -> u64;
+}
+
+/// Trait defining instance method `saturating_to_u128() : u128` that provides a
+/// conversion into `u128` that saturates at the bounds of `u128`.
+///
+/// A value greater than [`u128::MAX`] yields `u128::MAX`, and a value less
+/// than [`u128::MIN`] yields `u128::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `u128::MAX`, negative infinity yielding `u128::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToU128-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToU128 {
+    fn saturating_to_u128(&self) -> u128;
+}
+
+/// Trait defining instance method `saturating_to_usize() : usize` that provides a
+/// conversion into `usize` that saturates at the bounds of `usize`.
+///
+/// A value greater than [`usize::MAX`] yields `usize::MAX`, and a value less
+/// than [`usize::MIN`] yields `usize::MIN`. A floating-point value is
+/// rounded towards zero, with positive infinity yielding
+/// `usize::MAX`, negative infinity yielding `usize::MIN`, and NaN yielding
+/// `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-SaturatingToUSize-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait SaturatingToUSize {
+    fn saturating_to_usize(&self) -> usize;
+}
+
+
+implement_forwarding_!(SaturatingToI8 {
+    fn saturating_to_i8(&self) -> i8;
+});
+
+implement_forwarding_!(SaturatingToI16 {
+    fn saturating_to_i16(&self) -> i16;
+});
+
+implement_forwarding_!(SaturatingToI32 {
+    fn saturating_to_i32(&self) -> i32;
+});
+
+implement_forwarding_!(SaturatingToI64 {
+    fn saturating_to_i64(&self) -> i64;
+});
+
+implement_forwarding_!(SaturatingToI128 {
+    fn saturating_to_i128(&self) -> i128;
+});
+
+implement_forwarding_!(SaturatingToISize {
+    fn saturating_to_isize(&self) -> isize;
+});
+
+implement_forwarding_!(SaturatingToU8 {
+    fn saturating_to_u8(&self) -> u8;
+});
+
+implement_forwarding_!(SaturatingToU16 {
+    fn saturating_to_u16(&self) -> u16;
+});
+
+implement_forwarding_!(SaturatingToU32 {
+    fn saturating_to_u32(&self) -> u32;
+});
+
+implement_forwarding_!(SaturatingToU64 {
+    fn saturating_to_u64(&self) -> u64;
+});
+
+implement_forwarding_!(SaturatingToU128 {
+    fn saturating_to_u128(&self) -> u128;
+});
+
+implement_forwarding_!(SaturatingToUSize {
+    fn saturating_to_usize(&self) -> usize;
+});
+
+
+
+#[cfg(feature = "implement-SaturatingToI8-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToI8_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToI8_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToI8 for $type {
+                #[inline]
+                fn saturating_to_i8(&self) -> i8 {
+                    i8::try_from(*self).unwrap_or(if *self < 0 { i8::MIN } else { i8::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI8_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToI8 for $type {
+                #[inline]
+                fn saturating_to_i8(&self) -> i8 {
+                    i8::try_from(*self).unwrap_or(i8::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI8_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToI8 for $type {
+                #[inline]
+                fn saturating_to_i8(&self) -> i8 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { i8::MAX } else { i8::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as i8
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToI8_for_signed_!(i8);
+    implement_SaturatingToI8_for_signed_!(i16);
+    implement_SaturatingToI8_for_signed_!(i32);
+    implement_SaturatingToI8_for_signed_!(i64);
+    implement_SaturatingToI8_for_signed_!(i128);
+    implement_SaturatingToI8_for_signed_!(isize);
+    implement_SaturatingToI8_for_unsigned_!(u8);
+    implement_SaturatingToI8_for_unsigned_!(u16);
+    implement_SaturatingToI8_for_unsigned_!(u32);
+    implement_SaturatingToI8_for_unsigned_!(u64);
+    implement_SaturatingToI8_for_unsigned_!(u128);
+    implement_SaturatingToI8_for_unsigned_!(usize);
+    implement_SaturatingToI8_for_float_!(f32);
+    implement_SaturatingToI8_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToI16-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToI16_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToI16_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToI16 for $type {
+                #[inline]
+                fn saturating_to_i16(&self) -> i16 {
+                    i16::try_from(*self).unwrap_or(if *self < 0 { i16::MIN } else { i16::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI16_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToI16 for $type {
+                #[inline]
+                fn saturating_to_i16(&self) -> i16 {
+                    i16::try_from(*self).unwrap_or(i16::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI16_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToI16 for $type {
+                #[inline]
+                fn saturating_to_i16(&self) -> i16 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { i16::MAX } else { i16::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as i16
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToI16_for_signed_!(i8);
+    implement_SaturatingToI16_for_signed_!(i16);
+    implement_SaturatingToI16_for_signed_!(i32);
+    implement_SaturatingToI16_for_signed_!(i64);
+    implement_SaturatingToI16_for_signed_!(i128);
+    implement_SaturatingToI16_for_signed_!(isize);
+    implement_SaturatingToI16_for_unsigned_!(u8);
+    implement_SaturatingToI16_for_unsigned_!(u16);
+    implement_SaturatingToI16_for_unsigned_!(u32);
+    implement_SaturatingToI16_for_unsigned_!(u64);
+    implement_SaturatingToI16_for_unsigned_!(u128);
+    implement_SaturatingToI16_for_unsigned_!(usize);
+    implement_SaturatingToI16_for_float_!(f32);
+    implement_SaturatingToI16_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToI32-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToI32_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToI32_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToI32 for $type {
+                #[inline]
+                fn saturating_to_i32(&self) -> i32 {
+                    i32::try_from(*self).unwrap_or(if *self < 0 { i32::MIN } else { i32::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI32_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToI32 for $type {
+                #[inline]
+                fn saturating_to_i32(&self) -> i32 {
+                    i32::try_from(*self).unwrap_or(i32::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI32_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToI32 for $type {
+                #[inline]
+                fn saturating_to_i32(&self) -> i32 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { i32::MAX } else { i32::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as i32
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToI32_for_signed_!(i8);
+    implement_SaturatingToI32_for_signed_!(i16);
+    implement_SaturatingToI32_for_signed_!(i32);
+    implement_SaturatingToI32_for_signed_!(i64);
+    implement_SaturatingToI32_for_signed_!(i128);
+    implement_SaturatingToI32_for_signed_!(isize);
+    implement_SaturatingToI32_for_unsigned_!(u8);
+    implement_SaturatingToI32_for_unsigned_!(u16);
+    implement_SaturatingToI32_for_unsigned_!(u32);
+    implement_SaturatingToI32_for_unsigned_!(u64);
+    implement_SaturatingToI32_for_unsigned_!(u128);
+    implement_SaturatingToI32_for_unsigned_!(usize);
+    implement_SaturatingToI32_for_float_!(f32);
+    implement_SaturatingToI32_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToI64-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToI64_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToI64_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToI64 for $type {
+                #[inline]
+                fn saturating_to_i64(&self) -> i64 {
+                    i64::try_from(*self).unwrap_or(if *self < 0 { i64::MIN } else { i64::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI64_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToI64 for $type {
+                #[inline]
+                fn saturating_to_i64(&self) -> i64 {
+                    i64::try_from(*self).unwrap_or(i64::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI64_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToI64 for $type {
+                #[inline]
+                fn saturating_to_i64(&self) -> i64 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { i64::MAX } else { i64::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as i64
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToI64_for_signed_!(i8);
+    implement_SaturatingToI64_for_signed_!(i16);
+    implement_SaturatingToI64_for_signed_!(i32);
+    implement_SaturatingToI64_for_signed_!(i64);
+    implement_SaturatingToI64_for_signed_!(i128);
+    implement_SaturatingToI64_for_signed_!(isize);
+    implement_SaturatingToI64_for_unsigned_!(u8);
+    implement_SaturatingToI64_for_unsigned_!(u16);
+    implement_SaturatingToI64_for_unsigned_!(u32);
+    implement_SaturatingToI64_for_unsigned_!(u64);
+    implement_SaturatingToI64_for_unsigned_!(u128);
+    implement_SaturatingToI64_for_unsigned_!(usize);
+    implement_SaturatingToI64_for_float_!(f32);
+    implement_SaturatingToI64_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToI128-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToI128_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToI128_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToI128 for $type {
+                #[inline]
+                fn saturating_to_i128(&self) -> i128 {
+                    i128::try_from(*self).unwrap_or(if *self < 0 { i128::MIN } else { i128::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI128_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToI128 for $type {
+                #[inline]
+                fn saturating_to_i128(&self) -> i128 {
+                    i128::try_from(*self).unwrap_or(i128::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToI128_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToI128 for $type {
+                #[inline]
+                fn saturating_to_i128(&self) -> i128 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { i128::MAX } else { i128::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as i128
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToI128_for_signed_!(i8);
+    implement_SaturatingToI128_for_signed_!(i16);
+    implement_SaturatingToI128_for_signed_!(i32);
+    implement_SaturatingToI128_for_signed_!(i64);
+    implement_SaturatingToI128_for_signed_!(i128);
+    implement_SaturatingToI128_for_signed_!(isize);
+    implement_SaturatingToI128_for_unsigned_!(u8);
+    implement_SaturatingToI128_for_unsigned_!(u16);
+    implement_SaturatingToI128_for_unsigned_!(u32);
+    implement_SaturatingToI128_for_unsigned_!(u64);
+    implement_SaturatingToI128_for_unsigned_!(u128);
+    implement_SaturatingToI128_for_unsigned_!(usize);
+    implement_SaturatingToI128_for_float_!(f32);
+    implement_SaturatingToI128_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToISize-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToISize_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToISize_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToISize for $type {
+                #[inline]
+                fn saturating_to_isize(&self) -> isize {
+                    isize::try_from(*self).unwrap_or(if *self < 0 { isize::MIN } else { isize::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToISize_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToISize for $type {
+                #[inline]
+                fn saturating_to_isize(&self) -> isize {
+                    isize::try_from(*self).unwrap_or(isize::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToISize_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToISize for $type {
+                #[inline]
+                fn saturating_to_isize(&self) -> isize {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { isize::MAX } else { isize::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as isize
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToISize_for_signed_!(i8);
+    implement_SaturatingToISize_for_signed_!(i16);
+    implement_SaturatingToISize_for_signed_!(i32);
+    implement_SaturatingToISize_for_signed_!(i64);
+    implement_SaturatingToISize_for_signed_!(i128);
+    implement_SaturatingToISize_for_signed_!(isize);
+    implement_SaturatingToISize_for_unsigned_!(u8);
+    implement_SaturatingToISize_for_unsigned_!(u16);
+    implement_SaturatingToISize_for_unsigned_!(u32);
+    implement_SaturatingToISize_for_unsigned_!(u64);
+    implement_SaturatingToISize_for_unsigned_!(u128);
+    implement_SaturatingToISize_for_unsigned_!(usize);
+    implement_SaturatingToISize_for_float_!(f32);
+    implement_SaturatingToISize_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToU8-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToU8_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToU8_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToU8 for $type {
+                #[inline]
+                fn saturating_to_u8(&self) -> u8 {
+                    u8::try_from(*self).unwrap_or(if *self < 0 { u8::MIN } else { u8::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU8_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToU8 for $type {
+                #[inline]
+                fn saturating_to_u8(&self) -> u8 {
+                    u8::try_from(*self).unwrap_or(u8::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU8_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToU8 for $type {
+                #[inline]
+                fn saturating_to_u8(&self) -> u8 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { u8::MAX } else { u8::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as u8
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToU8_for_signed_!(i8);
+    implement_SaturatingToU8_for_signed_!(i16);
+    implement_SaturatingToU8_for_signed_!(i32);
+    implement_SaturatingToU8_for_signed_!(i64);
+    implement_SaturatingToU8_for_signed_!(i128);
+    implement_SaturatingToU8_for_signed_!(isize);
+    implement_SaturatingToU8_for_unsigned_!(u8);
+    implement_SaturatingToU8_for_unsigned_!(u16);
+    implement_SaturatingToU8_for_unsigned_!(u32);
+    implement_SaturatingToU8_for_unsigned_!(u64);
+    implement_SaturatingToU8_for_unsigned_!(u128);
+    implement_SaturatingToU8_for_unsigned_!(usize);
+    implement_SaturatingToU8_for_float_!(f32);
+    implement_SaturatingToU8_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToU16-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToU16_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToU16_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToU16 for $type {
+                #[inline]
+                fn saturating_to_u16(&self) -> u16 {
+                    u16::try_from(*self).unwrap_or(if *self < 0 { u16::MIN } else { u16::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU16_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToU16 for $type {
+                #[inline]
+                fn saturating_to_u16(&self) -> u16 {
+                    u16::try_from(*self).unwrap_or(u16::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU16_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToU16 for $type {
+                #[inline]
+                fn saturating_to_u16(&self) -> u16 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { u16::MAX } else { u16::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as u16
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToU16_for_signed_!(i8);
+    implement_SaturatingToU16_for_signed_!(i16);
+    implement_SaturatingToU16_for_signed_!(i32);
+    implement_SaturatingToU16_for_signed_!(i64);
+    implement_SaturatingToU16_for_signed_!(i128);
+    implement_SaturatingToU16_for_signed_!(isize);
+    implement_SaturatingToU16_for_unsigned_!(u8);
+    implement_SaturatingToU16_for_unsigned_!(u16);
+    implement_SaturatingToU16_for_unsigned_!(u32);
+    implement_SaturatingToU16_for_unsigned_!(u64);
+    implement_SaturatingToU16_for_unsigned_!(u128);
+    implement_SaturatingToU16_for_unsigned_!(usize);
+    implement_SaturatingToU16_for_float_!(f32);
+    implement_SaturatingToU16_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToU32-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToU32_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToU32_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToU32 for $type {
+                #[inline]
+                fn saturating_to_u32(&self) -> u32 {
+                    u32::try_from(*self).unwrap_or(if *self < 0 { u32::MIN } else { u32::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU32_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToU32 for $type {
+                #[inline]
+                fn saturating_to_u32(&self) -> u32 {
+                    u32::try_from(*self).unwrap_or(u32::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU32_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToU32 for $type {
+                #[inline]
+                fn saturating_to_u32(&self) -> u32 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { u32::MAX } else { u32::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as u32
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToU32_for_signed_!(i8);
+    implement_SaturatingToU32_for_signed_!(i16);
+    implement_SaturatingToU32_for_signed_!(i32);
+    implement_SaturatingToU32_for_signed_!(i64);
+    implement_SaturatingToU32_for_signed_!(i128);
+    implement_SaturatingToU32_for_signed_!(isize);
+    implement_SaturatingToU32_for_unsigned_!(u8);
+    implement_SaturatingToU32_for_unsigned_!(u16);
+    implement_SaturatingToU32_for_unsigned_!(u32);
+    implement_SaturatingToU32_for_unsigned_!(u64);
+    implement_SaturatingToU32_for_unsigned_!(u128);
+    implement_SaturatingToU32_for_unsigned_!(usize);
+    implement_SaturatingToU32_for_float_!(f32);
+    implement_SaturatingToU32_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToU64-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToU64_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToU64_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToU64 for $type {
+                #[inline]
+                fn saturating_to_u64(&self) -> u64 {
+                    u64::try_from(*self).unwrap_or(if *self < 0 { u64::MIN } else { u64::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU64_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToU64 for $type {
+                #[inline]
+                fn saturating_to_u64(&self) -> u64 {
+                    u64::try_from(*self).unwrap_or(u64::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU64_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToU64 for $type {
+                #[inline]
+                fn saturating_to_u64(&self) -> u64 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { u64::MAX } else { u64::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as u64
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToU64_for_signed_!(i8);
+    implement_SaturatingToU64_for_signed_!(i16);
+    implement_SaturatingToU64_for_signed_!(i32);
+    implement_SaturatingToU64_for_signed_!(i64);
+    implement_SaturatingToU64_for_signed_!(i128);
+    implement_SaturatingToU64_for_signed_!(isize);
+    implement_SaturatingToU64_for_unsigned_!(u8);
+    implement_SaturatingToU64_for_unsigned_!(u16);
+    implement_SaturatingToU64_for_unsigned_!(u32);
+    implement_SaturatingToU64_for_unsigned_!(u64);
+    implement_SaturatingToU64_for_unsigned_!(u128);
+    implement_SaturatingToU64_for_unsigned_!(usize);
+    implement_SaturatingToU64_for_float_!(f32);
+    implement_SaturatingToU64_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToU128-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToU128_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToU128_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToU128 for $type {
+                #[inline]
+                fn saturating_to_u128(&self) -> u128 {
+                    u128::try_from(*self).unwrap_or(if *self < 0 { u128::MIN } else { u128::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU128_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToU128 for $type {
+                #[inline]
+                fn saturating_to_u128(&self) -> u128 {
+                    u128::try_from(*self).unwrap_or(u128::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToU128_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToU128 for $type {
+                #[inline]
+                fn saturating_to_u128(&self) -> u128 {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { u128::MAX } else { u128::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as u128
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToU128_for_signed_!(i8);
+    implement_SaturatingToU128_for_signed_!(i16);
+    implement_SaturatingToU128_for_signed_!(i32);
+    implement_SaturatingToU128_for_signed_!(i64);
+    implement_SaturatingToU128_for_signed_!(i128);
+    implement_SaturatingToU128_for_signed_!(isize);
+    implement_SaturatingToU128_for_unsigned_!(u8);
+    implement_SaturatingToU128_for_unsigned_!(u16);
+    implement_SaturatingToU128_for_unsigned_!(u32);
+    implement_SaturatingToU128_for_unsigned_!(u64);
+    implement_SaturatingToU128_for_unsigned_!(u128);
+    implement_SaturatingToU128_for_unsigned_!(usize);
+    implement_SaturatingToU128_for_float_!(f32);
+    implement_SaturatingToU128_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-SaturatingToUSize-for-built_ins")]
+#[rustfmt::skip]
+mod impl_SaturatingToUSize_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        IsInfinity,
+        IsNAN,
+    };
+
+
+    macro_rules! implement_SaturatingToUSize_for_signed_ {
+        ($type:tt) => {
+            impl super::SaturatingToUSize for $type {
+                #[inline]
+                fn saturating_to_usize(&self) -> usize {
+                    usize::try_from(*self).unwrap_or(if *self < 0 { usize::MIN } else { usize::MAX })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToUSize_for_unsigned_ {
+        ($type:tt) => {
+            impl super::SaturatingToUSize for $type {
+                #[inline]
+                fn saturating_to_usize(&self) -> usize {
+                    usize::try_from(*self).unwrap_or(usize::MAX)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_SaturatingToUSize_for_float_ {
+        ($type:tt) => {
+            impl super::SaturatingToUSize for $type {
+                #[inline]
+                fn saturating_to_usize(&self) -> usize {
+                    if IsNAN::is_nan(self) {
+                        0
+                    } else if IsInfinity::is_infinity(self) {
+                        if *self > 0.0 { usize::MAX } else { usize::MIN }
+                    } else {
+                        // for a finite value, `as` rounds towards zero and
+                        // saturates at the bounds of the target type
+                        *self as usize
+                    }
+                }
+            }
+        };
+    }
+
+    implement_SaturatingToUSize_for_signed_!(i8);
+    implement_SaturatingToUSize_for_signed_!(i16);
+    implement_SaturatingToUSize_for_signed_!(i32);
+    implement_SaturatingToUSize_for_signed_!(i64);
+    implement_SaturatingToUSize_for_signed_!(i128);
+    implement_SaturatingToUSize_for_signed_!(isize);
+    implement_SaturatingToUSize_for_unsigned_!(u8);
+    implement_SaturatingToUSize_for_unsigned_!(u16);
+    implement_SaturatingToUSize_for_unsigned_!(u32);
+    implement_SaturatingToUSize_for_unsigned_!(u64);
+    implement_SaturatingToUSize_for_unsigned_!(u128);
+    implement_SaturatingToUSize_for_unsigned_!(usize);
+    implement_SaturatingToUSize_for_float_!(f32);
+    implement_SaturatingToUSize_for_float_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use std::rc as std_rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        struct CustomType {
+            value : i32,
+        }
+
+        impl SaturatingToI16 for CustomType {
+            fn saturating_to_i16(&self) -> i16 {
+                self.value.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_VALUES() {
+
+            const VALUES : &[(i32, i16)] = &[
+                // insert list:
+                (0, 0),
+                (1, 1),
+                (-1, -1),
+                (32767, 32767),
+                (32768, 32767),
+                (-32768, -32768),
+                (-32769, -32768),
+                (i32::MAX, i16::MAX),
+                (i32::MIN, i16::MIN),
+            ];
+
+            for &(value, expected) in VALUES {
+                let instance = CustomType { value };
+                let actual = instance.saturating_to_i16();
+
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToI8-for-built_ins")]
+    mod TEST_SaturatingToI8 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(0, 0i8.saturating_to_i8());
+            assert_eq!(127, 127i16.saturating_to_i8());
+            assert_eq!(127, 128i16.saturating_to_i8());
+            assert_eq!(-128, (-129i32).saturating_to_i8());
+            assert_eq!(-128, i128::MIN.saturating_to_i8());
+            assert_eq!(127, u8::MAX.saturating_to_i8());
+            assert_eq!(127, u128::MAX.saturating_to_i8());
+            assert_eq!(100, 100usize.saturating_to_i8());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(1, 1.99f32.saturating_to_i8());
+            assert_eq!(-1, (-1.99f64).saturating_to_i8());
+            assert_eq!(127, 1000.0f64.saturating_to_i8());
+            assert_eq!(-128, (-1000.0f32).saturating_to_i8());
+            assert_eq!(127, f64::INFINITY.saturating_to_i8());
+            assert_eq!(-128, f32::NEG_INFINITY.saturating_to_i8());
+            assert_eq!(0, f64::NAN.saturating_to_i8());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToI16-for-built_ins")]
+    mod TEST_SaturatingToI16 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(-128, i8::MIN.saturating_to_i16());
+            assert_eq!(i16::MAX, u16::MAX.saturating_to_i16());
+            assert_eq!(i16::MIN, i64::MIN.saturating_to_i16());
+            assert_eq!(255, u8::MAX.saturating_to_i16());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(i16::MAX, 40000.0f32.saturating_to_i16());
+            assert_eq!(0, f32::NAN.saturating_to_i16());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToI32-for-built_ins")]
+    mod TEST_SaturatingToI32 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(i32::MAX, u32::MAX.saturating_to_i32());
+            assert_eq!(i32::MIN, i64::MIN.saturating_to_i32());
+            assert_eq!(-5, (-5isize).saturating_to_i32());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(i32::MAX, f32::MAX.saturating_to_i32());
+            assert_eq!(i32::MIN, f64::MIN.saturating_to_i32());
+            assert_eq!(i32::MAX, f64::INFINITY.saturating_to_i32());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToI64-for-built_ins")]
+    mod TEST_SaturatingToI64 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(i64::MAX, u64::MAX.saturating_to_i64());
+            assert_eq!(i64::MIN, i128::MIN.saturating_to_i64());
+            assert_eq!(u32::MAX as i64, u32::MAX.saturating_to_i64());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(i64::MAX, 9_223_372_036_854_775_808.0f64.saturating_to_i64());
+            assert_eq!(i64::MIN, f64::NEG_INFINITY.saturating_to_i64());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToI128-for-built_ins")]
+    mod TEST_SaturatingToI128 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(i128::MAX, u128::MAX.saturating_to_i128());
+            assert_eq!(i64::MIN as i128, i64::MIN.saturating_to_i128());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(i128::MAX, f64::MAX.saturating_to_i128());
+            assert_eq!(0, f64::NAN.saturating_to_i128());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToISize-for-built_ins")]
+    mod TEST_SaturatingToISize {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(isize::MAX, usize::MAX.saturating_to_isize());
+            assert_eq!(isize::MIN, i128::MIN.saturating_to_isize());
+            assert_eq!(-1, (-1i8).saturating_to_isize());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(isize::MAX, f32::INFINITY.saturating_to_isize());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToU8-for-built_ins")]
+    mod TEST_SaturatingToU8 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(0, (-1i8).saturating_to_u8());
+            assert_eq!(0, i128::MIN.saturating_to_u8());
+            assert_eq!(127, i8::MAX.saturating_to_u8());
+            assert_eq!(255, 256i32.saturating_to_u8());
+            assert_eq!(255, u16::MAX.saturating_to_u8());
+            assert_eq!(200, 200u64.saturating_to_u8());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(0, (-0.5f32).saturating_to_u8());
+            assert_eq!(0, (-1000.0f64).saturating_to_u8());
+            assert_eq!(254, 254.9f64.saturating_to_u8());
+            assert_eq!(255, 255.9f64.saturating_to_u8());
+            assert_eq!(255, 256.0f32.saturating_to_u8());
+            assert_eq!(255, f64::INFINITY.saturating_to_u8());
+            assert_eq!(0, f64::NEG_INFINITY.saturating_to_u8());
+            assert_eq!(0, f32::NAN.saturating_to_u8());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToU16-for-built_ins")]
+    mod TEST_SaturatingToU16 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(0, i16::MIN.saturating_to_u16());
+            assert_eq!(u16::MAX, u32::MAX.saturating_to_u16());
+            assert_eq!(1234, 1234i64.saturating_to_u16());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(u16::MAX, 1.0e9f32.saturating_to_u16());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToU32-for-built_ins")]
+    mod TEST_SaturatingToU32 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(0, i32::MIN.saturating_to_u32());
+            assert_eq!(u32::MAX, u64::MAX.saturating_to_u32());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(u32::MAX, 1.0e10f64.saturating_to_u32());
+            assert_eq!(0, f64::NAN.saturating_to_u32());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToU64-for-built_ins")]
+    mod TEST_SaturatingToU64 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(0, i64::MIN.saturating_to_u64());
+            assert_eq!(u64::MAX, u128::MAX.saturating_to_u64());
+            assert_eq!(i64::MAX as u64, i64::MAX.saturating_to_u64());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(u64::MAX, f32::MAX.saturating_to_u64());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToU128-for-built_ins")]
+    mod TEST_SaturatingToU128 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(0, i128::MIN.saturating_to_u128());
+            assert_eq!(i128::MAX as u128, i128::MAX.saturating_to_u128());
+            assert_eq!(u128::MAX, u128::MAX.saturating_to_u128());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(u128::MAX, f64::INFINITY.saturating_to_u128());
+            assert_eq!(0, f64::NEG_INFINITY.saturating_to_u128());
+        }
+    }
+
+
+    #[cfg(feature = "implement-SaturatingToUSize-for-built_ins")]
+    mod TEST_SaturatingToUSize {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(0, isize::MIN.saturating_to_usize());
+            assert_eq!(usize::MAX, u128::MAX.saturating_to_usize());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(usize::MAX, f64::MAX.saturating_to_usize());
+            assert_eq!(3, 3.75f32.saturating_to_usize());
+        }
+    }
+
+
+    #[cfg(all(feature = "implement-SaturatingToI16-for-built_ins", feature = "implement-SaturatingToU8-for-built_ins"))]
+    mod TEST_FORWARDING {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_Box() {
+            assert_eq!(i16::MAX, Box::new(i32::MAX).saturating_to_i16());
+            assert_eq!(0, Box::new(-1.5f64).saturating_to_u8());
+
+            let instance : Box<dyn SaturatingToU8> = Box::new(1000u32);
+
+            assert_eq!(255, instance.saturating_to_u8());
+        }
+
+        #[test]
+        fn TEST_Rc() {
+            assert_eq!(i16::MIN, std_rc::Rc::new(i64::MIN).saturating_to_i16());
+            assert_eq!(255, std_rc::Rc::new(f32::INFINITY).saturating_to_u8());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
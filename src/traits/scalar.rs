@@ -1,9 +1,31 @@
 // src/traits/scalar.rs : `Scalar`
 
-pub trait Scalar {
+/// Marker trait for types whose instances are single, indivisible,
+/// values - such as numbers, characters, and booleans - and that are
+/// therefore cheaply copyable.
+///
+/// It is the root of the hierarchy of marker traits
+/// [`Scalar`] > [`Numeric`](crate::Numeric) >
+/// [`Integer`](crate::Integer) / [`Real`](crate::Real) /
+/// [`Signed`](crate::Signed) / [`Unsigned`](crate::Unsigned).
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-Scalar-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`bool`];
+/// - [`char`];
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait Scalar : Copy {
 }
 
 
+#[cfg(feature = "implement-Scalar-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
 
@@ -33,5 +55,59 @@ mod impl_for_built_ins {
 }
 
 
-// ///////////////////////////// end of file //////////////////////////// //
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::Scalar;
+
+
+    fn duplicate<T : Scalar>(t : T) -> (T, T) {
+        // `Copy` is implied by `Scalar`
+        (t, t)
+    }
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct CustomType {
+            value : u8,
+        }
+
+        impl Scalar for CustomType {}
 
+
+        #[test]
+        fn TEST_duplicate() {
+            let ct = CustomType { value : 1 };
+
+            assert_eq!((ct, ct), duplicate(ct));
+        }
+    }
+
+
+    #[cfg(feature = "implement-Scalar-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_duplicate() {
+            assert_eq!((true, true), duplicate(true));
+            assert_eq!(('a', 'a'), duplicate('a'));
+            assert_eq!((-1i8, -1i8), duplicate(-1i8));
+            assert_eq!((1u128, 1u128), duplicate(1u128));
+            assert_eq!((1.5f32, 1.5f32), duplicate(1.5f32));
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
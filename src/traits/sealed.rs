@@ -1,9 +1,12 @@
 // src/traits/sealed.rs : `Sealed`
 
 /// Trait for implementing **Sealed Trait Pattern**.
-#[cfg_attr(debug_assertions, allow(dead_code))]
-pub(crate) trait Sealed {}
+///
+/// It is declared `pub` - so that it may be used as a supertrait of
+/// public traits - but is not reachable from outside the crate, so that it
+/// (and thereby any trait of which it is a supertrait) may be implemented
+/// only within the crate.
+pub trait Sealed {}
 
 
 // ///////////////////////////// end of file //////////////////////////// //
-
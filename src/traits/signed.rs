@@ -1,9 +1,33 @@
 // src/traits/signed.rs : `Signed`
 
-pub trait Signed {
+use crate::{
+    IsNegative,
+    IsPositive,
+    Numeric,
+    Signum,
+};
+
+use core::ops as core_ops;
+
+
+/// Marker trait for signed numeric types, which, in addition to the
+/// facilities implied by [`Numeric`], may be negated, may be tested for
+/// being negative or positive, and may have their sign obtained.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-Signed-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`f32`], [`f64`];
+pub trait Signed : Numeric + core_ops::Neg<Output = Self> + IsNegative + IsPositive + Signum {
 }
 
 
+#[cfg(feature = "implement-Signed-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
 
@@ -23,5 +47,81 @@ mod impl_for_built_ins {
 }
 
 
-// ///////////////////////////// end of file //////////////////////////// //
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    /// Uses only the facilities implied by `Signed`.
+    fn magnitude<T : Signed>(t : T) -> T {
+        if t < T::zero() {
+            -t
+        } else {
+            t
+        }
+    }
+
+    /// Uses the sign facilities implied by `Signed`.
+    fn describe<T : Signed>(t : T) -> &'static str {
+        if t.is_negative() {
+            "negative"
+        } else if t.is_positive() {
+            "positive"
+        } else {
+            "zero"
+        }
+    }
+
+    fn requires_Numeric<T : Numeric>(t : T) -> T {
+        t
+    }
+
+    fn Signed_is_Numeric<T : Signed>(t : T) -> T {
+        requires_Numeric(t)
+    }
 
+
+    #[cfg(feature = "implement-Signed-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_magnitude() {
+            assert_eq!(1, magnitude(-1i8));
+            assert_eq!(1, magnitude(1i128));
+            assert_eq!(0, magnitude(0isize));
+            assert_eq!(2.5, magnitude(-2.5f32));
+            assert_eq!(2.5, magnitude(2.5f64));
+        }
+
+        #[test]
+        fn TEST_describe() {
+            assert_eq!("negative", describe(-1i8));
+            assert_eq!("zero", describe(0i64));
+            assert_eq!("positive", describe(1isize));
+            assert_eq!("negative", describe(-0.5f32));
+            assert_eq!("zero", describe(-0.0f64));
+            assert_eq!("positive", describe(f64::INFINITY));
+        }
+
+        #[test]
+        fn TEST_signum() {
+            assert_eq!(-1, Signum::signum(&-5i32));
+            assert_eq!(1.0, Signum::signum(&5.0f64));
+        }
+
+        #[test]
+        fn TEST_Signed_is_Numeric() {
+            assert_eq!(-1, Signed_is_Numeric(-1i16));
+            assert_eq!(-1.0, Signed_is_Numeric(-1.0f64));
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
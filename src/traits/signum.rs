@@ -0,0 +1,362 @@
+// src/traits/signum.rs : `Signum`
+
+/// Trait defining instance method `signum() : Self` that obtains
+/// the sign of the implementing type instance, as an instance of the
+/// same type: `-1` if it is negative; `0` if it is zero; `+1` if it is
+/// positive.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-Signum-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+///
+/// ## Standard Numeric Types
+///
+/// If the feature `"implement-Signum-for-standard_num_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::num::NonZeroI8`], ..., [`core::num::NonZeroI128`],
+///   [`core::num::NonZeroIsize`];
+/// - [`core::num::NonZeroU8`], ..., [`core::num::NonZeroU128`],
+///   [`core::num::NonZeroUsize`];
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-Signum-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`core::time::Duration`];
+///
+/// NOTE: for [`f32`] and [`f64`], zero (of either sign) and "not a
+/// number" yield themselves, whereas the inherent `signum()` methods yield
+/// `1.0` for positive zero and `-1.0` for negative zero.
+///
+/// NOTE: for [`core::time::Duration`], which is never negative, the unit
+/// value is the smallest non-zero duration - one nanosecond - so that a
+/// zero duration yields [`core::time::Duration::ZERO`] and any other
+/// yields `Duration::from_nanos(1)`.
+///
+/// NOTE: since `signum()` yields `Self`, this is implemented for `Box<T>`,
+/// `Rc<T>`, and `Arc<T>` - which can own the resultant value - but not for
+/// `&mut T`, `Cow<'_, T>`, or `Pin<P>`.
+pub trait Signum {
+    fn signum(&self) -> Self;
+}
+
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : Signum> Signum for alloc::boxed::Box<T> {
+    fn signum(&self) -> Self {
+        alloc::boxed::Box::new((**self).signum())
+    }
+}
+
+#[cfg(any(feature = "alloc", not(feature = "nostd")))]
+impl<T : Signum> Signum for alloc::rc::Rc<T> {
+    fn signum(&self) -> Self {
+        alloc::rc::Rc::new((**self).signum())
+    }
+}
+
+#[cfg(all(any(feature = "alloc", not(feature = "nostd")), target_has_atomic = "ptr"))]
+impl<T : Signum> Signum for alloc::sync::Arc<T> {
+    fn signum(&self) -> Self {
+        alloc::sync::Arc::new((**self).signum())
+    }
+}
+
+
+#[cfg(feature = "implement-Signum-for-built_ins")]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+    macro_rules! implement_Signum_for_signed_ {
+        ($type:tt) => {
+            impl super::Signum for $type {
+                #[inline]
+                fn signum(&self) -> Self {
+                    $type::signum(*self)
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_Signum_for_unsigned_ {
+        ($type:tt) => {
+            impl super::Signum for $type {
+                #[inline]
+                fn signum(&self) -> Self {
+                    if 0 == *self {
+                        0
+                    } else {
+                        1
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_Signum_for_float_ {
+        ($type:tt) => {
+            impl super::Signum for $type {
+                #[inline]
+                fn signum(&self) -> Self {
+                    // NOTE: unlike the inherent method, zero (of either sign), like
+                    // "not a number", yields itself
+                    if 0.0 == *self || $type::is_nan(*self) {
+                        *self
+                    } else {
+                        $type::signum(*self)
+                    }
+                }
+            }
+        };
+    }
+
+    implement_Signum_for_signed_!(i8);
+    implement_Signum_for_signed_!(i16);
+    implement_Signum_for_signed_!(i32);
+    implement_Signum_for_signed_!(i64);
+    implement_Signum_for_signed_!(i128);
+    implement_Signum_for_signed_!(isize);
+
+    implement_Signum_for_unsigned_!(u8);
+    implement_Signum_for_unsigned_!(u16);
+    implement_Signum_for_unsigned_!(u32);
+    implement_Signum_for_unsigned_!(u64);
+    implement_Signum_for_unsigned_!(u128);
+    implement_Signum_for_unsigned_!(usize);
+
+    implement_Signum_for_float_!(f32);
+    implement_Signum_for_float_!(f64);
+}
+
+
+#[cfg(feature = "implement-Signum-for-standard_num_types")]
+mod impl_for_std_num_types {
+    #![allow(non_snake_case)]
+
+    use super::Signum;
+
+    use core::num as core_num;
+
+
+    // NonZero*
+
+    macro_rules! implement_Signum_for_signed_NonZero_ {
+        ($type:ident) => {
+            impl Signum for core_num::$type {
+                #[inline]
+                fn signum(&self) -> Self {
+                    match Self::new(self.get().signum()) {
+                        Some(signum) => signum,
+                        None => unreachable!("the signum of a non-zero value cannot be zero"),
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_Signum_for_unsigned_NonZero_ {
+        ($type:ident) => {
+            impl Signum for core_num::$type {
+                #[inline]
+                fn signum(&self) -> Self {
+                    Self::MIN
+                }
+            }
+        };
+    }
+
+    implement_Signum_for_signed_NonZero_!(NonZeroI8);
+    implement_Signum_for_signed_NonZero_!(NonZeroI16);
+    implement_Signum_for_signed_NonZero_!(NonZeroI32);
+    implement_Signum_for_signed_NonZero_!(NonZeroI64);
+    implement_Signum_for_signed_NonZero_!(NonZeroI128);
+    implement_Signum_for_signed_NonZero_!(NonZeroIsize);
+
+    implement_Signum_for_unsigned_NonZero_!(NonZeroU8);
+    implement_Signum_for_unsigned_NonZero_!(NonZeroU16);
+    implement_Signum_for_unsigned_NonZero_!(NonZeroU32);
+    implement_Signum_for_unsigned_NonZero_!(NonZeroU64);
+    implement_Signum_for_unsigned_NonZero_!(NonZeroU128);
+    implement_Signum_for_unsigned_NonZero_!(NonZeroUsize);
+}
+
+
+#[cfg(feature = "implement-Signum-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl super::Signum for core_time::Duration {
+        #[inline]
+        fn signum(&self) -> Self {
+            if self.is_zero() {
+                core_time::Duration::ZERO
+            } else {
+                core_time::Duration::from_nanos(1)
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::Signum;
+
+    use std::{
+        rc::Rc,
+        sync::Arc,
+    };
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        /// A fixed-point type, in which the value is held in hundredths.
+        #[derive(Debug, PartialEq)]
+        struct Price {
+            cents : i64,
+        }
+
+        impl Signum for Price {
+            fn signum(&self) -> Self {
+                Self { cents : 100 * self.cents.signum() }
+            }
+        }
+
+
+        #[test]
+        fn TEST_Signum() {
+            assert_eq!(Price { cents : -100 }, Price { cents : -1234 }.signum());
+            assert_eq!(Price { cents : 0 }, Price { cents : 0 }.signum());
+            assert_eq!(Price { cents : 100 }, Price { cents : 1 }.signum());
+        }
+
+        #[test]
+        fn TEST_Signum_IN_Box_AND_Rc() {
+            fn signum_<T : Signum>(t : &T) -> T {
+                t.signum()
+            }
+
+            assert_eq!(Price { cents : -100 }, *signum_(&Box::new(Price { cents : -1234 })));
+            assert_eq!(Price { cents : 100 }, *signum_(&Rc::new(Price { cents : 1234 })));
+        }
+
+        #[test]
+        fn TEST_Signum_IN_Arc() {
+            fn signum_<T : Signum>(t : &T) -> T {
+                t.signum()
+            }
+
+            assert_eq!(Price { cents : 0 }, *signum_(&Arc::new(Price { cents : 0 })));
+            assert_eq!(Price { cents : -100 }, *signum_(&Arc::new(Price { cents : -1 })));
+        }
+    }
+
+
+    #[cfg(feature = "implement-Signum-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        // NOTE: the fully-qualified form is used throughout, since many of
+        // the built-in types have inherent methods of the same name
+
+
+        #[test]
+        fn TEST_signed_integers() {
+            assert_eq!(-1, Signum::signum(&i8::MIN));
+            assert_eq!(-1, Signum::signum(&-2i16));
+            assert_eq!(0, Signum::signum(&0i32));
+            assert_eq!(1, Signum::signum(&2i64));
+            assert_eq!(1, Signum::signum(&i128::MAX));
+            assert_eq!(-1, Signum::signum(&-1isize));
+        }
+
+        #[test]
+        fn TEST_unsigned_integers() {
+            assert_eq!(0, 0u8.signum());
+            assert_eq!(1, u16::MAX.signum());
+            assert_eq!(1, 2u32.signum());
+            assert_eq!(0, 0u64.signum());
+            assert_eq!(1, 1u128.signum());
+            assert_eq!(1, 3usize.signum());
+        }
+
+        #[test]
+        fn TEST_floats() {
+            assert_eq!(-1.0, Signum::signum(&-2.5f32));
+            assert_eq!(1.0, Signum::signum(&f32::INFINITY));
+            assert_eq!(-1.0, Signum::signum(&f64::NEG_INFINITY));
+            assert_eq!(1.0, Signum::signum(&f64::MIN_POSITIVE));
+
+            assert_eq!(0.0, Signum::signum(&0.0f32));
+            assert!(Signum::signum(&-0.0f64).is_sign_negative());
+            assert_eq!(0.0, Signum::signum(&-0.0f64));
+            assert!(Signum::signum(&f64::NAN).is_nan());
+        }
+    }
+
+
+    #[cfg(feature = "implement-Signum-for-standard_num_types")]
+    mod TEST_STANDARD_NUM_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::num as std_num;
+
+
+        #[test]
+        fn TEST_NonZero() {
+            assert_eq!(-1, Signum::signum(&std_num::NonZeroI8::MIN).get());
+            assert_eq!(1, Signum::signum(&std_num::NonZeroI32::new(42).unwrap()).get());
+            assert_eq!(-1, Signum::signum(&std_num::NonZeroIsize::new(-42).unwrap()).get());
+            assert_eq!(1, std_num::NonZeroU8::MAX.signum().get());
+            assert_eq!(1, std_num::NonZeroUsize::MIN.signum().get());
+        }
+    }
+
+
+    #[cfg(feature = "implement-Signum-for-standard_time_types")]
+    mod TEST_STANDARD_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time as std_time;
+
+
+        #[test]
+        fn TEST_Duration() {
+            assert_eq!(std_time::Duration::ZERO, std_time::Duration::ZERO.signum());
+            assert_eq!(std_time::Duration::from_nanos(1), std_time::Duration::from_nanos(1).signum());
+            assert_eq!(std_time::Duration::from_nanos(1), std_time::Duration::from_secs(60).signum());
+            assert_eq!(std_time::Duration::from_nanos(1), std_time::Duration::MAX.signum());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,279 @@
+// src/traits/to_f32.rs : `ToF32`
+
+/// Trait defining instance method `to_f32() : f32` that provides a
+/// no-cost or low-cost conversion into `f32`.
+///
+/// It is expected that the implementing type "is-a" `f32` in a logical
+/// manner.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-ToF32-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`i8`];
+/// - [`i16`];
+/// - [`u8`];
+/// - [`u16`];
+/// - [`f32`];
+pub trait ToF32 {
+    fn to_f32(&self) -> f32;
+}
+
+
+implement_forwarding_!(ToF32 {
+    fn to_f32(&self) -> f32;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToF32` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
+#[cfg(feature = "implement-ToF32-for-built_ins")]
+#[rustfmt::skip]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    impl super::ToF32 for f32 {
+        #[inline]
+        fn to_f32(&self) -> f32 {
+            *self
+        }
+    }
+
+    macro_rules! implement_ToF32_ {
+        ($type:tt) => {
+            impl super::ToF32 for $type {
+                #[inline]
+                fn to_f32(&self) -> f32 {
+                    *self as f32
+                }
+            }
+        };
+    }
+
+    implement_ToF32_!(i8);
+    implement_ToF32_!(i16);
+    implement_ToF32_!(u8);
+    implement_ToF32_!(u16);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::ToF32;
+
+    use std::rc as std_rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::ToF32;
+
+
+        struct CustomType {
+            value : f32,
+        }
+
+        impl ToF32 for CustomType {
+            fn to_f32(&self) -> f32 {
+                self.value
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_VALUES() {
+
+            const VALUES : &[f32] = &[
+                // insert list:
+                0.0,
+                1.0,
+                -1.0,
+                0.5,
+                1.0e10,
+                f32::MIN,
+                f32::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = CustomType { value };
+                let actual = instance.to_f32();
+
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+
+    #[cfg(feature = "implement-ToF32-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_RANGE_OF_f32_VALUES() {
+
+            const VALUES : &[f32] = &[
+                // insert list:
+                0.0,
+                1.0,
+                -1.0,
+                0.5,
+                1.0e10,
+                f32::MIN,
+                f32::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = value;
+                let actual = instance.to_f32();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_f32_VALUES_REF() {
+
+            const VALUES : &[f32] = &[
+                // insert list:
+                0.0,
+                1.0,
+                -1.0,
+                0.5,
+                1.0e10,
+                f32::MIN,
+                f32::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = value;
+                let instance = &instance;
+                let actual = instance.to_f32();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_f32_VALUES_IN_Box() {
+
+            const VALUES : &[f32] = &[
+                // insert list:
+                0.0,
+                1.0,
+                -1.0,
+                0.5,
+                1.0e10,
+                f32::MIN,
+                f32::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = Box::new(value);
+                let actual = instance.to_f32();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_f32_VALUES_IN_REF_Box() {
+
+            const VALUES : &[f32] = &[
+                // insert list:
+                0.0,
+                1.0,
+                -1.0,
+                0.5,
+                1.0e10,
+                f32::MIN,
+                f32::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = Box::new(value);
+                let instance = &instance;
+                let actual = instance.to_f32();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_f32_VALUES_IN_Rc() {
+
+            const VALUES : &[f32] = &[
+                // insert list:
+                0.0,
+                1.0,
+                -1.0,
+                0.5,
+                1.0e10,
+                f32::MIN,
+                f32::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = std_rc::Rc::new(value);
+                let actual = instance.to_f32();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_f32_VALUES_IN_REF_Rc() {
+
+            const VALUES : &[f32] = &[
+                // insert list:
+                0.0,
+                1.0,
+                -1.0,
+                0.5,
+                1.0e10,
+                f32::MIN,
+                f32::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = std_rc::Rc::new(value);
+                let instance = &instance;
+                let actual = instance.to_f32();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_OTHER_TYPES() {
+            assert_eq!(-128.0, i8::MIN.to_f32());
+            assert_eq!(-32768.0, i16::MIN.to_f32());
+            assert_eq!(32767.0, i16::MAX.to_f32());
+            assert_eq!(255.0, u8::MAX.to_f32());
+            assert_eq!(65535.0, u16::MAX.to_f32());
+
+            assert!(f32::NAN.to_f32().is_nan());
+            assert_eq!(f32::INFINITY, f32::INFINITY.to_f32());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
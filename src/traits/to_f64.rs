@@ -32,21 +32,14 @@ pub trait ToF64 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToF64 + ?Sized> ToF64 for Box<T> {
-    fn to_f64(&self) -> f64 {
-        (**self).to_f64()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToF64 + ?Sized> ToF64 for std::rc::Rc<T> {
-    fn to_f64(&self) -> f64 {
-        (**self).to_f64()
-    }
-}
+implement_forwarding_!(ToF64 {
+    fn to_f64(&self) -> f64;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToF64` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToF64-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
@@ -27,21 +27,14 @@ pub trait ToI128 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToI128 + ?Sized> ToI128 for Box<T> {
-    fn to_i128(&self) -> i128 {
-        (**self).to_i128()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToI128 + ?Sized> ToI128 for std::rc::Rc<T> {
-    fn to_i128(&self) -> i128 {
-        (**self).to_i128()
-    }
-}
+implement_forwarding_!(ToI128 {
+    fn to_i128(&self) -> i128;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToI128` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToI128-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -179,7 +172,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as i128;
-                let actual = (&value).to_i128();
+                let instance = &value;
+                let actual = instance.to_i128();
 
                 assert_eq!(expected, actual);
             }
@@ -205,7 +199,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as i128;
-                let actual = (&value).to_i128();
+                let instance = &value;
+                let actual = instance.to_i128();
 
                 assert_eq!(expected, actual);
             }
@@ -231,7 +226,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as i128;
-                let actual = (&value).to_i128();
+                let instance = &value;
+                let actual = instance.to_i128();
 
                 assert_eq!(expected, actual);
             }
@@ -257,7 +253,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).to_i128();
+                let instance = &value;
+                let actual = instance.to_i128();
 
                 assert_eq!(expected, actual);
             }
@@ -311,7 +308,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_i128();
+                let instance = &instance;
+                let actual = instance.to_i128();
 
                 assert_eq!(expected, actual);
             }
@@ -365,7 +363,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_i128();
+                let instance = &instance;
+                let actual = instance.to_i128();
 
                 assert_eq!(expected, actual);
             }
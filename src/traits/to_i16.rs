@@ -21,21 +21,14 @@ pub trait ToI16 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToI16 + ?Sized> ToI16 for Box<T> {
-    fn to_i16(&self) -> i16 {
-        (**self).to_i16()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToI16 + ?Sized> ToI16 for std::rc::Rc<T> {
-    fn to_i16(&self) -> i16 {
-        (**self).to_i16()
-    }
-}
+implement_forwarding_!(ToI16 {
+    fn to_i16(&self) -> i16;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToI16` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToI16-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -86,7 +79,7 @@ mod tests {
 
         impl ToI16 for CustomType {
             fn to_i16(&self) -> i16 {
-                self.value as i16
+                self.value
             }
         }
 
@@ -102,7 +95,7 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let instance = CustomType { value: value as i16 };
+                let instance = CustomType { value };
                 let actual = instance.to_i16();
 
                 assert_eq!(expected, actual);
@@ -163,8 +156,9 @@ mod tests {
             ];
 
             for &value in VALUES {
-                let expected = value as i16;
-                let actual = (&value).to_i16();
+                let expected = value;
+                let instance = &value;
+                let actual = instance.to_i16();
 
                 assert_eq!(expected, actual);
             }
@@ -218,7 +212,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_i16();
+                let instance = &instance;
+                let actual = instance.to_i16();
 
                 assert_eq!(expected, actual);
             }
@@ -272,7 +267,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_i16();
+                let instance = &instance;
+                let actual = instance.to_i16();
 
                 assert_eq!(expected, actual);
             }
@@ -23,21 +23,14 @@ pub trait ToI32 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToI32 + ?Sized> ToI32 for Box<T> {
-    fn to_i32(&self) -> i32 {
-        (**self).to_i32()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToI32 + ?Sized> ToI32 for std::rc::Rc<T> {
-    fn to_i32(&self) -> i32 {
-        (**self).to_i32()
-    }
-}
+implement_forwarding_!(ToI32 {
+    fn to_i32(&self) -> i32;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToI32` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToI32-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -169,7 +162,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as i32;
-                let actual = (&value).to_i32();
+                let instance = &value;
+                let actual = instance.to_i32();
 
                 assert_eq!(expected, actual);
             }
@@ -195,7 +189,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as i32;
-                let actual = (&value).to_i32();
+                let instance = &value;
+                let actual = instance.to_i32();
 
                 assert_eq!(expected, actual);
             }
@@ -221,7 +216,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).to_i32();
+                let instance = &value;
+                let actual = instance.to_i32();
 
                 assert_eq!(expected, actual);
             }
@@ -275,7 +271,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_i32();
+                let instance = &instance;
+                let actual = instance.to_i32();
 
                 assert_eq!(expected, actual);
             }
@@ -329,7 +326,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_i32();
+                let instance = &instance;
+                let actual = instance.to_i32();
 
                 assert_eq!(expected, actual);
             }
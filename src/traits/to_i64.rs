@@ -25,21 +25,14 @@ pub trait ToI64 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToI64 + ?Sized> ToI64 for Box<T> {
-    fn to_i64(&self) -> i64 {
-        (**self).to_i64()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToI64 + ?Sized> ToI64 for std::rc::Rc<T> {
-    fn to_i64(&self) -> i64 {
-        (**self).to_i64()
-    }
-}
+implement_forwarding_!(ToI64 {
+    fn to_i64(&self) -> i64;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToI64` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToI64-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -94,7 +87,7 @@ mod tests {
 
         impl ToI64 for CustomType {
             fn to_i64(&self) -> i64 {
-                self.value as i64
+                self.value
             }
         }
 
@@ -111,7 +104,7 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let instance = CustomType { value: value as i64 };
+                let instance = CustomType { value };
                 let actual = instance.to_i64();
 
                 assert_eq!(expected, actual);
@@ -173,7 +166,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as i64;
-                let actual = (&value).to_i64();
+                let instance = &value;
+                let actual = instance.to_i64();
 
                 assert_eq!(expected, actual);
             }
@@ -199,7 +193,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as i64;
-                let actual = (&value).to_i64();
+                let instance = &value;
+                let actual = instance.to_i64();
 
                 assert_eq!(expected, actual);
             }
@@ -225,7 +220,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as i64;
-                let actual = (&value).to_i64();
+                let instance = &value;
+                let actual = instance.to_i64();
 
                 assert_eq!(expected, actual);
             }
@@ -251,7 +247,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).to_i64();
+                let instance = &value;
+                let actual = instance.to_i64();
 
                 assert_eq!(expected, actual);
             }
@@ -305,7 +302,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_i64();
+                let instance = &instance;
+                let actual = instance.to_i64();
 
                 assert_eq!(expected, actual);
             }
@@ -359,7 +357,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_i64();
+                let instance = &instance;
+                let actual = instance.to_i64();
 
                 assert_eq!(expected, actual);
             }
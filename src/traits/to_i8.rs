@@ -0,0 +1,261 @@
+// src/traits/to_i8.rs : `ToI8`
+
+/// Trait defining instance method `to_i8() : i8` that provides a
+/// no-cost or low-cost conversion into `i8`.
+///
+/// It is expected that the implementing type "is-a" `i8` in a logical
+/// manner.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-ToI8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`i8`];
+pub trait ToI8 {
+    fn to_i8(&self) -> i8;
+}
+
+
+implement_forwarding_!(ToI8 {
+    fn to_i8(&self) -> i8;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToI8` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
+#[cfg(feature = "implement-ToI8-for-built_ins")]
+#[rustfmt::skip]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    impl super::ToI8 for i8 {
+        #[inline]
+        fn to_i8(&self) -> i8 {
+            *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::ToI8;
+
+    use std::rc as std_rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::ToI8;
+
+
+        struct CustomType {
+            value : i8,
+        }
+
+        impl ToI8 for CustomType {
+            fn to_i8(&self) -> i8 {
+                self.value
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_VALUES() {
+
+            const VALUES : &[i8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                -1,
+                -128,
+                i8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = CustomType { value };
+                let actual = instance.to_i8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+
+    #[cfg(feature = "implement-ToI8-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_RANGE_OF_i8_VALUES() {
+
+            const VALUES : &[i8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                -1,
+                -128,
+                i8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = value;
+                let actual = instance.to_i8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_i8_VALUES_REF() {
+
+            const VALUES : &[i8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                -1,
+                -128,
+                i8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = value;
+                let instance = &instance;
+                let actual = instance.to_i8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_i8_VALUES_IN_Box() {
+
+            const VALUES : &[i8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                -1,
+                -128,
+                i8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = Box::new(value);
+                let actual = instance.to_i8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_i8_VALUES_IN_REF_Box() {
+
+            const VALUES : &[i8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                -1,
+                -128,
+                i8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = Box::new(value);
+                let instance = &instance;
+                let actual = instance.to_i8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_i8_VALUES_IN_Rc() {
+
+            const VALUES : &[i8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                -1,
+                -128,
+                i8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = std_rc::Rc::new(value);
+                let actual = instance.to_i8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_i8_VALUES_IN_REF_Rc() {
+
+            const VALUES : &[i8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                -1,
+                -128,
+                i8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = std_rc::Rc::new(value);
+                let instance = &instance;
+                let actual = instance.to_i8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
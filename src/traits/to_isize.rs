@@ -28,21 +28,14 @@ pub trait ToISize {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToISize + ?Sized> ToISize for Box<T> {
-    fn to_isize(&self) -> isize {
-        (**self).to_isize()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToISize + ?Sized> ToISize for std::rc::Rc<T> {
-    fn to_isize(&self) -> isize {
-        (**self).to_isize()
-    }
-}
+implement_forwarding_!(ToISize {
+    fn to_isize(&self) -> isize;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToISize` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToISize-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -102,9 +95,7 @@ mod impl_for_built_ins {
         implement_ToISize_!(i64);
     }
 
-    #[cfg(any(
-        target_pointer_width = "128",
-    ))]
+    #[cfg(target_pointer_width = "128")]
     mod bits_128plus {
         implement_ToISize_!(u64);
         implement_ToISize_!(i128);
@@ -220,7 +211,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as isize;
-                let actual = (&value).to_isize();
+                let instance = &value;
+                let actual = instance.to_isize();
 
                 assert_eq!(expected, actual);
             }
@@ -252,7 +244,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as isize;
-                let actual = (&value).to_isize();
+                let instance = &value;
+                let actual = instance.to_isize();
 
                 assert_eq!(expected, actual);
             }
@@ -283,7 +276,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as isize;
-                let actual = (&value).to_isize();
+                let instance = &value;
+                let actual = instance.to_isize();
 
                 assert_eq!(expected, actual);
             }
@@ -309,7 +303,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).to_isize();
+                let instance = &value;
+                let actual = instance.to_isize();
 
                 assert_eq!(expected, actual);
             }
@@ -363,7 +358,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_isize();
+                let instance = &instance;
+                let actual = instance.to_isize();
 
                 assert_eq!(expected, actual);
             }
@@ -417,7 +413,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_isize();
+                let instance = &instance;
+                let actual = instance.to_isize();
 
                 assert_eq!(expected, actual);
             }
@@ -23,21 +23,14 @@ pub trait ToU128 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToU128 + ?Sized> ToU128 for Box<T> {
-    fn to_u128(&self) -> u128 {
-        (**self).to_u128()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToU128 + ?Sized> ToU128 for std::rc::Rc<T> {
-    fn to_u128(&self) -> u128 {
-        (**self).to_u128()
-    }
-}
+implement_forwarding_!(ToU128 {
+    fn to_u128(&self) -> u128;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToU128` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToU128-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -90,7 +83,7 @@ mod tests {
 
         impl ToU128 for CustomType {
             fn to_u128(&self) -> u128 {
-                self.value as u128
+                self.value
             }
         }
 
@@ -107,7 +100,7 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let instance = CustomType { value: value as u128 };
+                let instance = CustomType { value };
                 let actual = instance.to_u128();
 
                 assert_eq!(expected, actual);
@@ -169,7 +162,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as u128;
-                let actual = (&value).to_u128();
+                let instance = &value;
+                let actual = instance.to_u128();
 
                 assert_eq!(expected, actual);
             }
@@ -195,7 +189,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as u128;
-                let actual = (&value).to_u128();
+                let instance = &value;
+                let actual = instance.to_u128();
 
                 assert_eq!(expected, actual);
             }
@@ -221,7 +216,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).to_u128();
+                let instance = &value;
+                let actual = instance.to_u128();
 
                 assert_eq!(expected, actual);
             }
@@ -275,7 +271,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_u128();
+                let instance = &instance;
+                let actual = instance.to_u128();
 
                 assert_eq!(expected, actual);
             }
@@ -329,7 +326,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_u128();
+                let instance = &instance;
+                let actual = instance.to_u128();
 
                 assert_eq!(expected, actual);
             }
@@ -20,21 +20,14 @@ pub trait ToU16 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToU16 + ?Sized> ToU16 for Box<T> {
-    fn to_u16(&self) -> u16 {
-        (**self).to_u16()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToU16 + ?Sized> ToU16 for std::rc::Rc<T> {
-    fn to_u16(&self) -> u16 {
-        (**self).to_u16()
-    }
-}
+implement_forwarding_!(ToU16 {
+    fn to_u16(&self) -> u16;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToU16` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToU16-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -84,7 +77,7 @@ mod tests {
 
         impl ToU16 for CustomType {
             fn to_u16(&self) -> u16 {
-                self.value as u16
+                self.value
             }
         }
 
@@ -96,12 +89,12 @@ mod tests {
                 0,
                 1,
                 2, 4, 8, 16, 16, 16, 16, 256,
-                u16::MAX as u16,
+                u16::MAX,
             ];
 
             for &value in VALUES {
                 let expected = value;
-                let instance = CustomType { value: value as u16 };
+                let instance = CustomType { value };
                 let actual = instance.to_u16();
 
                 assert_eq!(expected, actual);
@@ -162,8 +155,9 @@ mod tests {
             ];
 
             for &value in VALUES {
-                let expected = value as u16;
-                let actual = (&value).to_u16();
+                let expected = value;
+                let instance = &value;
+                let actual = instance.to_u16();
 
                 assert_eq!(expected, actual);
             }
@@ -188,7 +182,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as u16;
-                let actual = (&value).to_u16();
+                let instance = &value;
+                let actual = instance.to_u16();
 
                 assert_eq!(expected, actual);
             }
@@ -242,7 +237,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_u16();
+                let instance = &instance;
+                let actual = instance.to_u16();
 
                 assert_eq!(expected, actual);
             }
@@ -296,7 +292,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_u16();
+                let instance = &instance;
+                let actual = instance.to_u16();
 
                 assert_eq!(expected, actual);
             }
@@ -21,21 +21,14 @@ pub trait ToU32 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToU32 + ?Sized> ToU32 for Box<T> {
-    fn to_u32(&self) -> u32 {
-        (**self).to_u32()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToU32 + ?Sized> ToU32 for std::rc::Rc<T> {
-    fn to_u32(&self) -> u32 {
-        (**self).to_u32()
-    }
-}
+implement_forwarding_!(ToU32 {
+    fn to_u32(&self) -> u32;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToU32` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToU32-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -86,7 +79,7 @@ mod tests {
 
         impl ToU32 for CustomType {
             fn to_u32(&self) -> u32 {
-                self.value as u32
+                self.value
             }
         }
 
@@ -99,12 +92,12 @@ mod tests {
                 1,
                 2, 4, 8, 16, 32, 32, 32, 256,
                 u16::MAX as u32,
-                u32::MAX as u32,
+                u32::MAX,
             ];
 
             for &value in VALUES {
                 let expected = value;
-                let instance = CustomType { value: value as u32 };
+                let instance = CustomType { value };
                 let actual = instance.to_u32();
 
                 assert_eq!(expected, actual);
@@ -166,7 +159,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as u32;
-                let actual = (&value).to_u32();
+                let instance = &value;
+                let actual = instance.to_u32();
 
                 assert_eq!(expected, actual);
             }
@@ -192,7 +186,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).to_u32();
+                let instance = &value;
+                let actual = instance.to_u32();
 
                 assert_eq!(expected, actual);
             }
@@ -246,7 +241,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_u32();
+                let instance = &instance;
+                let actual = instance.to_u32();
 
                 assert_eq!(expected, actual);
             }
@@ -300,7 +296,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_u32();
+                let instance = &instance;
+                let actual = instance.to_u32();
 
                 assert_eq!(expected, actual);
             }
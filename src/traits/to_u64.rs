@@ -22,21 +22,14 @@ pub trait ToU64 {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToU64 + ?Sized> ToU64 for Box<T> {
-    fn to_u64(&self) -> u64 {
-        (**self).to_u64()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToU64 + ?Sized> ToU64 for std::rc::Rc<T> {
-    fn to_u64(&self) -> u64 {
-        (**self).to_u64()
-    }
-}
+implement_forwarding_!(ToU64 {
+    fn to_u64(&self) -> u64;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToU64` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToU64-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -88,7 +81,7 @@ mod tests {
 
         impl ToU64 for CustomType {
             fn to_u64(&self) -> u64 {
-                self.value as u64
+                self.value
             }
         }
 
@@ -102,12 +95,12 @@ mod tests {
                 2, 4, 8, 16, 32, 64, 64, 256,
                 u16::MAX as u64,
                 u32::MAX as u64,
-                u64::MAX as u64,
+                u64::MAX,
             ];
 
             for &value in VALUES {
                 let expected = value;
-                let instance = CustomType { value: value as u64 };
+                let instance = CustomType { value };
                 let actual = instance.to_u64();
 
                 assert_eq!(expected, actual);
@@ -169,7 +162,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as u64;
-                let actual = (&value).to_u64();
+                let instance = &value;
+                let actual = instance.to_u64();
 
                 assert_eq!(expected, actual);
             }
@@ -195,7 +189,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as u64;
-                let actual = (&value).to_u64();
+                let instance = &value;
+                let actual = instance.to_u64();
 
                 assert_eq!(expected, actual);
             }
@@ -221,7 +216,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).to_u64();
+                let instance = &value;
+                let actual = instance.to_u64();
 
                 assert_eq!(expected, actual);
             }
@@ -275,7 +271,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_u64();
+                let instance = &instance;
+                let actual = instance.to_u64();
 
                 assert_eq!(expected, actual);
             }
@@ -329,7 +326,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_u64();
+                let instance = &instance;
+                let actual = instance.to_u64();
 
                 assert_eq!(expected, actual);
             }
@@ -0,0 +1,254 @@
+// src/traits/to_u8.rs : `ToU8`
+
+/// Trait defining instance method `to_u8() : u8` that provides a
+/// no-cost or low-cost conversion into `u8`.
+///
+/// It is expected that the implementing type "is-a" `u8` in a logical
+/// manner.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-ToU8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`u8`];
+pub trait ToU8 {
+    fn to_u8(&self) -> u8;
+}
+
+
+implement_forwarding_!(ToU8 {
+    fn to_u8(&self) -> u8;
+});
+
+
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToU8` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
+#[cfg(feature = "implement-ToU8-for-built_ins")]
+#[rustfmt::skip]
+mod impl_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    impl super::ToU8 for u8 {
+        #[inline]
+        fn to_u8(&self) -> u8 {
+            *self
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::ToU8;
+
+    use std::rc as std_rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::ToU8;
+
+
+        struct CustomType {
+            value : u8,
+        }
+
+        impl ToU8 for CustomType {
+            fn to_u8(&self) -> u8 {
+                self.value
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_VALUES() {
+
+            const VALUES : &[u8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                128,
+                u8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = CustomType { value };
+                let actual = instance.to_u8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+
+    #[cfg(feature = "implement-ToU8-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_RANGE_OF_u8_VALUES() {
+
+            const VALUES : &[u8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                128,
+                u8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = value;
+                let actual = instance.to_u8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_u8_VALUES_REF() {
+
+            const VALUES : &[u8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                128,
+                u8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = value;
+                let instance = &instance;
+                let actual = instance.to_u8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_u8_VALUES_IN_Box() {
+
+            const VALUES : &[u8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                128,
+                u8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = Box::new(value);
+                let actual = instance.to_u8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_u8_VALUES_IN_REF_Box() {
+
+            const VALUES : &[u8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                128,
+                u8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = Box::new(value);
+                let instance = &instance;
+                let actual = instance.to_u8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_u8_VALUES_IN_Rc() {
+
+            const VALUES : &[u8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                128,
+                u8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = std_rc::Rc::new(value);
+                let actual = instance.to_u8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_u8_VALUES_IN_REF_Rc() {
+
+            const VALUES : &[u8] = &[
+                // insert list:
+                0,
+                1,
+                2,
+                4,
+                8,
+                16,
+                128,
+                u8::MAX,
+            ];
+
+            for &value in VALUES {
+                let expected = value;
+                let instance = std_rc::Rc::new(value);
+                let instance = &instance;
+                let actual = instance.to_u8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
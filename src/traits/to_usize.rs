@@ -24,21 +24,14 @@ pub trait ToUSize {
 }
 
 
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToUSize + ?Sized> ToUSize for Box<T> {
-    fn to_usize(&self) -> usize {
-        (**self).to_usize()
-    }
-}
-
-#[cfg(all(not(test), not(feature = "nostd")))]
-impl<T : ToUSize + ?Sized> ToUSize for std::rc::Rc<T> {
-    fn to_usize(&self) -> usize {
-        (**self).to_usize()
-    }
-}
+implement_forwarding_!(ToUSize {
+    fn to_usize(&self) -> usize;
+});
 
 
+// NOTE: keep the types for which this is implemented in sync with the
+// `ToUSize` entry of the `TO` table in base-traits-derive/src/conversions.rs
+// (as verified by tests/derive_conversions.rs)
 #[cfg(feature = "implement-ToUSize-for-built_ins")]
 #[rustfmt::skip]
 mod impl_for_built_ins {
@@ -87,9 +80,7 @@ mod impl_for_built_ins {
     ))]
     implement_ToUSize_!(u64);
 
-    #[cfg(any(
-        target_pointer_width = "128",
-    ))]
+    #[cfg(target_pointer_width = "128")]
     implement_ToUSize_!(u128);
 }
 
@@ -203,7 +194,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as usize;
-                let actual = (&value).to_usize();
+                let instance = &value;
+                let actual = instance.to_usize();
 
                 assert_eq!(expected, actual);
             }
@@ -234,7 +226,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value as usize;
-                let actual = (&value).to_usize();
+                let instance = &value;
+                let actual = instance.to_usize();
 
                 assert_eq!(expected, actual);
             }
@@ -260,7 +253,8 @@ mod tests {
 
             for &value in VALUES {
                 let expected = value;
-                let actual = (&value).to_usize();
+                let instance = &value;
+                let actual = instance.to_usize();
 
                 assert_eq!(expected, actual);
             }
@@ -314,7 +308,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = Box::new(value);
-                let actual = (&instance).to_usize();
+                let instance = &instance;
+                let actual = instance.to_usize();
 
                 assert_eq!(expected, actual);
             }
@@ -368,7 +363,8 @@ mod tests {
             for &value in VALUES {
                 let expected = value;
                 let instance = std_rc::Rc::new(value);
-                let actual = (&instance).to_usize();
+                let instance = &instance;
+                let actual = instance.to_usize();
 
                 assert_eq!(expected, actual);
             }
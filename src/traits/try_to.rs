@@ -0,0 +1,1655 @@
+// src/traits/try_to.rs : `TryToI8`, `TryToI16`, `TryToI32`, `TryToI64`, `TryToI128`, `TryToISize`, `TryToU8`, `TryToU16`, `TryToU32`, `TryToU64`, `TryToU128`, `TryToUSize`
+
+use crate::ConversionError;
+
+
+/// Trait defining instance method `try_to_i8() : Result<i8, ConversionError>`
+/// that provides a checked conversion into `i8`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `i8`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToI8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToI8 {
+    fn try_to_i8(&self) -> Result<i8, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_i16() : Result<i16, ConversionError>`
+/// that provides a checked conversion into `i16`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `i16`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToI16-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToI16 {
+    fn try_to_i16(&self) -> Result<i16, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_i32() : Result<i32, ConversionError>`
+/// that provides a checked conversion into `i32`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `i32`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToI32-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToI32 {
+    fn try_to_i32(&self) -> Result<i32, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_i64() : Result<i64, ConversionError>`
+/// that provides a checked conversion into `i64`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `i64`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToI64-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToI64 {
+    fn try_to_i64(&self) -> Result<i64, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_i128() : Result<i128, ConversionError>`
+/// that provides a checked conversion into `i128`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `i128`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToI128-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToI128 {
+    fn try_to_i128(&self) -> Result<i128, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_isize() : Result<isize, ConversionError>`
+/// that provides a checked conversion into `isize`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `isize`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToISize-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToISize {
+    fn try_to_isize(&self) -> Result<isize, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_u8() : Result<u8, ConversionError>`
+/// that provides a checked conversion into `u8`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `u8`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToU8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToU8 {
+    fn try_to_u8(&self) -> Result<u8, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_u16() : Result<u16, ConversionError>`
+/// that provides a checked conversion into `u16`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `u16`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToU16-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToU16 {
+    fn try_to_u16(&self) -> Result<u16, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_u32() : Result<u32, ConversionError>`
+/// that provides a checked conversion into `u32`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `u32`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToU32-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToU32 {
+    fn try_to_u32(&self) -> Result<u32, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_u64() : Result<u64, ConversionError>`
+/// that provides a checked conversion into `u64`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `u64`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToU64-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToU64 {
+    fn try_to_u64(&self) -> Result<u64, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_u128() : Result<u128, ConversionError>`
+/// that provides a checked conversion into `u128`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `u128`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToU128-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToU128 {
+    fn try_to_u128(&self) -> Result<u128, ConversionError>;
+}
+
+/// Trait defining instance method `try_to_usize() : Result<usize, ConversionError>`
+/// that provides a checked conversion into `usize`.
+///
+/// The conversion succeeds only if the value can be represented exactly
+/// in `usize`; otherwise the [`ConversionError`] describes - by its
+/// [`kind()`](ConversionError::kind) - whether the value is too large,
+/// too small, not a number, or has a fractional part.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-TryToUSize-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait TryToUSize {
+    fn try_to_usize(&self) -> Result<usize, ConversionError>;
+}
+
+
+implement_forwarding_!(TryToI8 {
+    fn try_to_i8(&self) -> Result<i8, ConversionError>;
+});
+
+implement_forwarding_!(TryToI16 {
+    fn try_to_i16(&self) -> Result<i16, ConversionError>;
+});
+
+implement_forwarding_!(TryToI32 {
+    fn try_to_i32(&self) -> Result<i32, ConversionError>;
+});
+
+implement_forwarding_!(TryToI64 {
+    fn try_to_i64(&self) -> Result<i64, ConversionError>;
+});
+
+implement_forwarding_!(TryToI128 {
+    fn try_to_i128(&self) -> Result<i128, ConversionError>;
+});
+
+implement_forwarding_!(TryToISize {
+    fn try_to_isize(&self) -> Result<isize, ConversionError>;
+});
+
+implement_forwarding_!(TryToU8 {
+    fn try_to_u8(&self) -> Result<u8, ConversionError>;
+});
+
+implement_forwarding_!(TryToU16 {
+    fn try_to_u16(&self) -> Result<u16, ConversionError>;
+});
+
+implement_forwarding_!(TryToU32 {
+    fn try_to_u32(&self) -> Result<u32, ConversionError>;
+});
+
+implement_forwarding_!(TryToU64 {
+    fn try_to_u64(&self) -> Result<u64, ConversionError>;
+});
+
+implement_forwarding_!(TryToU128 {
+    fn try_to_u128(&self) -> Result<u128, ConversionError>;
+});
+
+implement_forwarding_!(TryToUSize {
+    fn try_to_usize(&self) -> Result<usize, ConversionError>;
+});
+
+
+#[allow(dead_code)]
+mod isolate_ {
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    /// Determines whether the value `v` may be converted exactly into an
+    /// integer type whose range is `[min, upper)`.
+    ///
+    /// The range of the integral part of `v` is checked before the
+    /// presence of a fractional part, so that, say, `300.5` is reported as
+    /// overflowing `u8` while `-0.5` is reported as fractional.
+    pub(super) fn check_float_(
+        v : f64,
+        min : f64,
+        upper : f64,
+    ) -> Result<(), ConversionError> {
+        if v.is_nan() {
+            return Err(ConversionErrorKind::NaN.into());
+        }
+
+        let integral = trunc_(v);
+
+        if integral >= upper {
+            return Err(ConversionErrorKind::Overflow.into());
+        }
+
+        if integral < min {
+            return Err(ConversionErrorKind::Underflow.into());
+        }
+
+        if integral != v {
+            return Err(ConversionErrorKind::Fractional.into());
+        }
+
+        Ok(())
+    }
+
+    /// Obtains the integral part of `v` without recourse to `std`.
+    fn trunc_(v : f64) -> f64 {
+        // every `f64` of magnitude 2^52 or more (including the infinities)
+        // is integral, and every one of lesser magnitude may be truncated
+        // exactly via `i64`
+        const LIMIT : f64 = 4_503_599_627_370_496.0;
+
+        if v > -LIMIT && v < LIMIT {
+            (v as i64) as f64
+        } else {
+            v
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-TryToI8-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToI8_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToI8_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToI8 for $type {
+                #[inline]
+                fn try_to_i8(&self) -> Result<i8, ConversionError> {
+                    i8::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI8_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToI8 for $type {
+                #[inline]
+                fn try_to_i8(&self) -> Result<i8, ConversionError> {
+                    i8::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI8_for_float_ {
+        ($type:tt) => {
+            impl super::TryToI8 for $type {
+                #[inline]
+                fn try_to_i8(&self) -> Result<i8, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, i8::MIN as f64, i8::MAX as f64 + 1.0)?;
+
+                    Ok(v as i8)
+                }
+            }
+        };
+    }
+
+    implement_TryToI8_for_signed_!(i8);
+    implement_TryToI8_for_signed_!(i16);
+    implement_TryToI8_for_signed_!(i32);
+    implement_TryToI8_for_signed_!(i64);
+    implement_TryToI8_for_signed_!(i128);
+    implement_TryToI8_for_signed_!(isize);
+    implement_TryToI8_for_unsigned_!(u8);
+    implement_TryToI8_for_unsigned_!(u16);
+    implement_TryToI8_for_unsigned_!(u32);
+    implement_TryToI8_for_unsigned_!(u64);
+    implement_TryToI8_for_unsigned_!(u128);
+    implement_TryToI8_for_unsigned_!(usize);
+    implement_TryToI8_for_float_!(f32);
+    implement_TryToI8_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToI16-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToI16_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToI16_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToI16 for $type {
+                #[inline]
+                fn try_to_i16(&self) -> Result<i16, ConversionError> {
+                    i16::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI16_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToI16 for $type {
+                #[inline]
+                fn try_to_i16(&self) -> Result<i16, ConversionError> {
+                    i16::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI16_for_float_ {
+        ($type:tt) => {
+            impl super::TryToI16 for $type {
+                #[inline]
+                fn try_to_i16(&self) -> Result<i16, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, i16::MIN as f64, i16::MAX as f64 + 1.0)?;
+
+                    Ok(v as i16)
+                }
+            }
+        };
+    }
+
+    implement_TryToI16_for_signed_!(i8);
+    implement_TryToI16_for_signed_!(i16);
+    implement_TryToI16_for_signed_!(i32);
+    implement_TryToI16_for_signed_!(i64);
+    implement_TryToI16_for_signed_!(i128);
+    implement_TryToI16_for_signed_!(isize);
+    implement_TryToI16_for_unsigned_!(u8);
+    implement_TryToI16_for_unsigned_!(u16);
+    implement_TryToI16_for_unsigned_!(u32);
+    implement_TryToI16_for_unsigned_!(u64);
+    implement_TryToI16_for_unsigned_!(u128);
+    implement_TryToI16_for_unsigned_!(usize);
+    implement_TryToI16_for_float_!(f32);
+    implement_TryToI16_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToI32-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToI32_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToI32_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToI32 for $type {
+                #[inline]
+                fn try_to_i32(&self) -> Result<i32, ConversionError> {
+                    i32::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI32_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToI32 for $type {
+                #[inline]
+                fn try_to_i32(&self) -> Result<i32, ConversionError> {
+                    i32::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI32_for_float_ {
+        ($type:tt) => {
+            impl super::TryToI32 for $type {
+                #[inline]
+                fn try_to_i32(&self) -> Result<i32, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, i32::MIN as f64, i32::MAX as f64 + 1.0)?;
+
+                    Ok(v as i32)
+                }
+            }
+        };
+    }
+
+    implement_TryToI32_for_signed_!(i8);
+    implement_TryToI32_for_signed_!(i16);
+    implement_TryToI32_for_signed_!(i32);
+    implement_TryToI32_for_signed_!(i64);
+    implement_TryToI32_for_signed_!(i128);
+    implement_TryToI32_for_signed_!(isize);
+    implement_TryToI32_for_unsigned_!(u8);
+    implement_TryToI32_for_unsigned_!(u16);
+    implement_TryToI32_for_unsigned_!(u32);
+    implement_TryToI32_for_unsigned_!(u64);
+    implement_TryToI32_for_unsigned_!(u128);
+    implement_TryToI32_for_unsigned_!(usize);
+    implement_TryToI32_for_float_!(f32);
+    implement_TryToI32_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToI64-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToI64_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToI64_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToI64 for $type {
+                #[inline]
+                fn try_to_i64(&self) -> Result<i64, ConversionError> {
+                    i64::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI64_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToI64 for $type {
+                #[inline]
+                fn try_to_i64(&self) -> Result<i64, ConversionError> {
+                    i64::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI64_for_float_ {
+        ($type:tt) => {
+            impl super::TryToI64 for $type {
+                #[inline]
+                fn try_to_i64(&self) -> Result<i64, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, i64::MIN as f64, i64::MAX as f64 + 1.0)?;
+
+                    Ok(v as i64)
+                }
+            }
+        };
+    }
+
+    implement_TryToI64_for_signed_!(i8);
+    implement_TryToI64_for_signed_!(i16);
+    implement_TryToI64_for_signed_!(i32);
+    implement_TryToI64_for_signed_!(i64);
+    implement_TryToI64_for_signed_!(i128);
+    implement_TryToI64_for_signed_!(isize);
+    implement_TryToI64_for_unsigned_!(u8);
+    implement_TryToI64_for_unsigned_!(u16);
+    implement_TryToI64_for_unsigned_!(u32);
+    implement_TryToI64_for_unsigned_!(u64);
+    implement_TryToI64_for_unsigned_!(u128);
+    implement_TryToI64_for_unsigned_!(usize);
+    implement_TryToI64_for_float_!(f32);
+    implement_TryToI64_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToI128-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToI128_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToI128_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToI128 for $type {
+                #[inline]
+                fn try_to_i128(&self) -> Result<i128, ConversionError> {
+                    i128::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI128_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToI128 for $type {
+                #[inline]
+                fn try_to_i128(&self) -> Result<i128, ConversionError> {
+                    i128::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToI128_for_float_ {
+        ($type:tt) => {
+            impl super::TryToI128 for $type {
+                #[inline]
+                fn try_to_i128(&self) -> Result<i128, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, i128::MIN as f64, i128::MAX as f64 + 1.0)?;
+
+                    Ok(v as i128)
+                }
+            }
+        };
+    }
+
+    implement_TryToI128_for_signed_!(i8);
+    implement_TryToI128_for_signed_!(i16);
+    implement_TryToI128_for_signed_!(i32);
+    implement_TryToI128_for_signed_!(i64);
+    implement_TryToI128_for_signed_!(i128);
+    implement_TryToI128_for_signed_!(isize);
+    implement_TryToI128_for_unsigned_!(u8);
+    implement_TryToI128_for_unsigned_!(u16);
+    implement_TryToI128_for_unsigned_!(u32);
+    implement_TryToI128_for_unsigned_!(u64);
+    implement_TryToI128_for_unsigned_!(u128);
+    implement_TryToI128_for_unsigned_!(usize);
+    implement_TryToI128_for_float_!(f32);
+    implement_TryToI128_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToISize-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToISize_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToISize_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToISize for $type {
+                #[inline]
+                fn try_to_isize(&self) -> Result<isize, ConversionError> {
+                    isize::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToISize_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToISize for $type {
+                #[inline]
+                fn try_to_isize(&self) -> Result<isize, ConversionError> {
+                    isize::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToISize_for_float_ {
+        ($type:tt) => {
+            impl super::TryToISize for $type {
+                #[inline]
+                fn try_to_isize(&self) -> Result<isize, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, isize::MIN as f64, isize::MAX as f64 + 1.0)?;
+
+                    Ok(v as isize)
+                }
+            }
+        };
+    }
+
+    implement_TryToISize_for_signed_!(i8);
+    implement_TryToISize_for_signed_!(i16);
+    implement_TryToISize_for_signed_!(i32);
+    implement_TryToISize_for_signed_!(i64);
+    implement_TryToISize_for_signed_!(i128);
+    implement_TryToISize_for_signed_!(isize);
+    implement_TryToISize_for_unsigned_!(u8);
+    implement_TryToISize_for_unsigned_!(u16);
+    implement_TryToISize_for_unsigned_!(u32);
+    implement_TryToISize_for_unsigned_!(u64);
+    implement_TryToISize_for_unsigned_!(u128);
+    implement_TryToISize_for_unsigned_!(usize);
+    implement_TryToISize_for_float_!(f32);
+    implement_TryToISize_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToU8-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToU8_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToU8_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToU8 for $type {
+                #[inline]
+                fn try_to_u8(&self) -> Result<u8, ConversionError> {
+                    u8::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU8_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToU8 for $type {
+                #[inline]
+                fn try_to_u8(&self) -> Result<u8, ConversionError> {
+                    u8::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU8_for_float_ {
+        ($type:tt) => {
+            impl super::TryToU8 for $type {
+                #[inline]
+                fn try_to_u8(&self) -> Result<u8, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, u8::MIN as f64, u8::MAX as f64 + 1.0)?;
+
+                    Ok(v as u8)
+                }
+            }
+        };
+    }
+
+    implement_TryToU8_for_signed_!(i8);
+    implement_TryToU8_for_signed_!(i16);
+    implement_TryToU8_for_signed_!(i32);
+    implement_TryToU8_for_signed_!(i64);
+    implement_TryToU8_for_signed_!(i128);
+    implement_TryToU8_for_signed_!(isize);
+    implement_TryToU8_for_unsigned_!(u8);
+    implement_TryToU8_for_unsigned_!(u16);
+    implement_TryToU8_for_unsigned_!(u32);
+    implement_TryToU8_for_unsigned_!(u64);
+    implement_TryToU8_for_unsigned_!(u128);
+    implement_TryToU8_for_unsigned_!(usize);
+    implement_TryToU8_for_float_!(f32);
+    implement_TryToU8_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToU16-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToU16_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToU16_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToU16 for $type {
+                #[inline]
+                fn try_to_u16(&self) -> Result<u16, ConversionError> {
+                    u16::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU16_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToU16 for $type {
+                #[inline]
+                fn try_to_u16(&self) -> Result<u16, ConversionError> {
+                    u16::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU16_for_float_ {
+        ($type:tt) => {
+            impl super::TryToU16 for $type {
+                #[inline]
+                fn try_to_u16(&self) -> Result<u16, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, u16::MIN as f64, u16::MAX as f64 + 1.0)?;
+
+                    Ok(v as u16)
+                }
+            }
+        };
+    }
+
+    implement_TryToU16_for_signed_!(i8);
+    implement_TryToU16_for_signed_!(i16);
+    implement_TryToU16_for_signed_!(i32);
+    implement_TryToU16_for_signed_!(i64);
+    implement_TryToU16_for_signed_!(i128);
+    implement_TryToU16_for_signed_!(isize);
+    implement_TryToU16_for_unsigned_!(u8);
+    implement_TryToU16_for_unsigned_!(u16);
+    implement_TryToU16_for_unsigned_!(u32);
+    implement_TryToU16_for_unsigned_!(u64);
+    implement_TryToU16_for_unsigned_!(u128);
+    implement_TryToU16_for_unsigned_!(usize);
+    implement_TryToU16_for_float_!(f32);
+    implement_TryToU16_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToU32-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToU32_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToU32_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToU32 for $type {
+                #[inline]
+                fn try_to_u32(&self) -> Result<u32, ConversionError> {
+                    u32::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU32_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToU32 for $type {
+                #[inline]
+                fn try_to_u32(&self) -> Result<u32, ConversionError> {
+                    u32::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU32_for_float_ {
+        ($type:tt) => {
+            impl super::TryToU32 for $type {
+                #[inline]
+                fn try_to_u32(&self) -> Result<u32, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, u32::MIN as f64, u32::MAX as f64 + 1.0)?;
+
+                    Ok(v as u32)
+                }
+            }
+        };
+    }
+
+    implement_TryToU32_for_signed_!(i8);
+    implement_TryToU32_for_signed_!(i16);
+    implement_TryToU32_for_signed_!(i32);
+    implement_TryToU32_for_signed_!(i64);
+    implement_TryToU32_for_signed_!(i128);
+    implement_TryToU32_for_signed_!(isize);
+    implement_TryToU32_for_unsigned_!(u8);
+    implement_TryToU32_for_unsigned_!(u16);
+    implement_TryToU32_for_unsigned_!(u32);
+    implement_TryToU32_for_unsigned_!(u64);
+    implement_TryToU32_for_unsigned_!(u128);
+    implement_TryToU32_for_unsigned_!(usize);
+    implement_TryToU32_for_float_!(f32);
+    implement_TryToU32_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToU64-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToU64_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToU64_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToU64 for $type {
+                #[inline]
+                fn try_to_u64(&self) -> Result<u64, ConversionError> {
+                    u64::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU64_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToU64 for $type {
+                #[inline]
+                fn try_to_u64(&self) -> Result<u64, ConversionError> {
+                    u64::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU64_for_float_ {
+        ($type:tt) => {
+            impl super::TryToU64 for $type {
+                #[inline]
+                fn try_to_u64(&self) -> Result<u64, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, u64::MIN as f64, u64::MAX as f64 + 1.0)?;
+
+                    Ok(v as u64)
+                }
+            }
+        };
+    }
+
+    implement_TryToU64_for_signed_!(i8);
+    implement_TryToU64_for_signed_!(i16);
+    implement_TryToU64_for_signed_!(i32);
+    implement_TryToU64_for_signed_!(i64);
+    implement_TryToU64_for_signed_!(i128);
+    implement_TryToU64_for_signed_!(isize);
+    implement_TryToU64_for_unsigned_!(u8);
+    implement_TryToU64_for_unsigned_!(u16);
+    implement_TryToU64_for_unsigned_!(u32);
+    implement_TryToU64_for_unsigned_!(u64);
+    implement_TryToU64_for_unsigned_!(u128);
+    implement_TryToU64_for_unsigned_!(usize);
+    implement_TryToU64_for_float_!(f32);
+    implement_TryToU64_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToU128-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToU128_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToU128_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToU128 for $type {
+                #[inline]
+                fn try_to_u128(&self) -> Result<u128, ConversionError> {
+                    u128::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU128_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToU128 for $type {
+                #[inline]
+                fn try_to_u128(&self) -> Result<u128, ConversionError> {
+                    u128::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToU128_for_float_ {
+        ($type:tt) => {
+            impl super::TryToU128 for $type {
+                #[inline]
+                fn try_to_u128(&self) -> Result<u128, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, u128::MIN as f64, u128::MAX as f64 + 1.0)?;
+
+                    Ok(v as u128)
+                }
+            }
+        };
+    }
+
+    implement_TryToU128_for_signed_!(i8);
+    implement_TryToU128_for_signed_!(i16);
+    implement_TryToU128_for_signed_!(i32);
+    implement_TryToU128_for_signed_!(i64);
+    implement_TryToU128_for_signed_!(i128);
+    implement_TryToU128_for_signed_!(isize);
+    implement_TryToU128_for_unsigned_!(u8);
+    implement_TryToU128_for_unsigned_!(u16);
+    implement_TryToU128_for_unsigned_!(u32);
+    implement_TryToU128_for_unsigned_!(u64);
+    implement_TryToU128_for_unsigned_!(u128);
+    implement_TryToU128_for_unsigned_!(usize);
+    implement_TryToU128_for_float_!(f32);
+    implement_TryToU128_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-TryToUSize-for-built_ins")]
+#[rustfmt::skip]
+mod impl_TryToUSize_for_built_ins {
+    #![allow(non_snake_case)]
+    #![allow(clippy::unnecessary_fallible_conversions)]
+
+    use crate::{
+        ConversionError,
+        ConversionErrorKind,
+    };
+
+
+    macro_rules! implement_TryToUSize_for_signed_ {
+        ($type:tt) => {
+            impl super::TryToUSize for $type {
+                #[inline]
+                fn try_to_usize(&self) -> Result<usize, ConversionError> {
+                    usize::try_from(*self).map_err(|_| {
+                        if *self < 0 {
+                            ConversionErrorKind::Underflow.into()
+                        } else {
+                            ConversionErrorKind::Overflow.into()
+                        }
+                    })
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToUSize_for_unsigned_ {
+        ($type:tt) => {
+            impl super::TryToUSize for $type {
+                #[inline]
+                fn try_to_usize(&self) -> Result<usize, ConversionError> {
+                    usize::try_from(*self).map_err(|_| ConversionErrorKind::Overflow.into())
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_TryToUSize_for_float_ {
+        ($type:tt) => {
+            impl super::TryToUSize for $type {
+                #[inline]
+                fn try_to_usize(&self) -> Result<usize, ConversionError> {
+                    let v = *self as f64;
+
+                    super::isolate_::check_float_(v, usize::MIN as f64, usize::MAX as f64 + 1.0)?;
+
+                    Ok(v as usize)
+                }
+            }
+        };
+    }
+
+    implement_TryToUSize_for_signed_!(i8);
+    implement_TryToUSize_for_signed_!(i16);
+    implement_TryToUSize_for_signed_!(i32);
+    implement_TryToUSize_for_signed_!(i64);
+    implement_TryToUSize_for_signed_!(i128);
+    implement_TryToUSize_for_signed_!(isize);
+    implement_TryToUSize_for_unsigned_!(u8);
+    implement_TryToUSize_for_unsigned_!(u16);
+    implement_TryToUSize_for_unsigned_!(u32);
+    implement_TryToUSize_for_unsigned_!(u64);
+    implement_TryToUSize_for_unsigned_!(u128);
+    implement_TryToUSize_for_unsigned_!(usize);
+    implement_TryToUSize_for_float_!(f32);
+    implement_TryToUSize_for_float_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use crate::ConversionErrorKind;
+
+    use std::rc as std_rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        struct CustomType {
+            value : i64,
+        }
+
+        impl TryToI32 for CustomType {
+            fn try_to_i32(&self) -> Result<i32, ConversionError> {
+                i32::try_from(self.value).map_err(|_| {
+                    ConversionError::new(if self.value < 0 {
+                        ConversionErrorKind::Underflow
+                    } else {
+                        ConversionErrorKind::Overflow
+                    })
+                })
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_VALUES() {
+
+            const VALUES : &[(i64, Result<i32, ConversionErrorKind>)] = &[
+                // insert list:
+                (0, Ok(0)),
+                (1, Ok(1)),
+                (-1, Ok(-1)),
+                (i32::MAX as i64, Ok(i32::MAX)),
+                (i32::MIN as i64, Ok(i32::MIN)),
+                (i32::MAX as i64 + 1, Err(ConversionErrorKind::Overflow)),
+                (i32::MIN as i64 - 1, Err(ConversionErrorKind::Underflow)),
+            ];
+
+            for &(value, expected) in VALUES {
+                let instance = CustomType { value };
+                let actual = instance.try_to_i32().map_err(|e| e.kind());
+
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToI8-for-built_ins")]
+    mod TEST_TryToI8 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(0), 0i8.try_to_i8());
+            assert_eq!(Ok(-128), i8::MIN.try_to_i8());
+            assert_eq!(Ok(127), 127i16.try_to_i8());
+            assert_eq!(Ok(-128), (-128i32).try_to_i8());
+            assert_eq!(Ok(100), 100u8.try_to_i8());
+            assert_eq!(Ok(100), 100u128.try_to_i8());
+            assert_eq!(Ok(-1), (-1isize).try_to_i8());
+            assert_eq!(Ok(1), 1usize.try_to_i8());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), 128i16.try_to_i8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), (-129i64).try_to_i8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), 128u8.try_to_i8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), i128::MIN.try_to_i8().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(127), 127.0f32.try_to_i8());
+            assert_eq!(Ok(-128), (-128.0f64).try_to_i8());
+            assert_eq!(Ok(0), (-0.0f64).try_to_i8());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), 128.0f64.try_to_i8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), (-129.0f32).try_to_i8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Fractional), 127.5f64.try_to_i8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Fractional), (-128.5f64).try_to_i8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::NaN), f32::NAN.try_to_i8().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToI16-for-built_ins")]
+    mod TEST_TryToI16 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(-128), i8::MIN.try_to_i16());
+            assert_eq!(Ok(i16::MAX), i16::MAX.try_to_i16());
+            assert_eq!(Ok(i16::MIN), (i16::MIN as i32).try_to_i16());
+            assert_eq!(Ok(255), u8::MAX.try_to_i16());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), u16::MAX.try_to_i16().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), (i16::MIN as i32 - 1).try_to_i16().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(i16::MAX), 32767.0f32.try_to_i16());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), 32768.0f32.try_to_i16().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Fractional), 0.25f64.try_to_i16().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToI32-for-built_ins")]
+    mod TEST_TryToI32 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(i32::MAX), (i32::MAX as i64).try_to_i32());
+            assert_eq!(Ok(i32::MIN), (i32::MIN as i128).try_to_i32());
+            assert_eq!(Ok(i32::MAX), (i32::MAX as u32).try_to_i32());
+            assert_eq!(Ok(65535), u16::MAX.try_to_i32());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), u32::MAX.try_to_i32().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), i64::MAX.try_to_i32().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), i64::MIN.try_to_i32().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(i32::MAX), 2_147_483_647.0f64.try_to_i32());
+            assert_eq!(Ok(i32::MIN), (-2_147_483_648.0f32).try_to_i32());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), 2_147_483_648.0f64.try_to_i32().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), (-2_147_483_649.0f64).try_to_i32().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), f64::INFINITY.try_to_i32().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), f32::NEG_INFINITY.try_to_i32().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToI64-for-built_ins")]
+    mod TEST_TryToI64 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(i64::MAX), i64::MAX.try_to_i64());
+            assert_eq!(Ok(u32::MAX as i64), u32::MAX.try_to_i64());
+            assert_eq!(Ok(i64::MIN), (i64::MIN as i128).try_to_i64());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), u64::MAX.try_to_i64().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), (i64::MIN as i128 - 1).try_to_i64().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            // 2^63 is not representable in `i64`, but -2^63 is
+            assert_eq!(Ok(i64::MIN), (-9_223_372_036_854_775_808.0f64).try_to_i64());
+            assert_eq!(Ok(9_007_199_254_740_992), 9_007_199_254_740_992.0f64.try_to_i64());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), 9_223_372_036_854_775_808.0f64.try_to_i64().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Fractional), 4_503_599_627_370_495.5f64.try_to_i64().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::NaN), f64::NAN.try_to_i64().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToI128-for-built_ins")]
+    mod TEST_TryToI128 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(i128::MIN), i128::MIN.try_to_i128());
+            assert_eq!(Ok(u64::MAX as i128), u64::MAX.try_to_i128());
+            assert_eq!(Ok(i128::MAX), (i128::MAX as u128).try_to_i128());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), u128::MAX.try_to_i128().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(1 << 100), 1.2676506002282294e30f64.try_to_i128());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), f32::MAX.try_to_i128().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), f64::MIN.try_to_i128().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToISize-for-built_ins")]
+    mod TEST_TryToISize {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(isize::MAX), isize::MAX.try_to_isize());
+            assert_eq!(Ok(-1), (-1i8).try_to_isize());
+            assert_eq!(Ok(isize::MAX), (isize::MAX as usize).try_to_isize());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), usize::MAX.try_to_isize().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), i128::MIN.try_to_isize().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(-12), (-12.0f32).try_to_isize());
+
+            assert_eq!(Err(ConversionErrorKind::Fractional), (-12.5f32).try_to_isize().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToU8-for-built_ins")]
+    mod TEST_TryToU8 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(0), 0i8.try_to_u8());
+            assert_eq!(Ok(127), i8::MAX.try_to_u8());
+            assert_eq!(Ok(255), 255i32.try_to_u8());
+            assert_eq!(Ok(255), u8::MAX.try_to_u8());
+            assert_eq!(Ok(255), 255usize.try_to_u8());
+
+            assert_eq!(Err(ConversionErrorKind::Underflow), (-1i8).try_to_u8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), 256u16.try_to_u8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), 256i64.try_to_u8().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(255), 255.0f32.try_to_u8());
+            assert_eq!(Ok(0), (-0.0f32).try_to_u8());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), 256.0f64.try_to_u8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), 300.5f64.try_to_u8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), (-1.0f64).try_to_u8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Fractional), (-0.5f64).try_to_u8().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::NaN), f64::NAN.try_to_u8().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToU16-for-built_ins")]
+    mod TEST_TryToU16 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(u16::MAX), u16::MAX.try_to_u16());
+            assert_eq!(Ok(i16::MAX as u16), i16::MAX.try_to_u16());
+
+            assert_eq!(Err(ConversionErrorKind::Underflow), i16::MIN.try_to_u16().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), 65536u32.try_to_u16().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(u16::MAX), 65535.0f32.try_to_u16());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), 65536.0f32.try_to_u16().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToU32-for-built_ins")]
+    mod TEST_TryToU32 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(u32::MAX), (u32::MAX as u64).try_to_u32());
+            assert_eq!(Ok(i32::MAX as u32), i32::MAX.try_to_u32());
+
+            assert_eq!(Err(ConversionErrorKind::Underflow), (-1i32).try_to_u32().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), u64::MAX.try_to_u32().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(u32::MAX), 4_294_967_295.0f64.try_to_u32());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), 4_294_967_296.0f64.try_to_u32().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), f32::INFINITY.try_to_u32().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToU64-for-built_ins")]
+    mod TEST_TryToU64 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(u64::MAX), u64::MAX.try_to_u64());
+            assert_eq!(Ok(i64::MAX as u64), i64::MAX.try_to_u64());
+
+            assert_eq!(Err(ConversionErrorKind::Underflow), i64::MIN.try_to_u64().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), u128::MAX.try_to_u64().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(1 << 63), 9_223_372_036_854_775_808.0f64.try_to_u64());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), 18_446_744_073_709_551_616.0f64.try_to_u64().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Underflow), f64::NEG_INFINITY.try_to_u64().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToU128-for-built_ins")]
+    mod TEST_TryToU128 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(u128::MAX), u128::MAX.try_to_u128());
+            assert_eq!(Ok(i128::MAX as u128), i128::MAX.try_to_u128());
+
+            assert_eq!(Err(ConversionErrorKind::Underflow), (-1i128).try_to_u128().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(f32::MAX as u128), f32::MAX.try_to_u128());
+
+            assert_eq!(Err(ConversionErrorKind::Overflow), f64::MAX.try_to_u128().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Fractional), f32::MIN_POSITIVE.try_to_u128().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(feature = "implement-TryToUSize-for-built_ins")]
+    mod TEST_TryToUSize {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(Ok(usize::MAX), usize::MAX.try_to_usize());
+            assert_eq!(Ok(isize::MAX as usize), isize::MAX.try_to_usize());
+
+            assert_eq!(Err(ConversionErrorKind::Underflow), isize::MIN.try_to_usize().map_err(|e| e.kind()));
+            assert_eq!(Err(ConversionErrorKind::Overflow), u128::MAX.try_to_usize().map_err(|e| e.kind()));
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(Ok(1024), 1024.0f64.try_to_usize());
+
+            assert_eq!(Err(ConversionErrorKind::Underflow), (-1024.0f64).try_to_usize().map_err(|e| e.kind()));
+        }
+    }
+
+
+    #[cfg(all(feature = "implement-TryToI32-for-built_ins", feature = "implement-TryToU8-for-built_ins"))]
+    mod TEST_FORWARDING {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_Box() {
+            assert_eq!(Ok(-1), Box::new(-1i64).try_to_i32());
+            assert_eq!(Err(ConversionErrorKind::Underflow), Box::new(-1i64).try_to_u8().map_err(|e| e.kind()));
+
+            let instance : Box<dyn TryToI32> = Box::new(1.0f64);
+
+            assert_eq!(Ok(1), instance.try_to_i32());
+        }
+
+        #[test]
+        fn TEST_Rc() {
+            assert_eq!(Ok(-1), std_rc::Rc::new(-1i64).try_to_i32());
+            assert_eq!(Err(ConversionErrorKind::Fractional), std_rc::Rc::new(0.5f32).try_to_u8().map_err(|e| e.kind()));
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
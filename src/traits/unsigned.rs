@@ -1,9 +1,24 @@
 // src/traits/unsigned.rs : `Unsigned`
 
-pub trait Unsigned {
+use crate::Numeric;
+
+
+/// Marker trait for unsigned numeric types, whose values are never less
+/// than zero.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-Unsigned-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+pub trait Unsigned : Numeric {
 }
 
 
+#[cfg(feature = "implement-Unsigned-for-built_ins")]
 mod impl_for_built_ins {
     #![allow(non_snake_case)]
 
@@ -20,5 +35,55 @@ mod impl_for_built_ins {
 }
 
 
-// ///////////////////////////// end of file //////////////////////////// //
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    /// Uses only the facilities implied by `Unsigned`.
+    fn difference<T : Unsigned>(
+        a : T,
+        b : T,
+    ) -> T {
+        // avoids underflow, since the result may not be negative
+        if a < b {
+            b - a
+        } else {
+            a - b
+        }
+    }
+
+    fn requires_Numeric<T : Numeric>(t : T) -> T {
+        t
+    }
+
+    fn Unsigned_is_Numeric<T : Unsigned>(t : T) -> T {
+        requires_Numeric(t)
+    }
 
+
+    #[cfg(feature = "implement-Unsigned-for-built_ins")]
+    mod TEST_BUILTIN_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_difference() {
+            assert_eq!(2, difference(1u8, 3));
+            assert_eq!(2, difference(3u64, 1));
+            assert_eq!(0, difference(usize::MAX, usize::MAX));
+        }
+
+        #[test]
+        fn TEST_Unsigned_is_Numeric() {
+            assert_eq!(1, Unsigned_is_Numeric(1u128));
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
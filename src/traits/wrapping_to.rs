@@ -0,0 +1,1240 @@
+// src/traits/wrapping_to.rs : `WrappingToI8`, `WrappingToI16`, `WrappingToI32`, `WrappingToI64`, `WrappingToI128`, `WrappingToISize`, `WrappingToU8`, `WrappingToU16`, `WrappingToU32`, `WrappingToU64`, `WrappingToU128`, `WrappingToUSize`
+
+
+/// Trait defining instance method `wrapping_to_i8() : i8` that provides a
+/// conversion into `i8` that wraps around at the bounds of `i8`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `i8`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToI8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToI8 {
+    fn wrapping_to_i8(&self) -> i8;
+}
+
+/// Trait defining instance method `wrapping_to_i16() : i16` that provides a
+/// conversion into `i16` that wraps around at the bounds of `i16`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `i16`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToI16-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToI16 {
+    fn wrapping_to_i16(&self) -> i16;
+}
+
+/// Trait defining instance method `wrapping_to_i32() : i32` that provides a
+/// conversion into `i32` that wraps around at the bounds of `i32`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `i32`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToI32-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToI32 {
+    fn wrapping_to_i32(&self) -> i32;
+}
+
+/// Trait defining instance method `wrapping_to_i64() : i64` that provides a
+/// conversion into `i64` that wraps around at the bounds of `i64`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `i64`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToI64-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToI64 {
+    fn wrapping_to_i64(&self) -> i64;
+}
+
+/// Trait defining instance method `wrapping_to_i128() : i128` that provides a
+/// conversion into `i128` that wraps around at the bounds of `i128`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `i128`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToI128-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToI128 {
+    fn wrapping_to_i128(&self) -> i128;
+}
+
+/// Trait defining instance method `wrapping_to_isize() : isize` that provides a
+/// conversion into `isize` that wraps around at the bounds of `isize`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `isize`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToISize-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToISize {
+    fn wrapping_to_isize(&self) -> isize;
+}
+
+/// Trait defining instance method `wrapping_to_u8() : u8` that provides a
+/// conversion into `u8` that wraps around at the bounds of `u8`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `u8`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToU8-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToU8 {
+    fn wrapping_to_u8(&self) -> u8;
+}
+
+/// Trait defining instance method `wrapping_to_u16() : u16` that provides a
+/// conversion into `u16` that wraps around at the bounds of `u16`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `u16`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToU16-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToU16 {
+    fn wrapping_to_u16(&self) -> u16;
+}
+
+/// Trait defining instance method `wrapping_to_u32() : u32` that provides a
+/// conversion into `u32` that wraps around at the bounds of `u32`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `u32`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToU32-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToU32 {
+    fn wrapping_to_u32(&self) -> u32;
+}
+
+/// Trait defining instance method `wrapping_to_u64() : u64` that provides a
+/// conversion into `u64` that wraps around at the bounds of `u64`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `u64`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToU64-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToU64 {
+    fn wrapping_to_u64(&self) -> u64;
+}
+
+/// Trait defining instance method `wrapping_to_u128() : u128` that provides a
+/// conversion into `u128` that wraps around at the bounds of `u128`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `u128`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToU128-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToU128 {
+    fn wrapping_to_u128(&self) -> u128;
+}
+
+/// Trait defining instance method `wrapping_to_usize() : usize` that provides a
+/// conversion into `usize` that wraps around at the bounds of `usize`.
+///
+/// The result is the value modulo 2<sup>N</sup>, where N is the number
+/// of bits in `usize`, as obtained by the `as` operator for an integer
+/// value. A floating-point value is first rounded towards zero, with
+/// NaN and the infinities yielding `0`.
+///
+/// # Additional Implementations on Foreign Types
+///
+/// ## Built-in Types
+///
+/// If the feature `"implement-WrappingToUSize-for-built_ins"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following types:
+/// - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`isize`];
+/// - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`], [`usize`];
+/// - [`f32`], [`f64`];
+pub trait WrappingToUSize {
+    fn wrapping_to_usize(&self) -> usize;
+}
+
+
+implement_forwarding_!(WrappingToI8 {
+    fn wrapping_to_i8(&self) -> i8;
+});
+
+implement_forwarding_!(WrappingToI16 {
+    fn wrapping_to_i16(&self) -> i16;
+});
+
+implement_forwarding_!(WrappingToI32 {
+    fn wrapping_to_i32(&self) -> i32;
+});
+
+implement_forwarding_!(WrappingToI64 {
+    fn wrapping_to_i64(&self) -> i64;
+});
+
+implement_forwarding_!(WrappingToI128 {
+    fn wrapping_to_i128(&self) -> i128;
+});
+
+implement_forwarding_!(WrappingToISize {
+    fn wrapping_to_isize(&self) -> isize;
+});
+
+implement_forwarding_!(WrappingToU8 {
+    fn wrapping_to_u8(&self) -> u8;
+});
+
+implement_forwarding_!(WrappingToU16 {
+    fn wrapping_to_u16(&self) -> u16;
+});
+
+implement_forwarding_!(WrappingToU32 {
+    fn wrapping_to_u32(&self) -> u32;
+});
+
+implement_forwarding_!(WrappingToU64 {
+    fn wrapping_to_u64(&self) -> u64;
+});
+
+implement_forwarding_!(WrappingToU128 {
+    fn wrapping_to_u128(&self) -> u128;
+});
+
+implement_forwarding_!(WrappingToUSize {
+    fn wrapping_to_usize(&self) -> usize;
+});
+
+
+#[allow(dead_code)]
+mod isolate_ {
+
+    /// Obtains the integral part of `v`, modulo 2^128, with NaN and the
+    /// infinities yielding `0`.
+    ///
+    /// The result may be narrowed to any integer type via `as`, since
+    /// that (for integers) itself takes the value modulo 2^N.
+    pub(super) fn wrapping_f64_to_u128_(v : f64) -> u128 {
+        const MANTISSA_BITS : u32 = 52;
+        const EXPONENT_MASK : u64 = 0x7ff;
+        const EXPONENT_BIAS : i32 = 1023;
+
+        let bits = v.to_bits();
+        let biased_exponent = (bits >> MANTISSA_BITS) & EXPONENT_MASK;
+
+        if EXPONENT_MASK == biased_exponent {
+            // NaN or infinity
+            return 0;
+        }
+
+        // v == mantissa * 2^(exponent - 52), with the implicit leading bit
+        // (which does not apply to zero or the subnormals, all of which
+        // have a magnitude less than 1, and are caught by the first branch)
+        let exponent = biased_exponent as i32 - EXPONENT_BIAS;
+        let mantissa = ((bits & ((1 << MANTISSA_BITS) - 1)) | (1 << MANTISSA_BITS)) as u128;
+        let shift = exponent - MANTISSA_BITS as i32;
+
+        let magnitude = if exponent < 0 {
+            0
+        } else if shift < 0 {
+            mantissa >> -shift
+        } else if shift < 128 {
+            mantissa << shift
+        } else {
+            0
+        };
+
+        if v.is_sign_negative() {
+            magnitude.wrapping_neg()
+        } else {
+            magnitude
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-WrappingToI8-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToI8_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToI8_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToI8 for $type {
+                #[inline]
+                fn wrapping_to_i8(&self) -> i8 {
+                    *self as i8
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToI8_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToI8 for $type {
+                #[inline]
+                fn wrapping_to_i8(&self) -> i8 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as i8
+                }
+            }
+        };
+    }
+
+    implement_WrappingToI8_for_integer_!(i8);
+    implement_WrappingToI8_for_integer_!(i16);
+    implement_WrappingToI8_for_integer_!(i32);
+    implement_WrappingToI8_for_integer_!(i64);
+    implement_WrappingToI8_for_integer_!(i128);
+    implement_WrappingToI8_for_integer_!(isize);
+    implement_WrappingToI8_for_integer_!(u8);
+    implement_WrappingToI8_for_integer_!(u16);
+    implement_WrappingToI8_for_integer_!(u32);
+    implement_WrappingToI8_for_integer_!(u64);
+    implement_WrappingToI8_for_integer_!(u128);
+    implement_WrappingToI8_for_integer_!(usize);
+    implement_WrappingToI8_for_float_!(f32);
+    implement_WrappingToI8_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToI16-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToI16_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToI16_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToI16 for $type {
+                #[inline]
+                fn wrapping_to_i16(&self) -> i16 {
+                    *self as i16
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToI16_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToI16 for $type {
+                #[inline]
+                fn wrapping_to_i16(&self) -> i16 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as i16
+                }
+            }
+        };
+    }
+
+    implement_WrappingToI16_for_integer_!(i8);
+    implement_WrappingToI16_for_integer_!(i16);
+    implement_WrappingToI16_for_integer_!(i32);
+    implement_WrappingToI16_for_integer_!(i64);
+    implement_WrappingToI16_for_integer_!(i128);
+    implement_WrappingToI16_for_integer_!(isize);
+    implement_WrappingToI16_for_integer_!(u8);
+    implement_WrappingToI16_for_integer_!(u16);
+    implement_WrappingToI16_for_integer_!(u32);
+    implement_WrappingToI16_for_integer_!(u64);
+    implement_WrappingToI16_for_integer_!(u128);
+    implement_WrappingToI16_for_integer_!(usize);
+    implement_WrappingToI16_for_float_!(f32);
+    implement_WrappingToI16_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToI32-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToI32_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToI32_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToI32 for $type {
+                #[inline]
+                fn wrapping_to_i32(&self) -> i32 {
+                    *self as i32
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToI32_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToI32 for $type {
+                #[inline]
+                fn wrapping_to_i32(&self) -> i32 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as i32
+                }
+            }
+        };
+    }
+
+    implement_WrappingToI32_for_integer_!(i8);
+    implement_WrappingToI32_for_integer_!(i16);
+    implement_WrappingToI32_for_integer_!(i32);
+    implement_WrappingToI32_for_integer_!(i64);
+    implement_WrappingToI32_for_integer_!(i128);
+    implement_WrappingToI32_for_integer_!(isize);
+    implement_WrappingToI32_for_integer_!(u8);
+    implement_WrappingToI32_for_integer_!(u16);
+    implement_WrappingToI32_for_integer_!(u32);
+    implement_WrappingToI32_for_integer_!(u64);
+    implement_WrappingToI32_for_integer_!(u128);
+    implement_WrappingToI32_for_integer_!(usize);
+    implement_WrappingToI32_for_float_!(f32);
+    implement_WrappingToI32_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToI64-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToI64_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToI64_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToI64 for $type {
+                #[inline]
+                fn wrapping_to_i64(&self) -> i64 {
+                    *self as i64
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToI64_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToI64 for $type {
+                #[inline]
+                fn wrapping_to_i64(&self) -> i64 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as i64
+                }
+            }
+        };
+    }
+
+    implement_WrappingToI64_for_integer_!(i8);
+    implement_WrappingToI64_for_integer_!(i16);
+    implement_WrappingToI64_for_integer_!(i32);
+    implement_WrappingToI64_for_integer_!(i64);
+    implement_WrappingToI64_for_integer_!(i128);
+    implement_WrappingToI64_for_integer_!(isize);
+    implement_WrappingToI64_for_integer_!(u8);
+    implement_WrappingToI64_for_integer_!(u16);
+    implement_WrappingToI64_for_integer_!(u32);
+    implement_WrappingToI64_for_integer_!(u64);
+    implement_WrappingToI64_for_integer_!(u128);
+    implement_WrappingToI64_for_integer_!(usize);
+    implement_WrappingToI64_for_float_!(f32);
+    implement_WrappingToI64_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToI128-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToI128_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToI128_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToI128 for $type {
+                #[inline]
+                fn wrapping_to_i128(&self) -> i128 {
+                    *self as i128
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToI128_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToI128 for $type {
+                #[inline]
+                fn wrapping_to_i128(&self) -> i128 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as i128
+                }
+            }
+        };
+    }
+
+    implement_WrappingToI128_for_integer_!(i8);
+    implement_WrappingToI128_for_integer_!(i16);
+    implement_WrappingToI128_for_integer_!(i32);
+    implement_WrappingToI128_for_integer_!(i64);
+    implement_WrappingToI128_for_integer_!(i128);
+    implement_WrappingToI128_for_integer_!(isize);
+    implement_WrappingToI128_for_integer_!(u8);
+    implement_WrappingToI128_for_integer_!(u16);
+    implement_WrappingToI128_for_integer_!(u32);
+    implement_WrappingToI128_for_integer_!(u64);
+    implement_WrappingToI128_for_integer_!(u128);
+    implement_WrappingToI128_for_integer_!(usize);
+    implement_WrappingToI128_for_float_!(f32);
+    implement_WrappingToI128_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToISize-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToISize_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToISize_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToISize for $type {
+                #[inline]
+                fn wrapping_to_isize(&self) -> isize {
+                    *self as isize
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToISize_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToISize for $type {
+                #[inline]
+                fn wrapping_to_isize(&self) -> isize {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as isize
+                }
+            }
+        };
+    }
+
+    implement_WrappingToISize_for_integer_!(i8);
+    implement_WrappingToISize_for_integer_!(i16);
+    implement_WrappingToISize_for_integer_!(i32);
+    implement_WrappingToISize_for_integer_!(i64);
+    implement_WrappingToISize_for_integer_!(i128);
+    implement_WrappingToISize_for_integer_!(isize);
+    implement_WrappingToISize_for_integer_!(u8);
+    implement_WrappingToISize_for_integer_!(u16);
+    implement_WrappingToISize_for_integer_!(u32);
+    implement_WrappingToISize_for_integer_!(u64);
+    implement_WrappingToISize_for_integer_!(u128);
+    implement_WrappingToISize_for_integer_!(usize);
+    implement_WrappingToISize_for_float_!(f32);
+    implement_WrappingToISize_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToU8-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToU8_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToU8_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToU8 for $type {
+                #[inline]
+                fn wrapping_to_u8(&self) -> u8 {
+                    *self as u8
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToU8_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToU8 for $type {
+                #[inline]
+                fn wrapping_to_u8(&self) -> u8 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as u8
+                }
+            }
+        };
+    }
+
+    implement_WrappingToU8_for_integer_!(i8);
+    implement_WrappingToU8_for_integer_!(i16);
+    implement_WrappingToU8_for_integer_!(i32);
+    implement_WrappingToU8_for_integer_!(i64);
+    implement_WrappingToU8_for_integer_!(i128);
+    implement_WrappingToU8_for_integer_!(isize);
+    implement_WrappingToU8_for_integer_!(u8);
+    implement_WrappingToU8_for_integer_!(u16);
+    implement_WrappingToU8_for_integer_!(u32);
+    implement_WrappingToU8_for_integer_!(u64);
+    implement_WrappingToU8_for_integer_!(u128);
+    implement_WrappingToU8_for_integer_!(usize);
+    implement_WrappingToU8_for_float_!(f32);
+    implement_WrappingToU8_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToU16-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToU16_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToU16_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToU16 for $type {
+                #[inline]
+                fn wrapping_to_u16(&self) -> u16 {
+                    *self as u16
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToU16_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToU16 for $type {
+                #[inline]
+                fn wrapping_to_u16(&self) -> u16 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as u16
+                }
+            }
+        };
+    }
+
+    implement_WrappingToU16_for_integer_!(i8);
+    implement_WrappingToU16_for_integer_!(i16);
+    implement_WrappingToU16_for_integer_!(i32);
+    implement_WrappingToU16_for_integer_!(i64);
+    implement_WrappingToU16_for_integer_!(i128);
+    implement_WrappingToU16_for_integer_!(isize);
+    implement_WrappingToU16_for_integer_!(u8);
+    implement_WrappingToU16_for_integer_!(u16);
+    implement_WrappingToU16_for_integer_!(u32);
+    implement_WrappingToU16_for_integer_!(u64);
+    implement_WrappingToU16_for_integer_!(u128);
+    implement_WrappingToU16_for_integer_!(usize);
+    implement_WrappingToU16_for_float_!(f32);
+    implement_WrappingToU16_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToU32-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToU32_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToU32_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToU32 for $type {
+                #[inline]
+                fn wrapping_to_u32(&self) -> u32 {
+                    *self as u32
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToU32_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToU32 for $type {
+                #[inline]
+                fn wrapping_to_u32(&self) -> u32 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as u32
+                }
+            }
+        };
+    }
+
+    implement_WrappingToU32_for_integer_!(i8);
+    implement_WrappingToU32_for_integer_!(i16);
+    implement_WrappingToU32_for_integer_!(i32);
+    implement_WrappingToU32_for_integer_!(i64);
+    implement_WrappingToU32_for_integer_!(i128);
+    implement_WrappingToU32_for_integer_!(isize);
+    implement_WrappingToU32_for_integer_!(u8);
+    implement_WrappingToU32_for_integer_!(u16);
+    implement_WrappingToU32_for_integer_!(u32);
+    implement_WrappingToU32_for_integer_!(u64);
+    implement_WrappingToU32_for_integer_!(u128);
+    implement_WrappingToU32_for_integer_!(usize);
+    implement_WrappingToU32_for_float_!(f32);
+    implement_WrappingToU32_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToU64-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToU64_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToU64_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToU64 for $type {
+                #[inline]
+                fn wrapping_to_u64(&self) -> u64 {
+                    *self as u64
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToU64_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToU64 for $type {
+                #[inline]
+                fn wrapping_to_u64(&self) -> u64 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as u64
+                }
+            }
+        };
+    }
+
+    implement_WrappingToU64_for_integer_!(i8);
+    implement_WrappingToU64_for_integer_!(i16);
+    implement_WrappingToU64_for_integer_!(i32);
+    implement_WrappingToU64_for_integer_!(i64);
+    implement_WrappingToU64_for_integer_!(i128);
+    implement_WrappingToU64_for_integer_!(isize);
+    implement_WrappingToU64_for_integer_!(u8);
+    implement_WrappingToU64_for_integer_!(u16);
+    implement_WrappingToU64_for_integer_!(u32);
+    implement_WrappingToU64_for_integer_!(u64);
+    implement_WrappingToU64_for_integer_!(u128);
+    implement_WrappingToU64_for_integer_!(usize);
+    implement_WrappingToU64_for_float_!(f32);
+    implement_WrappingToU64_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToU128-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToU128_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToU128_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToU128 for $type {
+                #[inline]
+                fn wrapping_to_u128(&self) -> u128 {
+                    *self as u128
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToU128_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToU128 for $type {
+                #[inline]
+                fn wrapping_to_u128(&self) -> u128 {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as u128
+                }
+            }
+        };
+    }
+
+    implement_WrappingToU128_for_integer_!(i8);
+    implement_WrappingToU128_for_integer_!(i16);
+    implement_WrappingToU128_for_integer_!(i32);
+    implement_WrappingToU128_for_integer_!(i64);
+    implement_WrappingToU128_for_integer_!(i128);
+    implement_WrappingToU128_for_integer_!(isize);
+    implement_WrappingToU128_for_integer_!(u8);
+    implement_WrappingToU128_for_integer_!(u16);
+    implement_WrappingToU128_for_integer_!(u32);
+    implement_WrappingToU128_for_integer_!(u64);
+    implement_WrappingToU128_for_integer_!(u128);
+    implement_WrappingToU128_for_integer_!(usize);
+    implement_WrappingToU128_for_float_!(f32);
+    implement_WrappingToU128_for_float_!(f64);
+}
+
+#[cfg(feature = "implement-WrappingToUSize-for-built_ins")]
+#[rustfmt::skip]
+mod impl_WrappingToUSize_for_built_ins {
+    #![allow(non_snake_case)]
+
+
+    macro_rules! implement_WrappingToUSize_for_integer_ {
+        ($type:tt) => {
+            impl super::WrappingToUSize for $type {
+                #[inline]
+                fn wrapping_to_usize(&self) -> usize {
+                    *self as usize
+                }
+            }
+        };
+    }
+
+    macro_rules! implement_WrappingToUSize_for_float_ {
+        ($type:tt) => {
+            impl super::WrappingToUSize for $type {
+                #[inline]
+                fn wrapping_to_usize(&self) -> usize {
+                    super::isolate_::wrapping_f64_to_u128_(*self as f64) as usize
+                }
+            }
+        };
+    }
+
+    implement_WrappingToUSize_for_integer_!(i8);
+    implement_WrappingToUSize_for_integer_!(i16);
+    implement_WrappingToUSize_for_integer_!(i32);
+    implement_WrappingToUSize_for_integer_!(i64);
+    implement_WrappingToUSize_for_integer_!(i128);
+    implement_WrappingToUSize_for_integer_!(isize);
+    implement_WrappingToUSize_for_integer_!(u8);
+    implement_WrappingToUSize_for_integer_!(u16);
+    implement_WrappingToUSize_for_integer_!(u32);
+    implement_WrappingToUSize_for_integer_!(u64);
+    implement_WrappingToUSize_for_integer_!(u128);
+    implement_WrappingToUSize_for_integer_!(usize);
+    implement_WrappingToUSize_for_float_!(f32);
+    implement_WrappingToUSize_for_float_!(f64);
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use std::rc as std_rc;
+
+
+    mod TEST_CUSTOM_TYPE {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        struct CustomType {
+            value : u32,
+        }
+
+        impl WrappingToU8 for CustomType {
+            fn wrapping_to_u8(&self) -> u8 {
+                (self.value % 256) as u8
+            }
+        }
+
+        #[test]
+        fn TEST_RANGE_OF_VALUES() {
+
+            const VALUES : &[(u32, u8)] = &[
+                // insert list:
+                (0, 0),
+                (1, 1),
+                (255, 255),
+                (256, 0),
+                (257, 1),
+                (u32::MAX, 255),
+            ];
+
+            for &(value, expected) in VALUES {
+                let instance = CustomType { value };
+                let actual = instance.wrapping_to_u8();
+
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToI8-for-built_ins")]
+    mod TEST_WrappingToI8 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(0, 0i8.wrapping_to_i8());
+            assert_eq!(-128, 128i16.wrapping_to_i8());
+            assert_eq!(127, (-129i32).wrapping_to_i8());
+            assert_eq!(-1, u8::MAX.wrapping_to_i8());
+            assert_eq!(-1, u128::MAX.wrapping_to_i8());
+            assert_eq!(0, i128::MIN.wrapping_to_i8());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(1, 1.99f32.wrapping_to_i8());
+            assert_eq!(-1, (-1.99f64).wrapping_to_i8());
+            assert_eq!(-128, 128.0f64.wrapping_to_i8());
+            assert_eq!(-24, 1000.0f64.wrapping_to_i8());
+            assert_eq!(24, (-1000.0f32).wrapping_to_i8());
+            assert_eq!(0, f64::INFINITY.wrapping_to_i8());
+            assert_eq!(0, f32::NEG_INFINITY.wrapping_to_i8());
+            assert_eq!(0, f64::NAN.wrapping_to_i8());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToI16-for-built_ins")]
+    mod TEST_WrappingToI16 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(-1, u16::MAX.wrapping_to_i16());
+            assert_eq!(i16::MIN, 32768i32.wrapping_to_i16());
+            assert_eq!(255, u8::MAX.wrapping_to_i16());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(-25536, 40000.0f32.wrapping_to_i16());
+            assert_eq!(-12, (-12.5f64).wrapping_to_i16());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToI32-for-built_ins")]
+    mod TEST_WrappingToI32 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(-1, u32::MAX.wrapping_to_i32());
+            assert_eq!(0, i64::MIN.wrapping_to_i32());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(i32::MIN, 2_147_483_648.0f64.wrapping_to_i32());
+            assert_eq!(0, 4_294_967_296.0f64.wrapping_to_i32());
+            assert_eq!(1, 4_294_967_297.0f64.wrapping_to_i32());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToI64-for-built_ins")]
+    mod TEST_WrappingToI64 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(-1, u64::MAX.wrapping_to_i64());
+            assert_eq!(i64::MIN, (i64::MAX as i128 + 1).wrapping_to_i64());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(i64::MIN, 9_223_372_036_854_775_808.0f64.wrapping_to_i64());
+            assert_eq!(i64::MIN, (-9_223_372_036_854_775_808.0f64).wrapping_to_i64());
+            assert_eq!(0, 18_446_744_073_709_551_616.0f64.wrapping_to_i64());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToI128-for-built_ins")]
+    mod TEST_WrappingToI128 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(-1, u128::MAX.wrapping_to_i128());
+            assert_eq!(i64::MIN as i128, i64::MIN.wrapping_to_i128());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(i128::MIN, 1.7014118346046923e38f64.wrapping_to_i128());
+            assert_eq!(0, f64::MAX.wrapping_to_i128());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToISize-for-built_ins")]
+    mod TEST_WrappingToISize {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(-1, usize::MAX.wrapping_to_isize());
+            assert_eq!(-1, (-1i8).wrapping_to_isize());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(-3, (-3.75f32).wrapping_to_isize());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToU8-for-built_ins")]
+    mod TEST_WrappingToU8 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(255, (-1i8).wrapping_to_u8());
+            assert_eq!(0, 256i32.wrapping_to_u8());
+            assert_eq!(44, 300u16.wrapping_to_u8());
+            assert_eq!(200, 200u64.wrapping_to_u8());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(0, (-0.5f32).wrapping_to_u8());
+            assert_eq!(255, (-1.0f64).wrapping_to_u8());
+            assert_eq!(44, 300.5f64.wrapping_to_u8());
+            assert_eq!(0, 256.0f32.wrapping_to_u8());
+            assert_eq!(0, 1.0e-300f64.wrapping_to_u8());
+            assert_eq!(0, f64::INFINITY.wrapping_to_u8());
+            assert_eq!(0, f32::NAN.wrapping_to_u8());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToU16-for-built_ins")]
+    mod TEST_WrappingToU16 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(u16::MAX, (-1i16).wrapping_to_u16());
+            assert_eq!(1, 65537u32.wrapping_to_u16());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(1, 65537.0f32.wrapping_to_u16());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToU32-for-built_ins")]
+    mod TEST_WrappingToU32 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(u32::MAX, (-1i64).wrapping_to_u32());
+            assert_eq!(0, (1u64 << 32).wrapping_to_u32());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(u32::MAX, (-1.0f64).wrapping_to_u32());
+            assert_eq!(1, 4_294_967_297.0f64.wrapping_to_u32());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToU64-for-built_ins")]
+    mod TEST_WrappingToU64 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(u64::MAX, i128::from(-1i8).wrapping_to_u64());
+            assert_eq!(i64::MAX as u64, i64::MAX.wrapping_to_u64());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(1 << 63, 9_223_372_036_854_775_808.0f64.wrapping_to_u64());
+            assert_eq!(1 << 63, (-9_223_372_036_854_775_808.0f64).wrapping_to_u64());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToU128-for-built_ins")]
+    mod TEST_WrappingToU128 {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(u128::MAX, i128::from(-1i8).wrapping_to_u128());
+            assert_eq!(u128::MAX, u128::MAX.wrapping_to_u128());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(1 << 127, 1.7014118346046923e38f64.wrapping_to_u128());
+            assert_eq!(u128::MAX, (-1.0f32).wrapping_to_u128());
+            assert_eq!(f32::MAX as u128, f32::MAX.wrapping_to_u128());
+            assert_eq!(0, f64::MAX.wrapping_to_u128());
+        }
+    }
+
+
+    #[cfg(feature = "implement-WrappingToUSize-for-built_ins")]
+    mod TEST_WrappingToUSize {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_FROM_INTEGERS() {
+            assert_eq!(usize::MAX, (-1isize).wrapping_to_usize());
+            assert_eq!(usize::MAX, u128::MAX.wrapping_to_usize());
+        }
+
+        #[test]
+        fn TEST_FROM_FLOATS() {
+            assert_eq!(usize::MAX, (-1.0f64).wrapping_to_usize());
+        }
+    }
+
+
+    #[cfg(all(feature = "implement-WrappingToI16-for-built_ins", feature = "implement-WrappingToU8-for-built_ins"))]
+    mod TEST_FORWARDING {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+
+        #[test]
+        fn TEST_Box() {
+            assert_eq!(-1, Box::new(u16::MAX).wrapping_to_i16());
+            assert_eq!(255, Box::new(-1.5f64).wrapping_to_u8());
+
+            let instance : Box<dyn WrappingToU8> = Box::new(1000u32);
+
+            assert_eq!(232, instance.wrapping_to_u8());
+        }
+
+        #[test]
+        fn TEST_Rc() {
+            assert_eq!(0, std_rc::Rc::new(i64::MIN).wrapping_to_i16());
+            assert_eq!(0, std_rc::Rc::new(f32::INFINITY).wrapping_to_u8());
+        }
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
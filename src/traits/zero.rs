@@ -25,6 +25,38 @@
 /// - [`f32`];
 /// - [`f64`];
 /// - [`char`];
+///
+/// ## Standard Network Types
+///
+/// If the feature `"implement-Zero-for-standard_net_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`core::net::IpAddr`], as `0.0.0.0`;
+/// - [`core::net::Ipv4Addr`], as `0.0.0.0`;
+/// - [`core::net::Ipv6Addr`], as `::`;
+/// - [`core::net::SocketAddr`], as `0.0.0.0:0`;
+/// - [`core::net::SocketAddrV4`], as `0.0.0.0:0`;
+/// - [`core::net::SocketAddrV6`], as `[::]:0`;
+///
+/// ## Standard Numeric Types
+///
+/// If the feature `"implement-Zero-for-standard_num_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`core::num::Saturating<T>`], where `T : Zero`;
+/// - [`core::num::Wrapping<T>`], where `T : Zero`;
+///
+/// NOTE: it is not implemented for the `NonZero*` types, since they cannot
+/// represent zero.
+///
+/// ## Standard Time Types
+///
+/// If the feature `"implement-Zero-for-standard_time_types"`
+/// is defined (as it is by `"default"`), then this is also implemented
+/// for the following type(s):
+/// - [`core::time::Duration`];
+/// - [`std::time::SystemTime`], as [`std::time::UNIX_EPOCH`] (unless
+///   `"nostd"` is defined);
 pub trait Zero {
     fn zero() -> Self;
 }
@@ -67,6 +99,129 @@ mod impl_for_built_ins {
 }
 
 
+#[cfg(feature = "implement-Zero-for-standard_net_types")]
+mod impl_for_std_net_types {
+    #![allow(non_snake_case)]
+
+    use super::Zero;
+
+    use core::net as core_net;
+
+
+    // Ipv4Addr
+
+    impl Zero for core_net::Ipv4Addr {
+        #[inline]
+        fn zero() -> Self {
+            core_net::Ipv4Addr::UNSPECIFIED
+        }
+    }
+
+    // Ipv6Addr
+
+    impl Zero for core_net::Ipv6Addr {
+        #[inline]
+        fn zero() -> Self {
+            core_net::Ipv6Addr::UNSPECIFIED
+        }
+    }
+
+    // IpAddr
+
+    impl Zero for core_net::IpAddr {
+        #[inline]
+        fn zero() -> Self {
+            core_net::IpAddr::V4(core_net::Ipv4Addr::UNSPECIFIED)
+        }
+    }
+
+    // SocketAddrV4
+
+    impl Zero for core_net::SocketAddrV4 {
+        #[inline]
+        fn zero() -> Self {
+            core_net::SocketAddrV4::new(core_net::Ipv4Addr::UNSPECIFIED, 0)
+        }
+    }
+
+    // SocketAddrV6
+
+    impl Zero for core_net::SocketAddrV6 {
+        #[inline]
+        fn zero() -> Self {
+            core_net::SocketAddrV6::new(core_net::Ipv6Addr::UNSPECIFIED, 0, 0, 0)
+        }
+    }
+
+    // SocketAddr
+
+    impl Zero for core_net::SocketAddr {
+        #[inline]
+        fn zero() -> Self {
+            core_net::SocketAddr::V4(core_net::SocketAddrV4::zero())
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-Zero-for-standard_num_types")]
+mod impl_for_std_num_types {
+    #![allow(non_snake_case)]
+
+    use super::Zero;
+
+    use core::num as core_num;
+
+
+    // Saturating<T>
+
+    impl<T : Zero> Zero for core_num::Saturating<T> {
+        #[inline]
+        fn zero() -> Self {
+            core_num::Saturating(T::zero())
+        }
+    }
+
+
+    // Wrapping<T>
+
+    impl<T : Zero> Zero for core_num::Wrapping<T> {
+        #[inline]
+        fn zero() -> Self {
+            core_num::Wrapping(T::zero())
+        }
+    }
+}
+
+
+#[cfg(feature = "implement-Zero-for-standard_time_types")]
+mod impl_for_std_time_types {
+    #![allow(non_snake_case)]
+
+    use core::time as core_time;
+
+
+    // Duration
+
+    impl super::Zero for core_time::Duration {
+        #[inline]
+        fn zero() -> Self {
+            core_time::Duration::ZERO
+        }
+    }
+
+    // SystemTime
+
+    #[cfg(not(feature = "nostd"))]
+    impl super::Zero for std::time::SystemTime {
+        #[inline]
+        fn zero() -> Self {
+            std::time::UNIX_EPOCH
+        }
+    }
+}
+
+
 #[cfg(test)]
 mod tests {
     #![allow(non_snake_case)]
@@ -132,5 +287,100 @@ mod tests {
             assert_eq!('\0', char::zero());
         }
     }
+
+
+    #[cfg(feature = "implement-Zero-for-standard_net_types")]
+    mod TEST_NET_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::net::{
+            IpAddr,
+            Ipv4Addr,
+            Ipv6Addr,
+            SocketAddr,
+            SocketAddrV4,
+            SocketAddrV6,
+        };
+
+
+        #[test]
+        fn TEST_ADDRESSES() {
+            assert_eq!(Ipv4Addr::UNSPECIFIED, Ipv4Addr::zero());
+            assert_eq!(Ipv6Addr::UNSPECIFIED, Ipv6Addr::zero());
+            assert_eq!("0.0.0.0".parse::<IpAddr>().unwrap(), IpAddr::zero());
+        }
+
+        #[test]
+        fn TEST_SOCKET_ADDRESSES() {
+            assert_eq!("0.0.0.0:0".parse::<SocketAddrV4>().unwrap(), SocketAddrV4::zero());
+            assert_eq!("[::]:0".parse::<SocketAddrV6>().unwrap(), SocketAddrV6::zero());
+            assert_eq!("0.0.0.0:0".parse::<SocketAddr>().unwrap(), SocketAddr::zero());
+        }
+
+        #[cfg(feature = "implement-IsZero-for-standard_net_types")]
+        #[test]
+        fn TEST_IsZero() {
+            use crate::IsZero;
+
+            assert!(Ipv4Addr::zero().is_zero());
+            assert!(Ipv6Addr::zero().is_zero());
+            assert!(IpAddr::zero().is_zero());
+            assert!(SocketAddrV4::zero().is_zero());
+            assert!(SocketAddrV6::zero().is_zero());
+            assert!(SocketAddr::zero().is_zero());
+        }
+    }
+
+
+    #[cfg(all(feature = "implement-Zero-for-built_ins", feature = "implement-Zero-for-standard_num_types"))]
+    mod TEST_STANDARD_NUM_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::num as std_num;
+
+
+        #[test]
+        fn TEST_Saturating() {
+            assert_eq!(std_num::Saturating(0i16), std_num::Saturating::<i16>::zero());
+            assert_eq!(std_num::Saturating(0u64), std_num::Saturating::<u64>::zero());
+        }
+
+        #[test]
+        fn TEST_Wrapping() {
+            assert_eq!(std_num::Wrapping(0u8), std_num::Wrapping::<u8>::zero());
+            assert_eq!(std_num::Wrapping(0.0f32), std_num::Wrapping::<f32>::zero());
+        }
+    }
+
+    #[cfg(feature = "implement-Zero-for-standard_time_types")]
+    mod TEST_TIME_TYPES {
+        #![allow(non_snake_case)]
+
+        use super::*;
+
+        use std::time::Duration;
+
+
+        #[test]
+        fn TEST_Duration() {
+            assert_eq!(Duration::ZERO, Duration::zero());
+            assert_eq!(Duration::from_secs(0), Duration::zero());
+        }
+
+        #[cfg(not(feature = "nostd"))]
+        #[test]
+        fn TEST_SystemTime() {
+            use std::time::{
+                SystemTime,
+                UNIX_EPOCH,
+            };
+
+            assert_eq!(UNIX_EPOCH, SystemTime::zero());
+        }
+    }
 }
 
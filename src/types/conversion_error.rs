@@ -0,0 +1,104 @@
+// src/types/conversion_error.rs : `ConversionError`, `ConversionErrorKind`
+
+use core::fmt as core_fmt;
+
+
+/// Describes the reason for the failure of a checked conversion, such as
+/// those of the `TryTo*` traits (e.g. [`crate::TryToI32`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConversionErrorKind {
+    /// The (integral part of the) value is greater than the maximum value
+    /// of the target type.
+    Overflow,
+    /// The (integral part of the) value is less than the minimum value of
+    /// the target type.
+    Underflow,
+    /// The value is "not a number".
+    NaN,
+    /// The value has a fractional part, which cannot be represented in the
+    /// (integral) target type.
+    Fractional,
+}
+
+/// Error type of a failed checked conversion, such as those of the
+/// `TryTo*` traits (e.g. [`crate::TryToI32`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConversionError {
+    kind : ConversionErrorKind,
+}
+
+impl ConversionError {
+    /// Creates an instance with the given kind.
+    pub const fn new(kind : ConversionErrorKind) -> Self {
+        Self {
+            kind,
+        }
+    }
+
+    /// The reason for the failure.
+    pub const fn kind(&self) -> ConversionErrorKind {
+        self.kind
+    }
+}
+
+impl core_fmt::Display for ConversionError {
+    fn fmt(
+        &self,
+        f : &mut core_fmt::Formatter<'_>,
+    ) -> core_fmt::Result {
+        let message = match self.kind {
+            ConversionErrorKind::Overflow => "value too large for the target type",
+            ConversionErrorKind::Underflow => "value too small for the target type",
+            ConversionErrorKind::NaN => "value is not a number",
+            ConversionErrorKind::Fractional => "value has a fractional part",
+        };
+
+        f.write_str(message)
+    }
+}
+
+#[cfg(not(feature = "nostd"))]
+impl std::error::Error for ConversionError {}
+
+impl From<ConversionErrorKind> for ConversionError {
+    fn from(kind : ConversionErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_kind() {
+        assert_eq!(ConversionErrorKind::Overflow, ConversionError::new(ConversionErrorKind::Overflow).kind());
+        assert_eq!(ConversionErrorKind::Underflow, ConversionError::new(ConversionErrorKind::Underflow).kind());
+        assert_eq!(ConversionErrorKind::NaN, ConversionError::from(ConversionErrorKind::NaN).kind());
+        assert_eq!(ConversionErrorKind::Fractional, ConversionError::from(ConversionErrorKind::Fractional).kind());
+    }
+
+    #[test]
+    fn TEST_Display() {
+        assert_eq!("value too large for the target type", ConversionError::new(ConversionErrorKind::Overflow).to_string());
+        assert_eq!("value too small for the target type", ConversionError::new(ConversionErrorKind::Underflow).to_string());
+        assert_eq!("value is not a number", ConversionError::new(ConversionErrorKind::NaN).to_string());
+        assert_eq!("value has a fractional part", ConversionError::new(ConversionErrorKind::Fractional).to_string());
+    }
+
+    #[cfg(not(feature = "nostd"))]
+    #[test]
+    fn TEST_Error() {
+        let e : Box<dyn std::error::Error> = Box::new(ConversionError::new(ConversionErrorKind::Overflow));
+
+        assert_eq!("value too large for the target type", e.to_string());
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,111 @@
+// src/types/exact_size.rs : `ExactSize`
+
+use crate::traits::{
+    IsEmpty,
+    Len,
+};
+
+
+/// An adapter that gives any [`ExactSizeIterator`] implementations of
+/// [`IsEmpty`] and [`Len`], in terms of the number of items remaining in
+/// the iterator.
+///
+/// (The traits are not implemented for iterators in general, since the
+/// method names would collide with those of [`ExactSizeIterator`]; for the
+/// same reason, the implementation of [`Len`] for integer ranges is
+/// available only via a non-default feature.)
+///
+/// # Examples
+///
+/// ```
+/// use base_traits::{
+///     ExactSize,
+///     IsEmpty,
+///     Len,
+/// };
+///
+/// fn describe<T : IsEmpty + Len>(t : &T) -> String {
+///     if t.is_empty() {
+///         "none".into()
+///     } else {
+///         format!("{} item(s)", t.len())
+///     }
+/// }
+///
+/// let mut chars = ExactSize([ 'a', 'b', 'c' ].into_iter());
+///
+/// assert_eq!("3 item(s)", describe(&chars));
+///
+/// chars.0.next();
+///
+/// assert_eq!("2 item(s)", describe(&chars));
+/// assert_eq!("none", describe(&ExactSize(std::iter::empty::<char>())));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExactSize<I>(pub I);
+
+impl<I> ExactSize<I> {
+    /// Obtains the adapted iterator.
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I : ExactSizeIterator> IsEmpty for ExactSize<I> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        0 == self.0.len()
+    }
+}
+
+impl<I : ExactSizeIterator> Len for ExactSize<I> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_EMPTY() {
+        let es = ExactSize(core::iter::empty::<i32>());
+
+        assert!(es.is_empty());
+        assert_eq!(0, es.len());
+    }
+
+    #[test]
+    fn TEST_NONEMPTY() {
+        let mut es = ExactSize([ 1, 2 ].iter());
+
+        assert!(!es.is_empty());
+        assert_eq!(2, es.len());
+
+        es.0.next();
+
+        assert!(!es.is_empty());
+        assert_eq!(1, es.len());
+
+        es.0.next();
+
+        assert!(es.is_empty());
+        assert_eq!(0, es.len());
+    }
+
+    #[test]
+    fn TEST_into_inner() {
+        let es = ExactSize(0..3);
+
+        assert_eq!(3, es.into_inner().count());
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
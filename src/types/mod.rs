@@ -0,0 +1,20 @@
+// types/mod.rs
+
+macro_rules! declare_and_publish {
+    ($mod_name:ident, $($type_name:ident),*) => {
+        mod $mod_name;
+
+        pub use $mod_name::{
+            $($type_name),*
+        };
+    };
+}
+
+declare_and_publish!(conversion_error, ConversionError, ConversionErrorKind);
+declare_and_publish!(exact_size, ExactSize);
+declare_and_publish!(number_category, NumberCategory);
+declare_and_publish!(presence, Presence);
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,59 @@
+// src/types/number_category.rs : `NumberCategory`
+
+use core::num as core_num;
+
+
+/// The category of a numeric value, as obtained from the `Classify` trait
+/// (i.e. [`crate::Classify`]).
+///
+/// This mirrors [`core::num::FpCategory`], but is defined by the crate so
+/// that it may be obtained for types other than the built-in
+/// floating-point types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumberCategory {
+    /// The value is zero.
+    Zero,
+    /// The value is (non-zero and) too small in magnitude to be
+    /// represented in normalized form.
+    Subnormal,
+    /// The value is finite, non-zero, and normal.
+    Normal,
+    /// The value is (positive or negative) infinity.
+    Infinite,
+    /// The value is "not a number".
+    NaN,
+}
+
+impl From<core_num::FpCategory> for NumberCategory {
+    fn from(category : core_num::FpCategory) -> Self {
+        match category {
+            core_num::FpCategory::Zero => Self::Zero,
+            core_num::FpCategory::Subnormal => Self::Subnormal,
+            core_num::FpCategory::Normal => Self::Normal,
+            core_num::FpCategory::Infinite => Self::Infinite,
+            core_num::FpCategory::Nan => Self::NaN,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_From_FpCategory() {
+        assert_eq!(NumberCategory::Zero, NumberCategory::from(core_num::FpCategory::Zero));
+        assert_eq!(NumberCategory::Subnormal, NumberCategory::from(core_num::FpCategory::Subnormal));
+        assert_eq!(NumberCategory::Normal, NumberCategory::from(core_num::FpCategory::Normal));
+        assert_eq!(NumberCategory::Infinite, NumberCategory::from(core_num::FpCategory::Infinite));
+        assert_eq!(NumberCategory::NaN, NumberCategory::from(core_num::FpCategory::Nan));
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
@@ -0,0 +1,122 @@
+// src/types/presence.rs : `Presence`
+
+use crate::traits::{
+    IsEmpty,
+    Len,
+};
+
+
+/// A view of an [`Option`] or a [`Result`] that regards it as a collection
+/// of (at most) one value, regardless of that value's own emptiness or
+/// length.
+///
+/// Hence, `None` and `Err` are empty and have length 0, and `Some` and
+/// `Ok` are non-empty and have length 1.
+///
+/// # Examples
+///
+/// ```
+/// use base_traits::{
+///     IsEmpty,
+///     Len,
+///     Presence,
+/// };
+///
+/// let name : Option<String> = Some(String::new());
+///
+/// assert!(!Presence(&name).is_empty());
+/// assert_eq!(1, Presence(&name).len());
+///
+/// let name : Option<String> = None;
+///
+/// assert!(Presence(&name).is_empty());
+/// assert_eq!(0, Presence(&name).len());
+/// ```
+#[derive(Debug)]
+pub struct Presence<'a, T>(pub &'a T);
+
+impl<T> Clone for Presence<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Presence<'_, T> {
+}
+
+
+// Option<>
+
+impl<T> IsEmpty for Presence<'_, Option<T>> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<T> Len for Presence<'_, Option<T>> {
+    #[inline]
+    fn len(&self) -> usize {
+        usize::from(self.0.is_some())
+    }
+}
+
+// Result<>
+
+impl<T, E> IsEmpty for Presence<'_, Result<T, E>> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_err()
+    }
+}
+
+impl<T, E> Len for Presence<'_, Result<T, E>> {
+    #[inline]
+    fn len(&self) -> usize {
+        usize::from(self.0.is_ok())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_Option() {
+        let o : Option<&str> = None;
+
+        assert!(Presence(&o).is_empty());
+        assert_eq!(0, Presence(&o).len());
+
+        let o = Some("");
+
+        assert!(!Presence(&o).is_empty());
+        assert_eq!(1, Presence(&o).len());
+
+        let o = Some("abc");
+
+        assert!(!Presence(&o).is_empty());
+        assert_eq!(1, Presence(&o).len());
+    }
+
+    #[test]
+    fn TEST_Result() {
+        let r : Result<&str, i32> = Err(-1);
+
+        assert!(Presence(&r).is_empty());
+        assert_eq!(0, Presence(&r).len());
+
+        let r : Result<&str, i32> = Ok("");
+
+        assert!(!Presence(&r).is_empty());
+        assert_eq!(1, Presence(&r).len());
+    }
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+
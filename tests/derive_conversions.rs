@@ -0,0 +1,140 @@
+// tests/derive_conversions.rs : `#[derive(As)]` and `#[derive(To)]` vs the `As*` and `To*` implementations
+
+//! Verifies that the tables of conversions in **base-traits-derive** (in
+//! `base-traits-derive/src/conversions.rs`) agree with the implementations
+//! of the `As*` and `To*` traits for the built-in types (in
+//! `src/traits/as_*.rs` and `src/traits/to_*.rs`): for each built-in
+//! numeric type, a newtype that derives `As` and `To` without listing the
+//! conversions must implement exactly the traits that the wrapped type
+//! implements.
+
+#![allow(non_snake_case)]
+
+use base_traits::{
+    As,
+    AsF32,
+    AsF64,
+    AsI128,
+    AsI16,
+    AsI32,
+    AsI64,
+    AsI8,
+    AsISize,
+    AsU128,
+    AsU16,
+    AsU32,
+    AsU64,
+    AsU8,
+    AsUSize,
+    To,
+    ToF32,
+    ToF64,
+    ToI128,
+    ToI16,
+    ToI32,
+    ToI64,
+    ToI8,
+    ToISize,
+    ToU128,
+    ToU16,
+    ToU32,
+    ToU64,
+    ToU8,
+    ToUSize,
+};
+
+
+/// Evaluates to `true` if, and only if, `$type` implements `$trait_name`.
+///
+/// NOTE: this relies on an inherent associated constant - available only
+/// when the bound is satisfied - taking precedence over the trait's
+/// associated constant of the same name.
+macro_rules! implements_ {
+    ($type:ty : $trait_name:ident) => {{
+        struct Probe<T : ?Sized>(core::marker::PhantomData<T>);
+
+        #[allow(dead_code)]
+        trait NotImplemented {
+            const IMPLEMENTS : bool = false;
+        }
+
+        impl<T : ?Sized> NotImplemented for Probe<T> {}
+
+        #[allow(dead_code)]
+        impl<T : $trait_name + ?Sized> Probe<T> {
+            const IMPLEMENTS : bool = true;
+        }
+
+        <Probe<$type>>::IMPLEMENTS
+    }};
+}
+
+macro_rules! verify_family_ {
+    ($newtype:ident, $type:ty; $($trait_name:ident),+) => {
+        $(
+            assert_eq!(
+                implements_!($type : $trait_name),
+                implements_!($newtype : $trait_name),
+                "the derived implementation of `{}` for a newtype of `{}` does not agree with that of `{}`",
+                stringify!($trait_name),
+                stringify!($type),
+                stringify!($type),
+            );
+        )+
+    };
+}
+
+macro_rules! verify_conversions_ {
+    ($mod_name:ident, $type:ty) => {
+        mod $mod_name {
+            #![allow(non_snake_case)]
+
+            use super::*;
+
+
+            #[derive(As, To)]
+            #[allow(unused)]
+            struct Newtype($type);
+
+
+            #[test]
+            fn TEST_As() {
+                verify_family_!(
+                    Newtype, $type;
+                    AsF32, AsF64,
+                    AsI8, AsI16, AsI32, AsI64, AsI128, AsISize,
+                    AsU8, AsU16, AsU32, AsU64, AsU128, AsUSize
+                );
+            }
+
+            #[test]
+            fn TEST_To() {
+                verify_family_!(
+                    Newtype, $type;
+                    ToF32, ToF64,
+                    ToI8, ToI16, ToI32, ToI64, ToI128, ToISize,
+                    ToU8, ToU16, ToU32, ToU64, ToU128, ToUSize
+                );
+            }
+        }
+    };
+}
+
+
+verify_conversions_!(TEST_f32, f32);
+verify_conversions_!(TEST_f64, f64);
+verify_conversions_!(TEST_i8, i8);
+verify_conversions_!(TEST_i16, i16);
+verify_conversions_!(TEST_i32, i32);
+verify_conversions_!(TEST_i64, i64);
+verify_conversions_!(TEST_i128, i128);
+verify_conversions_!(TEST_isize, isize);
+verify_conversions_!(TEST_u8, u8);
+verify_conversions_!(TEST_u16, u16);
+verify_conversions_!(TEST_u32, u32);
+verify_conversions_!(TEST_u64, u64);
+verify_conversions_!(TEST_u128, u128);
+verify_conversions_!(TEST_usize, usize);
+
+
+// ///////////////////////////// end of file //////////////////////////// //
@@ -0,0 +1,102 @@
+// tests/nostd_alloc.rs : `"nostd"` + `"alloc"` configuration
+
+#![no_std]
+#![allow(non_snake_case)]
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    rc::Rc,
+    string::String,
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+
+use base_traits::{
+    AsStr,
+    IsEmpty,
+    Len,
+};
+
+
+fn is_empty_<T : IsEmpty + ?Sized>(t : &T) -> bool {
+    t.is_empty()
+}
+
+fn len_<T : Len + ?Sized>(t : &T) -> usize {
+    t.len()
+}
+
+fn as_str_<T : AsStr + ?Sized>(t : &T) -> &str {
+    t.as_str()
+}
+
+
+#[test]
+fn TEST_Vec() {
+    let v : Vec<i32> = Vec::new();
+
+    assert!(is_empty_(&v));
+    assert_eq!(0, len_(&v));
+
+    let v = vec![ 1, 2, 3 ];
+
+    assert!(!is_empty_(&v));
+    assert_eq!(3, len_(&v));
+}
+
+#[test]
+fn TEST_String() {
+    let s = String::new();
+
+    assert!(is_empty_(&s));
+    assert_eq!(0, len_(&s));
+    assert_eq!("", as_str_(&s));
+
+    let s = String::from("abc");
+
+    assert!(!is_empty_(&s));
+    assert_eq!(3, len_(&s));
+    assert_eq!("abc", as_str_(&s));
+}
+
+#[test]
+fn TEST_BTreeMap() {
+    let mut m = BTreeMap::new();
+
+    assert!(is_empty_(&m));
+    assert_eq!(0, len_(&m));
+
+    m.insert(1, "one");
+
+    assert!(!is_empty_(&m));
+    assert_eq!(1, len_(&m));
+}
+
+#[test]
+fn TEST_CString() {
+    let s = alloc::ffi::CString::new("abc").unwrap();
+
+    assert!(!is_empty_(&s));
+    assert_eq!(3, len_(&s));
+    assert_eq!(3, len_(s.as_c_str()));
+}
+
+#[test]
+fn TEST_POINTERS() {
+    let b : Box<String> = Box::new("abc".into());
+    let r : Rc<Vec<i32>> = Rc::new(vec![ 1, 2 ]);
+    let a : Arc<String> = Arc::new(String::new());
+
+    assert_eq!(3, len_(&b));
+    assert_eq!("abc", as_str_(&b));
+    assert_eq!(2, len_(&r));
+    assert!(is_empty_(&a));
+}
+
+
+// ///////////////////////////// end of file //////////////////////////// //
+